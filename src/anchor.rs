@@ -0,0 +1,35 @@
+use glam::Vec2;
+
+/// One of the nine reference points of a rectangle: its corners, the
+/// midpoints of its edges, and its center.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Anchor {
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+impl Anchor {
+    /// Returns the position of the anchor within a unit square, where (0, 0)
+    /// is the top left vertex and (1, 1) the bottom right vertex.
+    #[inline]
+    pub const fn fraction(self) -> Vec2 {
+        match self {
+            Anchor::TopLeft => Vec2::new(0.0, 0.0),
+            Anchor::Top => Vec2::new(0.5, 0.0),
+            Anchor::TopRight => Vec2::new(1.0, 0.0),
+            Anchor::Left => Vec2::new(0.0, 0.5),
+            Anchor::Center => Vec2::new(0.5, 0.5),
+            Anchor::Right => Vec2::new(1.0, 0.5),
+            Anchor::BottomLeft => Vec2::new(0.0, 1.0),
+            Anchor::Bottom => Vec2::new(0.5, 1.0),
+            Anchor::BottomRight => Vec2::new(1.0, 1.0),
+        }
+    }
+}
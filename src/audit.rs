@@ -0,0 +1,159 @@
+use std::fmt;
+use std::panic::Location;
+
+use glam::Vec2;
+
+use crate::Rect;
+
+/// Where an [`AuditedRect`] first got a non-finite coordinate.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct AuditReport {
+    /// The name of the operation that produced the non-finite rect.
+    pub operation: &'static str,
+    /// The rect before the operation.
+    pub input: Rect,
+    /// The rect produced by the operation.
+    pub output: Rect,
+    /// The source location that applied the operation.
+    pub location: &'static Location<'static>,
+}
+
+impl fmt::Display for AuditReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` at {} turned {:?} into {:?}",
+            self.operation, self.location, self.input, self.output
+        )
+    }
+}
+
+/// A rect that remembers the first operation that gave it a NaN or infinite
+/// coordinate, so long pipelines can report where a rect went bad instead
+/// of only noticing it when rendering.
+///
+/// Operations are applied with [`AuditedRect::map`], or one of the
+/// shorthands for common operations, and record the calling source
+/// location. Once a report is recorded it is kept, even if a later
+/// operation makes the rect finite again.
+///
+/// Note that with the `strict` feature enabled, debug builds panic as soon
+/// as a non-finite rect is constructed, before it can be reported.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct AuditedRect {
+    rect: Rect,
+    report: Option<AuditReport>,
+}
+
+impl AuditedRect {
+    /// Starts auditing `rect`. If it is already non-finite, this is reported
+    /// as the operation `"new"`.
+    #[track_caller]
+    pub fn new(rect: Rect) -> Self {
+        Self { rect, report: None }.map("new", |_| rect)
+    }
+
+    /// Returns the current rect.
+    #[inline]
+    pub fn rect(&self) -> Rect {
+        self.rect
+    }
+
+    /// Returns where the rect first became non-finite, or `None` if it has
+    /// stayed finite.
+    #[inline]
+    pub fn report(&self) -> Option<&AuditReport> {
+        self.report.as_ref()
+    }
+
+    /// Returns the current rect, or the report of where it first went bad.
+    pub fn into_result(self) -> Result<Rect, AuditReport> {
+        match self.report {
+            Some(report) => Err(report),
+            None => Ok(self.rect),
+        }
+    }
+
+    /// Applies `f` to the rect, recording `operation` and the caller's
+    /// location if the result is the first non-finite rect.
+    #[track_caller]
+    #[must_use]
+    pub fn map(mut self, operation: &'static str, f: impl FnOnce(&Rect) -> Rect) -> Self {
+        let output = f(&self.rect);
+        if self.report.is_none() && !output.is_finite() {
+            self.report = Some(AuditReport {
+                operation,
+                input: self.rect,
+                output,
+                location: Location::caller(),
+            });
+        }
+        self.rect = output;
+        self
+    }
+
+    /// Audited [`Rect::with_offset`].
+    #[track_caller]
+    #[must_use]
+    pub fn with_offset(self, offset: Vec2) -> Self {
+        self.map("with_offset", |rect| rect.with_offset(offset))
+    }
+
+    /// Audited [`Rect::scale`].
+    #[track_caller]
+    #[must_use]
+    pub fn scale(self, factor: Vec2) -> Self {
+        self.map("scale", |rect| rect.scale(factor))
+    }
+
+    /// Audited [`Rect::union`].
+    #[track_caller]
+    #[must_use]
+    pub fn union(self, other: &Rect) -> Self {
+        self.map("union", |rect| rect.union(other))
+    }
+
+    /// Audited [`Rect::lerp`].
+    #[track_caller]
+    #[must_use]
+    pub fn lerp(self, other: &Rect, t: f32) -> Self {
+        self.map("lerp", |rect| rect.lerp(other, t))
+    }
+}
+
+impl From<Rect> for AuditedRect {
+    #[track_caller]
+    fn from(rect: Rect) -> Self {
+        Self::new(rect)
+    }
+}
+
+// Constructing non-finite rects panics with the `strict` feature.
+#[cfg(all(test, not(feature = "strict")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_audited_rect() {
+        let rect = Rect::from_tuples((0.0, 0.0), (10.0, 10.0));
+        let audited = AuditedRect::new(rect)
+            .with_offset(Vec2::new(5.0, 0.0))
+            .scale(Vec2::new(2.0, 0.5));
+        assert_eq!(audited.report(), None);
+        assert_eq!(audited.rect(), Rect::from_tuples((10.0, 0.0), (30.0, 5.0)));
+
+        let empty = Rect::from_tuples((0.0, 0.0), (0.0, 0.0));
+        let line = line!() + 3;
+        let audited = audited
+            .map("fit", |r| r.fit_inside(&empty))
+            .map("normalize", |r| r.scale(Vec2::ONE / r.size()))
+            .map("reset", |_| rect);
+        // Resetting made the rect finite again, but the report stays.
+        assert_eq!(audited.rect(), rect);
+        let report = audited.report().unwrap();
+        assert_eq!(report.operation, "normalize");
+        assert_eq!(report.input, empty);
+        assert_eq!(report.location.line(), line);
+        assert!(audited.into_result().is_err());
+    }
+}
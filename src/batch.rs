@@ -0,0 +1,126 @@
+use glam::{Vec2, Vec4};
+
+use crate::Rect;
+
+/// Loads one coordinate of up to four rects into the lanes of a `Vec4`,
+/// padding missing lanes with `pad`.
+#[inline]
+fn lanes(rects: &[Rect], pad: f32, coordinate: impl Fn(&Rect) -> f32) -> Vec4 {
+    let mut lanes = [pad; 4];
+    for (lane, rect) in lanes.iter_mut().zip(rects) {
+        *lane = coordinate(rect);
+    }
+    Vec4::from_array(lanes)
+}
+
+/// Returns a bitmask of which of up to four `others` overlap `rect` with
+/// positive area, as by [`Rect::intersects`].
+#[inline]
+fn intersects_mask(rect: &Rect, others: &[Rect]) -> u32 {
+    Vec4::splat(rect.top_left.x)
+        .cmplt(lanes(others, 0.0, |r| r.bottom_right.x))
+        .bitmask()
+        & lanes(others, 0.0, |r| r.top_left.x)
+            .cmplt(Vec4::splat(rect.bottom_right.x))
+            .bitmask()
+        & Vec4::splat(rect.top_left.y)
+            .cmplt(lanes(others, 0.0, |r| r.bottom_right.y))
+            .bitmask()
+        & lanes(others, 0.0, |r| r.top_left.y)
+            .cmplt(Vec4::splat(rect.bottom_right.y))
+            .bitmask()
+}
+
+/// Intersects `rect` with each of `others` four at a time, writing the same
+/// results as [`Rect::intersect`] to `out`, which is cleared first.
+pub fn intersect_many(rect: &Rect, others: &[Rect], out: &mut Vec<Option<Rect>>) {
+    out.clear();
+    out.reserve(others.len());
+    for chunk in others.chunks(4) {
+        let left = lanes(chunk, 0.0, |r| r.top_left.x).max(Vec4::splat(rect.top_left.x));
+        let top = lanes(chunk, 0.0, |r| r.top_left.y).max(Vec4::splat(rect.top_left.y));
+        let right = lanes(chunk, 0.0, |r| r.bottom_right.x).min(Vec4::splat(rect.bottom_right.x));
+        let bottom = lanes(chunk, 0.0, |r| r.bottom_right.y).min(Vec4::splat(rect.bottom_right.y));
+        let mask = intersects_mask(rect, chunk)
+            & left.cmplt(right).bitmask()
+            & top.cmplt(bottom).bitmask();
+        let (left, top, right, bottom) = (
+            left.to_array(),
+            top.to_array(),
+            right.to_array(),
+            bottom.to_array(),
+        );
+        out.extend((0..chunk.len()).map(|i| {
+            (mask & (1 << i) != 0).then(|| Rect {
+                top_left: Vec2::new(left[i], top[i]),
+                bottom_right: Vec2::new(right[i], bottom[i]),
+            })
+        }));
+    }
+}
+
+/// Tests `rect` against each of `others` four at a time, returning the same
+/// results as [`Rect::intersects`]. This is the usual culling test for large
+/// numbers of sprites.
+pub fn intersects_many(rect: &Rect, others: &[Rect]) -> Vec<bool> {
+    let mut result = Vec::with_capacity(others.len());
+    for chunk in others.chunks(4) {
+        let mask = intersects_mask(rect, chunk);
+        result.extend((0..chunk.len()).map(|i| mask & (1 << i) != 0));
+    }
+    result
+}
+
+/// Tests each of `points` against `rect` four at a time, returning the same
+/// results as [`Rect::contains`].
+pub fn contains_points(rect: &Rect, points: &[Vec2]) -> Vec<bool> {
+    let mut result = Vec::with_capacity(points.len());
+    for chunk in points.chunks(4) {
+        let mut xs = [0.0; 4];
+        let mut ys = [0.0; 4];
+        for (i, point) in chunk.iter().enumerate() {
+            xs[i] = point.x;
+            ys[i] = point.y;
+        }
+        let (xs, ys) = (Vec4::from_array(xs), Vec4::from_array(ys));
+        let mask = xs.cmpge(Vec4::splat(rect.top_left.x)).bitmask()
+            & ys.cmpge(Vec4::splat(rect.top_left.y)).bitmask()
+            & xs.cmplt(Vec4::splat(rect.bottom_right.x)).bitmask()
+            & ys.cmplt(Vec4::splat(rect.bottom_right.y)).bitmask();
+        result.extend((0..chunk.len()).map(|i| mask & (1 << i) != 0));
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_batch_matches_scalar() {
+        let rect = Rect::from_tuples((0.0, 0.0), (10.0, 10.0));
+        let others: Vec<Rect> = (0..11)
+            .map(|i| {
+                let offset = i as f32 * 2.0 - 4.0;
+                Rect::from_tuples((offset, -1.0), (offset + 3.0, 1.0 + i as f32))
+            })
+            .chain([Rect {
+                top_left: Vec2::new(f32::NAN, 0.0),
+                bottom_right: Vec2::new(5.0, 5.0),
+            }])
+            .collect();
+        let mut out = vec![None];
+        intersect_many(&rect, &others, &mut out);
+        let expected: Vec<_> = others.iter().map(|other| rect.intersect(other)).collect();
+        assert_eq!(out, expected);
+        let expected: Vec<_> = others.iter().map(|other| rect.intersects(other)).collect();
+        assert_eq!(intersects_many(&rect, &others), expected);
+
+        let points: Vec<Vec2> = (0..7)
+            .map(|i| Vec2::new(i as f32 * 2.5 - 2.5, 10.0 - i as f32))
+            .collect();
+        let expected: Vec<_> = points.iter().map(|&point| rect.contains(point)).collect();
+        assert_eq!(contains_points(&rect, &points), expected);
+        assert!(contains_points(&rect, &[]).is_empty());
+    }
+}
@@ -0,0 +1,107 @@
+use crate::Rect;
+
+/// Returns the index pairs of all rects that overlap with positive area, as
+/// by [`Rect::intersects`], using a sort-based sweep along the x axis. Each
+/// pair is ordered with the smaller index first, and the pairs are sorted.
+///
+/// For rects that move a little between frames, [`SweepAndPrune`] reuses the
+/// sort order of the previous frame.
+pub fn overlapping_pairs(rects: &[Rect]) -> Vec<(usize, usize)> {
+    let mut broadphase = SweepAndPrune::new();
+    broadphase.update(rects);
+    broadphase.pairs
+}
+
+/// An incremental sweep-and-prune broadphase.
+///
+/// The rects are kept sorted by their left edge between updates with an
+/// insertion sort, which takes close to linear time when they have moved
+/// only a little since the previous update.
+#[derive(Debug, Clone, Default)]
+pub struct SweepAndPrune {
+    order: Vec<usize>,
+    pairs: Vec<(usize, usize)>,
+}
+
+impl SweepAndPrune {
+    /// Constructs a new broadphase without rects.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the pairs found by the last update, see [`overlapping_pairs`].
+    #[inline]
+    pub fn pairs(&self) -> &[(usize, usize)] {
+        &self.pairs
+    }
+
+    /// Finds the overlapping pairs of `rects`, which are identified by their
+    /// index. The order from the previous update is reused if the number of
+    /// rects did not change.
+    pub fn update(&mut self, rects: &[Rect]) -> &[(usize, usize)] {
+        if self.order.len() != rects.len() {
+            self.order.clear();
+            self.order.extend(0..rects.len());
+            self.order
+                .sort_unstable_by(|&a, &b| rects[a].left().total_cmp(&rects[b].left()));
+        } else {
+            for i in 1..self.order.len() {
+                let mut j = i;
+                while j > 0
+                    && rects[self.order[j - 1]]
+                        .left()
+                        .total_cmp(&rects[self.order[j]].left())
+                        == core::cmp::Ordering::Greater
+                {
+                    self.order.swap(j - 1, j);
+                    j -= 1;
+                }
+            }
+        }
+
+        self.pairs.clear();
+        for (i, &a) in self.order.iter().enumerate() {
+            for &b in &self.order[i + 1..] {
+                if rects[b].left() >= rects[a].right() {
+                    break;
+                }
+                if rects[a].intersects(&rects[b]) {
+                    self.pairs.push((a.min(b), a.max(b)));
+                }
+            }
+        }
+        self.pairs.sort_unstable();
+        &self.pairs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_overlapping_pairs() {
+        let mut rects = vec![
+            Rect::from_tuples((10.0, 0.0), (20.0, 10.0)),
+            Rect::from_tuples((0.0, 0.0), (12.0, 5.0)),
+            Rect::from_tuples((20.0, 0.0), (30.0, 10.0)),
+            Rect::from_tuples((5.0, 6.0), (25.0, 8.0)),
+        ];
+        let mut expected = Vec::new();
+        for i in 0..rects.len() {
+            for j in i + 1..rects.len() {
+                if rects[i].intersects(&rects[j]) {
+                    expected.push((i, j));
+                }
+            }
+        }
+        assert_eq!(overlapping_pairs(&rects), expected);
+        assert_eq!(expected, [(0, 1), (0, 3), (2, 3)]);
+
+        let mut broadphase = SweepAndPrune::new();
+        broadphase.update(&rects);
+        rects[1] = rects[1].with_offset((25.0, 0.0));
+        assert_eq!(broadphase.update(&rects), [(0, 3), (1, 2), (2, 3)]);
+    }
+}
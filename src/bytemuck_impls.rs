@@ -0,0 +1,31 @@
+use bytemuck::{Pod, Zeroable};
+
+use crate::{DRect, IRect, Rect, URect};
+
+// SAFETY: each rect is `#[repr(C)]` and made of two `Pod` vectors of the same
+// alignment, so it has no padding and every bit pattern is valid.
+unsafe impl Zeroable for Rect {}
+unsafe impl Pod for Rect {}
+unsafe impl Zeroable for DRect {}
+unsafe impl Pod for DRect {}
+unsafe impl Zeroable for URect {}
+unsafe impl Pod for URect {}
+unsafe impl Zeroable for IRect {}
+unsafe impl Pod for IRect {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_cast_slice() {
+        let rects = [
+            URect::from_tuples((1, 2), (3, 4)),
+            URect::from_tuples((5, 6), (7, 8)),
+        ];
+        let words: &[u32] = bytemuck::cast_slice(&rects);
+        assert_eq!(words, &[1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(bytemuck::cast_slice::<u32, URect>(words), &rects);
+        assert_eq!(std::mem::size_of::<Rect>(), 16);
+    }
+}
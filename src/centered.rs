@@ -0,0 +1,589 @@
+use glam::Vec2;
+
+use crate::{Anchor, Insets, Rect, RectRelation};
+
+/// An axis-aligned rectangle stored as its center point and half extents,
+/// the form physics and signed distance code usually works in. Converts to
+/// and from [`Rect`] with `From`, [`to_rect`](Self::to_rect) and
+/// [`from_rect`](Self::from_rect).
+///
+/// Most operations of [`Rect`] are provided here too, computed on the center
+/// form where that is simpler and otherwise through `Rect`. Those tied to the
+/// corner form are left out: byte and vector encodings, triangle lists, pixel
+/// snapping and cell coverage, splitting, subtraction and relative layout.
+/// Convert to `Rect` for those.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct CenteredRect {
+    pub center: Vec2,
+    pub half_extents: Vec2,
+}
+
+/// The name physics engines commonly use for [`CenteredRect`].
+pub type Aabb2 = CenteredRect;
+
+impl CenteredRect {
+    /// Constructs a new `CenteredRect`. Both components of `half_extents`
+    /// must be non-negative.
+    #[inline]
+    pub const fn new(center: Vec2, half_extents: Vec2) -> Self {
+        Self {
+            center,
+            half_extents,
+        }
+    }
+
+    /// Constructs the `CenteredRect` covering `rect`.
+    #[inline]
+    pub fn from_rect(rect: &Rect) -> Self {
+        Self::from(*rect)
+    }
+
+    /// Returns the corner form of the rectangle.
+    #[inline]
+    pub fn to_rect(&self) -> Rect {
+        Rect::from(*self)
+    }
+
+    /// A constant representing a rectangle centered on (0, 0) with zero area.
+    pub const ZERO: CenteredRect = CenteredRect::new(Vec2::ZERO, Vec2::ZERO);
+
+    /// Returns the top left vertex.
+    #[inline]
+    pub fn top_left(&self) -> Vec2 {
+        self.center - self.half_extents
+    }
+
+    /// Returns the bottom right vertex.
+    #[inline]
+    pub fn bottom_right(&self) -> Vec2 {
+        self.center + self.half_extents
+    }
+
+    #[inline]
+    pub fn top_right(&self) -> Vec2 {
+        Vec2::new(
+            self.center.x + self.half_extents.x,
+            self.center.y - self.half_extents.y,
+        )
+    }
+
+    #[inline]
+    pub fn bottom_left(&self) -> Vec2 {
+        Vec2::new(
+            self.center.x - self.half_extents.x,
+            self.center.y + self.half_extents.y,
+        )
+    }
+
+    /// Returns the four vertices in the same order as [`Rect::corners`].
+    #[inline]
+    pub fn corners(&self) -> [Vec2; 4] {
+        [
+            self.top_left(),
+            self.top_right(),
+            self.bottom_right(),
+            self.bottom_left(),
+        ]
+    }
+
+    /// Returns the width of the rectangle.
+    #[inline]
+    pub fn width(&self) -> f32 {
+        self.half_extents.x * 2.0
+    }
+
+    /// Returns the height of the rectangle.
+    #[inline]
+    pub fn height(&self) -> f32 {
+        self.half_extents.y * 2.0
+    }
+
+    /// Returns a `Vector2` containing the width and height of the rectangle.
+    #[inline]
+    pub fn size(&self) -> Vec2 {
+        self.half_extents * 2.0
+    }
+
+    /// Returns true if the specified point is inside this rectangle, with the
+    /// same edge convention as [`Rect::contains`].
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, point: Vec2) -> bool {
+        Rect::from(*self).contains(point)
+    }
+
+    /// Finds the intersection of two rectangles, see [`Rect::intersect`].
+    #[inline]
+    #[must_use]
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        Rect::from(*self)
+            .intersect(&Rect::from(*other))
+            .map(Self::from)
+    }
+
+    /// Returns `true` if the two rectangles overlap with positive area, see
+    /// [`Rect::intersects`].
+    #[inline]
+    pub fn intersects(&self, other: &Self) -> bool {
+        let gap = (self.center - other.center).abs() - (self.half_extents + other.half_extents);
+        gap.x < 0.0 && gap.y < 0.0
+    }
+
+    /// Returns the smallest rectangle containing both this rectangle and
+    /// `other`.
+    #[inline]
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        Self::from(Rect::from(*self).union(&Rect::from(*other)))
+    }
+
+    /// Returns `true` if `other` lies entirely inside this rectangle, edges
+    /// included, see [`Rect::contains_rect`].
+    #[inline]
+    #[must_use]
+    pub fn contains_rect(&self, other: &Self) -> bool {
+        let margin = self.half_extents - other.half_extents - (self.center - other.center).abs();
+        margin.x >= 0.0 && margin.y >= 0.0
+    }
+
+    /// Returns the smallest rectangle containing both this rectangle and
+    /// `point`.
+    #[inline]
+    #[must_use]
+    pub fn union_point(&self, point: Vec2) -> Self {
+        Self::from(Rect::from(*self).union_point(point))
+    }
+
+    /// Returns the area of the rectangle.
+    #[inline]
+    pub fn area(&self) -> f32 {
+        4.0 * self.half_extents.x * self.half_extents.y
+    }
+
+    /// Returns the perimeter of the rectangle.
+    #[inline]
+    pub fn perimeter(&self) -> f32 {
+        4.0 * (self.half_extents.x + self.half_extents.y)
+    }
+
+    /// Returns the length of the rectangle's diagonal.
+    #[inline]
+    pub fn diagonal_length(&self) -> f32 {
+        self.size().length()
+    }
+
+    /// Returns the width divided by the height.
+    #[inline]
+    pub fn aspect_ratio(&self) -> f32 {
+        self.half_extents.x / self.half_extents.y
+    }
+
+    /// Returns the point inside the rectangle closest to `point`.
+    #[inline]
+    pub fn closest_point(&self, point: Vec2) -> Vec2 {
+        self.center + (point - self.center).clamp(-self.half_extents, self.half_extents)
+    }
+
+    /// Returns the point inside the rectangle, including its edges, that is
+    /// closest to `point`. This is the same as
+    /// [`closest_point`](Self::closest_point), named as in [`Rect`].
+    #[inline]
+    #[must_use]
+    pub fn clamp_point(&self, point: Vec2) -> Vec2 {
+        self.closest_point(point)
+    }
+
+    /// Returns the distance from `point` to the rectangle, which is zero for
+    /// points inside.
+    #[inline]
+    pub fn distance(&self, point: Vec2) -> f32 {
+        ((point - self.center).abs() - self.half_extents)
+            .max(Vec2::ZERO)
+            .length()
+    }
+
+    /// Returns `true` if the rectangle has zero area.
+    #[inline]
+    pub fn is_zero_area(&self) -> bool {
+        self.half_extents.x == 0.0 || self.half_extents.y == 0.0
+    }
+
+    /// Returns `true` if the rectangle has an area greater than zero.
+    #[inline]
+    pub fn is_positive_area(&self) -> bool {
+        self.half_extents.x > 0.0 && self.half_extents.y > 0.0
+    }
+
+    /// Returns `true` if all coordinates are finite.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.center.is_finite() && self.half_extents.is_finite()
+    }
+
+    /// Returns `true` if any coordinate is NaN.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.center.is_nan() || self.half_extents.is_nan()
+    }
+
+    /// Returns a new rectangle grown outwards by `insets`, see
+    /// [`Rect::inflate`]. Uneven insets move the center.
+    #[inline]
+    #[must_use]
+    pub fn inflate(&self, insets: impl Into<Insets>) -> Self {
+        Self::from(Rect::from(*self).inflate(insets))
+    }
+
+    /// Returns a new rectangle shrunk inwards by `insets`, or `None` if the
+    /// opposite edges would cross, see [`Rect::deflate`].
+    #[inline]
+    #[must_use]
+    pub fn deflate(&self, insets: impl Into<Insets>) -> Option<Self> {
+        Rect::from(*self).deflate(insets).map(Self::from)
+    }
+
+    /// Returns a new rectangle scaled by `factor` about the origin, see
+    /// [`Rect::scale`].
+    #[inline]
+    #[must_use]
+    pub fn scale(&self, factor: Vec2) -> Self {
+        self.scale_around(Vec2::ZERO, factor)
+    }
+
+    /// Returns a new rectangle scaled by `factor` about the `anchor` point,
+    /// which stays in place. Negative factors mirror the rectangle, which
+    /// stays normalized.
+    #[inline]
+    #[must_use]
+    pub fn scale_around(&self, anchor: Vec2, factor: Vec2) -> Self {
+        Self::new(
+            anchor + (self.center - anchor) * factor,
+            self.half_extents * factor.abs(),
+        )
+    }
+
+    /// Linearly interpolates the center and half extents between this
+    /// rectangle and `other`. `t = 0` returns `self` and `t = 1` returns
+    /// `other`.
+    #[inline]
+    #[must_use]
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        Self::new(
+            self.center.lerp(other.center, t),
+            self.half_extents.lerp(other.half_extents, t),
+        )
+    }
+
+    /// Returns a new rectangle moved by the specified amount.
+    #[inline]
+    pub fn with_offset(&self, offset: impl Into<Vec2>) -> Self {
+        Self::new(self.center + offset.into(), self.half_extents)
+    }
+
+    /// Returns a new rectangle moved by the negated specified amount.
+    #[inline]
+    pub fn with_negative_offset(&self, offset: impl Into<Vec2>) -> Self {
+        Self::new(self.center - offset.into(), self.half_extents)
+    }
+}
+
+/// Operations computed through [`Rect`].
+impl CenteredRect {
+    /// Returns how this rectangle and `other` overlap, see [`Rect::relation`].
+    #[inline]
+    pub fn relation(&self, other: &Self) -> RectRelation {
+        self.to_rect().relation(&other.to_rect())
+    }
+
+    /// Returns `true` if the rectangles share an edge or corner without
+    /// overlapping, see [`Rect::touches`].
+    #[inline]
+    pub fn touches(&self, other: &Self) -> bool {
+        self.to_rect().touches(&other.to_rect())
+    }
+
+    /// Returns the area shared with `other`, see [`Rect::intersection_area`].
+    #[inline]
+    pub fn intersection_area(&self, other: &Self) -> f32 {
+        self.to_rect().intersection_area(&other.to_rect())
+    }
+
+    /// Returns the intersection over union with `other`, see [`Rect::iou`].
+    #[inline]
+    pub fn iou(&self, other: &Self) -> f32 {
+        self.to_rect().iou(&other.to_rect())
+    }
+
+    /// Returns the signed distance from `point` to the border, see
+    /// [`Rect::signed_distance`].
+    #[inline]
+    pub fn signed_distance(&self, point: Vec2) -> f32 {
+        self.to_rect().signed_distance(point)
+    }
+
+    /// Returns the distance between the closest points of the two
+    /// rectangles, see [`Rect::distance_to_rect`].
+    #[inline]
+    pub fn distance_to_rect(&self, other: &Self) -> f32 {
+        self.to_rect().distance_to_rect(&other.to_rect())
+    }
+
+    /// Returns the shortest translation that makes this rectangle touch
+    /// `other`, see [`Rect::separation_vector`].
+    #[inline]
+    pub fn separation_vector(&self, other: &Self) -> Vec2 {
+        self.to_rect().separation_vector(&other.to_rect())
+    }
+
+    /// Returns the point at `anchor`, see [`Rect::point_at`].
+    #[inline]
+    pub fn point_at(&self, anchor: Anchor) -> Vec2 {
+        self.to_rect().point_at(anchor)
+    }
+
+    /// Returns a rectangle of `new_size` that keeps the point at `anchor` in
+    /// place, see [`Rect::resize_with_anchor`].
+    #[inline]
+    #[must_use]
+    pub fn resize_with_anchor(&self, new_size: Vec2, anchor: Anchor) -> Self {
+        Self::from_rect(&self.to_rect().resize_with_anchor(new_size, anchor))
+    }
+
+    /// Grows the rectangle to the aspect `ratio`, see
+    /// [`Rect::expand_to_aspect`].
+    #[inline]
+    #[must_use]
+    pub fn expand_to_aspect(&self, ratio: f32, anchor: Anchor) -> Self {
+        Self::from_rect(&self.to_rect().expand_to_aspect(ratio, anchor))
+    }
+
+    /// Returns the largest rectangle with this aspect ratio that fits inside
+    /// `container`, see [`Rect::fit_inside`].
+    #[inline]
+    #[must_use]
+    pub fn fit_inside(&self, container: &Self) -> Self {
+        Self::from_rect(&self.to_rect().fit_inside(&container.to_rect()))
+    }
+
+    /// Returns the smallest rectangle with this aspect ratio that covers
+    /// `container`, see [`Rect::fill`].
+    #[inline]
+    #[must_use]
+    pub fn fill(&self, container: &Self) -> Self {
+        Self::from_rect(&self.to_rect().fill(&container.to_rect()))
+    }
+
+    /// Moves the rectangle by the least amount that puts it inside
+    /// `container`, see [`Rect::clamp_inside`].
+    #[inline]
+    #[must_use]
+    pub fn clamp_inside(&self, container: &Self) -> Self {
+        Self::from_rect(&self.to_rect().clamp_inside(&container.to_rect()))
+    }
+
+    /// Returns the bounds of the rectangle moving by `velocity` for `dt`, see
+    /// [`Rect::swept_bounds`].
+    #[inline]
+    #[must_use]
+    pub fn swept_bounds(&self, velocity: Vec2, dt: f32) -> Self {
+        Self::from_rect(&self.to_rect().swept_bounds(velocity, dt))
+    }
+
+    /// Returns the area inside `insets`, see [`Rect::interior`].
+    #[inline]
+    #[must_use]
+    pub fn interior(&self, insets: impl Into<Insets>) -> Self {
+        Self::from_rect(&self.to_rect().interior(insets))
+    }
+
+    /// Returns the four border strips inside `insets`, see [`Rect::frame`].
+    #[inline]
+    pub fn frame(&self, insets: impl Into<Insets>) -> [Self; 4] {
+        self.to_rect()
+            .frame(insets)
+            .map(|rect| Self::from_rect(&rect))
+    }
+
+    /// Splits the rectangle into nine patches, see [`Rect::nine_slice`].
+    #[inline]
+    pub fn nine_slice(&self, insets: impl Into<Insets>) -> [Self; 9] {
+        self.to_rect()
+            .nine_slice(insets)
+            .map(|rect| Self::from_rect(&rect))
+    }
+
+    /// Maps `point` to coordinates relative to the rectangle, see
+    /// [`Rect::to_normalized`].
+    #[inline]
+    pub fn to_normalized(&self, point: Vec2) -> Vec2 {
+        self.to_rect().to_normalized(point)
+    }
+
+    /// Maps relative coordinates back to a point, see
+    /// [`Rect::from_normalized`].
+    #[inline]
+    pub fn from_normalized(&self, uv: Vec2) -> Vec2 {
+        self.to_rect().from_normalized(uv)
+    }
+
+    /// Returns the parameters where the ray enters and leaves the rectangle,
+    /// see [`Rect::intersect_ray`].
+    #[inline]
+    pub fn intersect_ray(&self, origin: Vec2, direction: Vec2) -> Option<(f32, f32)> {
+        self.to_rect().intersect_ray(origin, direction)
+    }
+
+    /// Returns the parameters where the segment from `a` to `b` enters and
+    /// leaves the rectangle, see [`Rect::intersect_segment`].
+    #[inline]
+    pub fn intersect_segment(&self, a: Vec2, b: Vec2) -> Option<(f32, f32)> {
+        self.to_rect().intersect_segment(a, b)
+    }
+
+    /// Returns the part of the segment from `a` to `b` inside the rectangle,
+    /// see [`Rect::clip_segment`].
+    #[inline]
+    pub fn clip_segment(&self, a: Vec2, b: Vec2) -> Option<(Vec2, Vec2)> {
+        self.to_rect().clip_segment(a, b)
+    }
+
+    /// Clips `polygon` to the rectangle, see [`Rect::clip_polygon`].
+    #[inline]
+    pub fn clip_polygon(&self, polygon: &[Vec2]) -> Vec<Vec2> {
+        self.to_rect().clip_polygon(polygon)
+    }
+}
+
+/// Converts to corner form. This is exact for rects whose coordinates are
+/// representable without rounding, e.g. integers and dyadic fractions of
+/// moderate magnitude.
+impl From<CenteredRect> for Rect {
+    #[inline]
+    fn from(rect: CenteredRect) -> Self {
+        Rect::new(rect.top_left(), rect.bottom_right())
+    }
+}
+
+/// Converts to center form, see the note on the reverse conversion.
+impl From<Rect> for CenteredRect {
+    #[inline]
+    fn from(rect: Rect) -> Self {
+        let half_extents = (rect.bottom_right - rect.top_left) * 0.5;
+        CenteredRect::new(rect.top_left + half_extents, half_extents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_round_trip() {
+        let rect = Rect::from_tuples((-3.0, 1.0), (5.0, 2.5));
+        let centered = CenteredRect::from(rect);
+
+        assert_eq!(centered.center, Vec2::new(1.0, 1.75));
+        assert_eq!(centered.half_extents, Vec2::new(4.0, 0.75));
+        assert_eq!(centered.size(), rect.size());
+        assert_eq!(Rect::from(centered), rect);
+    }
+
+    #[test]
+    pub fn test_queries() {
+        let a = Aabb2::new(Vec2::ZERO, Vec2::new(2.0, 1.0));
+        let b = Aabb2::new(Vec2::new(4.0, 0.0), Vec2::new(2.0, 2.0));
+        let c = Aabb2::new(Vec2::new(2.5, 0.0), Vec2::ONE);
+
+        assert!(!a.intersects(&b));
+        assert!(a.intersects(&c));
+        assert_eq!(a.area(), 8.0);
+        assert_eq!(
+            a.union(&b),
+            Aabb2::new(Vec2::new(2.0, 0.0), Vec2::new(4.0, 2.0))
+        );
+        assert_eq!(a.closest_point(Vec2::new(5.0, -0.5)), Vec2::new(2.0, -0.5));
+        assert_eq!(a.distance(Vec2::new(5.0, 5.0)), 5.0);
+        assert_eq!(a.distance(Vec2::new(1.0, 0.5)), 0.0);
+    }
+
+    #[test]
+    pub fn test_transforms() {
+        let a = Aabb2::new(Vec2::new(1.0, 1.0), Vec2::new(2.0, 1.0));
+        let rect = Rect::from(a);
+
+        assert_eq!(Rect::from(a.inflate(1.0)), rect.inflate(1.0));
+        assert_eq!(
+            a.deflate(Vec2::new(1.0, 0.5)).map(Rect::from),
+            rect.deflate(Vec2::new(1.0, 0.5))
+        );
+        assert_eq!(a.deflate(3.0), None);
+        assert_eq!(
+            Rect::from(a.scale(Vec2::new(-2.0, 0.5))),
+            rect.scale(Vec2::new(-2.0, 0.5))
+        );
+        let anchor = Vec2::new(3.0, -1.0);
+        assert_eq!(
+            Rect::from(a.scale_around(anchor, Vec2::splat(2.0))),
+            rect.scale_around(anchor, Vec2::splat(2.0))
+        );
+
+        let b = Aabb2::new(Vec2::new(3.0, 5.0), Vec2::new(4.0, 5.0));
+        assert_eq!(Rect::from(a.lerp(&b, 0.5)), rect.lerp(&Rect::from(b), 0.5));
+        assert!(b.contains_rect(&a));
+        assert!(!a.contains_rect(&b));
+        assert!(a.contains_rect(&a));
+        assert_eq!(
+            Rect::from(a.union_point(Vec2::new(5.0, -2.0))),
+            rect.union_point(Vec2::new(5.0, -2.0))
+        );
+
+        assert_eq!(
+            a.clamp_point(Vec2::new(9.0, 0.5)),
+            rect.clamp_point(Vec2::new(9.0, 0.5))
+        );
+        assert_eq!(a.perimeter(), rect.perimeter());
+        assert_eq!(a.aspect_ratio(), rect.aspect_ratio());
+        assert_eq!(a.diagonal_length(), rect.diagonal_length());
+        assert!(a.is_finite() && !a.is_nan());
+        let nan = Aabb2::new(Vec2::new(f32::NAN, 0.0), Vec2::ONE);
+        assert!(!nan.is_finite() && nan.is_nan());
+    }
+
+    #[test]
+    pub fn test_forwarded() {
+        let a = Aabb2::new(Vec2::new(2.0, 1.0), Vec2::new(2.0, 1.0));
+        let b = Aabb2::new(Vec2::new(5.0, 1.0), Vec2::new(1.0, 1.0));
+        let (ra, rb) = (a.to_rect(), b.to_rect());
+        assert_eq!(Aabb2::from_rect(&ra), a);
+
+        assert_eq!(a.relation(&b), ra.relation(&rb));
+        assert!(a.touches(&b));
+        assert_eq!(a.iou(&a), 1.0);
+        assert_eq!(a.signed_distance(Vec2::new(2.0, 1.0)), -1.0);
+        assert_eq!(a.point_at(Anchor::BottomRight), Vec2::new(4.0, 2.0));
+        assert_eq!(
+            a.resize_with_anchor(Vec2::ONE, Anchor::TopLeft).to_rect(),
+            Rect::from_tuples((0.0, 0.0), (1.0, 1.0))
+        );
+        let container = Aabb2::new(Vec2::ZERO, Vec2::splat(10.0));
+        assert_eq!(
+            a.fit_inside(&container).to_rect(),
+            ra.fit_inside(&container.to_rect())
+        );
+        assert_eq!(a.fill(&container).to_rect(), ra.fill(&container.to_rect()));
+        assert_eq!(
+            a.nine_slice(0.5).map(|patch| patch.to_rect()),
+            ra.nine_slice(0.5)
+        );
+        assert_eq!(
+            a.clip_segment(Vec2::new(-1.0, 1.0), Vec2::new(9.0, 1.0)),
+            Some((Vec2::new(0.0, 1.0), Vec2::new(4.0, 1.0)))
+        );
+        assert_eq!(
+            a.from_normalized(a.to_normalized(Vec2::new(3.0, 0.5))),
+            Vec2::new(3.0, 0.5)
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use glam::Vec2;
+
+use crate::Rect;
+
+/// A circle, for rect-versus-circle queries.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Circle {
+    pub center: Vec2,
+    pub radius: f32,
+}
+
+impl Circle {
+    /// Constructs a new `Circle`. The radius must be non-negative.
+    #[inline]
+    pub const fn new(center: Vec2, radius: f32) -> Self {
+        Self { center, radius }
+    }
+
+    /// Returns `true` if `point` is inside the circle or on its border.
+    #[inline]
+    pub fn contains(&self, point: Vec2) -> bool {
+        self.center.distance_squared(point) <= self.radius * self.radius
+    }
+
+    /// Returns the smallest rect containing the circle.
+    #[inline]
+    pub fn bounding_rect(&self) -> Rect {
+        Rect::from_center_size(self.center, Vec2::splat(self.radius * 2.0))
+    }
+}
+
+impl Rect {
+    /// Returns `true` if the rectangle and `circle` have a common area. As
+    /// with [`Rect::intersects`], shapes that only touch do not intersect.
+    #[inline]
+    #[must_use]
+    pub fn intersects_circle(&self, circle: &Circle) -> bool {
+        let closest = self.clamp_point(circle.center);
+        closest.distance_squared(circle.center) < circle.radius * circle.radius
+    }
+
+    /// Returns `true` if `circle` lies entirely inside the rectangle,
+    /// including touching its edges from the inside.
+    #[inline]
+    #[must_use]
+    pub fn contains_circle(&self, circle: &Circle) -> bool {
+        let radius = Vec2::splat(circle.radius);
+        (circle.center - radius).cmpge(self.top_left).all()
+            && (circle.center + radius).cmple(self.bottom_right).all()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_circle_queries() {
+        let rect = Rect::from_tuples((0.0, 0.0), (10.0, 10.0));
+        let circle = Circle::new(Vec2::new(12.0, 12.0), 2.5);
+        // The bounding rects overlap, but the circle misses the corner.
+        assert!(rect.intersects(&circle.bounding_rect()));
+        assert!(!rect.intersects_circle(&circle));
+        assert!(rect.intersects_circle(&Circle::new(Vec2::new(12.0, 12.0), 2.9)));
+        assert!(!rect.intersects_circle(&Circle::new(Vec2::new(12.0, 5.0), 2.0)));
+
+        assert!(rect.contains_circle(&Circle::new(Vec2::new(5.0, 5.0), 5.0)));
+        assert!(!rect.contains_circle(&Circle::new(Vec2::new(5.0, 4.0), 5.0)));
+        assert!(circle.contains(Vec2::new(12.0, 14.5)));
+    }
+}
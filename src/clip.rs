@@ -0,0 +1,82 @@
+use crate::Rect;
+
+/// A stack of nested clip rects, as for scissor rects in a renderer or the
+/// save and restore of a canvas. Each pushed rect is intersected with the
+/// one below it.
+#[derive(Debug, Clone, Default)]
+pub struct ClipStack {
+    stack: Vec<Rect>,
+}
+
+impl ClipStack {
+    /// Constructs a new empty stack, which does not clip.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of pushed rects.
+    #[inline]
+    pub fn depth(&self) -> usize {
+        self.stack.len()
+    }
+
+    /// Returns the current clip rect, or `None` if nothing is pushed and
+    /// nothing is clipped. If nested clip rects do not overlap, the clip rect
+    /// has zero area and everything is clipped.
+    #[inline]
+    pub fn current(&self) -> Option<Rect> {
+        self.stack.last().copied()
+    }
+
+    /// Pushes `rect`, intersected with the current clip rect, and returns the
+    /// new clip rect.
+    pub fn push(&mut self, rect: Rect) -> Rect {
+        let clip = match self.current() {
+            Some(current) => {
+                let top_left = current.top_left.max(rect.top_left);
+                let bottom_right = current.bottom_right.min(rect.bottom_right).max(top_left);
+                Rect::new(top_left, bottom_right)
+            }
+            None => rect,
+        };
+        self.stack.push(clip);
+        clip
+    }
+
+    /// Removes the most recently pushed clip rect and returns it, restoring
+    /// the one before.
+    #[inline]
+    pub fn pop(&mut self) -> Option<Rect> {
+        self.stack.pop()
+    }
+
+    /// Removes all clip rects.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.stack.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_clip_stack() {
+        let mut clips = ClipStack::new();
+        assert_eq!(clips.current(), None);
+        clips.push(Rect::from_tuples((0.0, 0.0), (100.0, 100.0)));
+        let panel = clips.push(Rect::from_tuples((50.0, -10.0), (150.0, 40.0)));
+        assert_eq!(panel, Rect::from_tuples((50.0, 0.0), (100.0, 40.0)));
+
+        let hidden = clips.push(Rect::from_tuples((0.0, 60.0), (20.0, 80.0)));
+        assert!(hidden.is_zero_area());
+        assert_eq!(clips.depth(), 3);
+
+        assert_eq!(clips.pop(), Some(hidden));
+        assert_eq!(clips.current(), Some(panel));
+        clips.clear();
+        assert_eq!(clips.pop(), None);
+    }
+}
@@ -0,0 +1,263 @@
+use std::error::Error;
+use std::fmt;
+
+use glam::Vec2;
+
+use crate::{Edge, Rect};
+
+/// Refers to a rect added to a [`ConstraintSolver`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct RectHandle(usize);
+
+impl RectHandle {
+    /// Returns one edge of the rect, for use in a [`Constraint`].
+    #[inline]
+    pub const fn edge(self, edge: Edge) -> EdgeVar {
+        EdgeVar { rect: self, edge }
+    }
+}
+
+/// One edge of a rect in a [`ConstraintSolver`], i.e. a single unknown
+/// coordinate.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct EdgeVar {
+    pub rect: RectHandle,
+    pub edge: Edge,
+}
+
+/// A relation that determines the first edge of each variant from the
+/// others. Constraints are directional: they assign to their first edge
+/// and never change their sources.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Constraint {
+    /// `target = value`.
+    Fixed(EdgeVar, f32),
+    /// `target = source`.
+    Equal(EdgeVar, EdgeVar),
+    /// `target = source + offset`.
+    Offset(EdgeVar, EdgeVar, f32),
+    /// `target = from + (to - from) * ratio`, placing the target at a
+    /// fraction of the way between two edges.
+    Ratio(EdgeVar, EdgeVar, EdgeVar, f32),
+    /// `target >= source + offset`.
+    Min(EdgeVar, EdgeVar, f32),
+    /// `target <= source + offset`.
+    Max(EdgeVar, EdgeVar, f32),
+}
+
+/// The reasons a [`ConstraintSolver`] can fail.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum SolveError {
+    /// The constraints kept changing the edges, usually because they depend
+    /// on each other in a cycle.
+    DidNotConverge,
+    /// The min or max constraint with the given index, counting in the order
+    /// the constraints were added, contradicts another one.
+    Conflict(usize),
+    /// The solution inverts the given rect.
+    Inverted(RectHandle),
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::DidNotConverge => f.write_str("rect constraints did not converge"),
+            SolveError::Conflict(index) => write!(f, "rect constraint {index} is unsatisfiable"),
+            SolveError::Inverted(handle) => {
+                write!(f, "rect constraints invert rect {}", handle.0)
+            }
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+/// A small constraint system for laying out rects relative to each other,
+/// for layouts that outgrow the simpler helpers.
+///
+/// Every edge starts at zero. Each pass applies the constraints in the order
+/// they were added, clamping every assigned edge with the min and max
+/// constraints on it, and passes repeat until no edge moves any more. Edges that no
+/// constraint targets stay at zero.
+#[derive(Debug, Clone, Default)]
+pub struct ConstraintSolver {
+    rect_count: usize,
+    constraints: Vec<Constraint>,
+}
+
+/// How much an edge may still move in the last pass for the solution to be
+/// considered converged.
+const TOLERANCE: f32 = 1e-4;
+
+impl ConstraintSolver {
+    /// Constructs a new solver without rects.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a rect whose edges are determined by constraints.
+    pub fn add_rect(&mut self) -> RectHandle {
+        self.rect_count += 1;
+        RectHandle(self.rect_count - 1)
+    }
+
+    /// Adds a rect with all four edges fixed to those of `rect`.
+    pub fn add_fixed_rect(&mut self, rect: &Rect) -> RectHandle {
+        let handle = self.add_rect();
+        let values = [
+            rect.top_left.x,
+            rect.top_left.y,
+            rect.bottom_right.x,
+            rect.bottom_right.y,
+        ];
+        for (edge, value) in Edge::ALL.into_iter().zip(values) {
+            self.add(Constraint::Fixed(handle.edge(edge), value));
+        }
+        handle
+    }
+
+    /// Adds a constraint.
+    pub fn add(&mut self, constraint: Constraint) {
+        self.constraints.push(constraint);
+    }
+
+    /// Resolves all rects, returned in the order they were added.
+    pub fn solve(&self) -> Result<Vec<Rect>, SolveError> {
+        let mut edges = vec![[0.0f32; 4]; self.rect_count];
+        let index = |var: EdgeVar| (var.rect.0, var.edge as usize);
+        let get = |edges: &[[f32; 4]], var: EdgeVar| {
+            let (rect, edge) = index(var);
+            edges[rect][edge]
+        };
+
+        let eval = |edges: &[[f32; 4]], constraint: &Constraint| match *constraint {
+            Constraint::Fixed(target, value) => (target, value),
+            Constraint::Equal(target, source) => (target, get(edges, source)),
+            Constraint::Offset(target, source, offset) => (target, get(edges, source) + offset),
+            Constraint::Ratio(target, from, to, ratio) => {
+                let from = get(edges, from);
+                (target, from + (get(edges, to) - from) * ratio)
+            }
+            Constraint::Min(target, source, offset) => {
+                (target, get(edges, target).max(get(edges, source) + offset))
+            }
+            Constraint::Max(target, source, offset) => {
+                (target, get(edges, target).min(get(edges, source) + offset))
+            }
+        };
+        let is_clamp = |constraint: &Constraint| {
+            matches!(constraint, Constraint::Min(..) | Constraint::Max(..))
+        };
+
+        let mut converged = false;
+        for _ in 0..self.constraints.len() + 2 {
+            let previous = edges.clone();
+            for constraint in &self.constraints {
+                let (target, value) = eval(&edges, constraint);
+                let (rect, edge) = index(target);
+                edges[rect][edge] = value;
+                if is_clamp(constraint) {
+                    continue;
+                }
+                // Clamp right away so later constraints read the clamped edge.
+                for clamp in self.constraints.iter().filter(|c| is_clamp(c)) {
+                    let (clamped, value) = eval(&edges, clamp);
+                    if clamped == target {
+                        edges[rect][edge] = value;
+                    }
+                }
+            }
+            let moved = edges
+                .iter()
+                .flatten()
+                .zip(previous.iter().flatten())
+                .fold(0.0f32, |moved, (a, b)| moved.max((a - b).abs()));
+            if moved <= TOLERANCE {
+                converged = true;
+                break;
+            }
+        }
+        if !converged {
+            return Err(SolveError::DidNotConverge);
+        }
+        // A later clamp can undo an earlier one on the same edge.
+        for (i, constraint) in self.constraints.iter().enumerate() {
+            if is_clamp(constraint) {
+                let (target, value) = eval(&edges, constraint);
+                if (get(&edges, target) - value).abs() > TOLERANCE {
+                    return Err(SolveError::Conflict(i));
+                }
+            }
+        }
+
+        edges
+            .iter()
+            .enumerate()
+            .map(|(i, [left, top, right, bottom])| {
+                Rect::try_new(Vec2::new(*left, *top), Vec2::new(*right, *bottom))
+                    .map_err(|_| SolveError::Inverted(RectHandle(i)))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_constraint_solver() {
+        let mut solver = ConstraintSolver::new();
+        // Constraints may be added before the rects they depend on are
+        // known.
+        let sidebar = solver.add_rect();
+        let content = solver.add_rect();
+        let window = solver.add_fixed_rect(&Rect::from_tuples((0.0, 0.0), (800.0, 600.0)));
+
+        use Edge::*;
+        for edge in [Left, Top, Bottom] {
+            solver.add(Constraint::Equal(sidebar.edge(edge), window.edge(edge)));
+        }
+        solver.add(Constraint::Ratio(
+            sidebar.edge(Right),
+            window.edge(Left),
+            window.edge(Right),
+            0.1,
+        ));
+        solver.add(Constraint::Min(
+            sidebar.edge(Right),
+            sidebar.edge(Left),
+            120.0,
+        ));
+        solver.add(Constraint::Offset(
+            content.edge(Left),
+            sidebar.edge(Right),
+            8.0,
+        ));
+        for edge in [Top, Right, Bottom] {
+            solver.add(Constraint::Equal(content.edge(edge), window.edge(edge)));
+        }
+
+        let rects = solver.solve().unwrap();
+        assert_eq!(rects[0], Rect::from_tuples((0.0, 0.0), (120.0, 600.0)));
+        assert_eq!(rects[1], Rect::from_tuples((128.0, 0.0), (800.0, 600.0)));
+
+        solver.add(Constraint::Max(
+            sidebar.edge(Right),
+            window.edge(Left),
+            100.0,
+        ));
+        assert_eq!(solver.solve(), Err(SolveError::Conflict(8)));
+
+        let mut solver = ConstraintSolver::new();
+        let rect = solver.add_rect();
+        solver.add(Constraint::Offset(rect.edge(Left), rect.edge(Right), 1.0));
+        solver.add(Constraint::Offset(rect.edge(Right), rect.edge(Left), 1.0));
+        assert_eq!(solver.solve(), Err(SolveError::DidNotConverge));
+
+        let mut solver = ConstraintSolver::new();
+        let rect = solver.add_rect();
+        solver.add(Constraint::Fixed(rect.edge(Left), 10.0));
+        assert_eq!(solver.solve(), Err(SolveError::Inverted(rect)));
+    }
+}
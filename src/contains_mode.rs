@@ -0,0 +1,15 @@
+/// Which edges of a rectangle count as inside it, for `contains_with` on the
+/// rect types.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub enum ContainsMode {
+    /// The top and left edges are inside and the bottom and right edges are
+    /// outside, as for pixels. This is what `contains` uses, so that rects
+    /// tiling the plane never both contain a point.
+    #[default]
+    HalfOpen,
+    /// All edges are inside, as for geometric "in the box or on its
+    /// boundary" checks.
+    Closed,
+    /// All edges are outside, so only strictly interior points are inside.
+    Open,
+}
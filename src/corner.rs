@@ -0,0 +1,40 @@
+/// One of the four corners of a rectangle.
+///
+/// The variants are in the order of the `corners` array of the rect types,
+/// see [`Corner::index`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Corner {
+    TopLeft,
+    TopRight,
+    BottomRight,
+    BottomLeft,
+}
+
+impl Corner {
+    /// All four corners, clockwise from the top left one.
+    pub const ALL: [Corner; 4] = [
+        Corner::TopLeft,
+        Corner::TopRight,
+        Corner::BottomRight,
+        Corner::BottomLeft,
+    ];
+
+    /// Returns the position of the corner in the `corners` array of the rect
+    /// types and other arrays in the same order, such as the radii of a
+    /// [`RoundedRect`](crate::RoundedRect).
+    #[inline]
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the diagonally opposite corner.
+    #[inline]
+    pub const fn opposite(self) -> Corner {
+        match self {
+            Corner::TopLeft => Corner::BottomRight,
+            Corner::TopRight => Corner::BottomLeft,
+            Corner::BottomRight => Corner::TopLeft,
+            Corner::BottomLeft => Corner::TopRight,
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use glam::{IVec2, UVec2};
+
+use crate::IRect;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Coverage {
+    Empty,
+    Partial,
+    Full,
+}
+
+/// Tracks which parts of an integer domain have been covered, for
+/// front-to-back occlusion culling.
+///
+/// Coverage is stored in a mip-like hierarchy where each cell of a level
+/// covers four cells of the level below. Fully covered or fully empty cells
+/// answer queries without looking at their children, so both marking and
+/// querying a rect only descend along the rect's boundary.
+#[derive(Debug, Clone)]
+pub struct CoveragePyramid {
+    domain: IRect,
+    /// The levels from finest to coarsest. Level `k` has cells of `2^k` by
+    /// `2^k` points, and the last level has a single cell.
+    levels: Vec<Level>,
+}
+
+#[derive(Debug, Clone)]
+struct Level {
+    size: UVec2,
+    cells: Vec<Coverage>,
+}
+
+impl CoveragePyramid {
+    /// Constructs a new pyramid over `domain` with nothing covered.
+    pub fn new(domain: IRect) -> Self {
+        let mut size = domain.size().as_uvec2().max(UVec2::ONE);
+        let mut levels = Vec::new();
+        loop {
+            levels.push(Level {
+                size,
+                cells: vec![Coverage::Empty; (size.x * size.y) as usize],
+            });
+            if size == UVec2::ONE {
+                break;
+            }
+            size = (size + 1) / 2;
+        }
+        Self { domain, levels }
+    }
+
+    /// Returns the domain the pyramid covers.
+    #[inline]
+    pub fn domain(&self) -> &IRect {
+        &self.domain
+    }
+
+    /// Marks everything as uncovered.
+    pub fn clear(&mut self) {
+        for level in &mut self.levels {
+            level.cells.fill(Coverage::Empty);
+        }
+    }
+
+    /// Marks the part of `rect` inside the domain as covered.
+    pub fn cover(&mut self, rect: &IRect) {
+        if let Some(rect) = self.local(rect) {
+            self.cover_cell(self.levels.len() - 1, UVec2::ZERO, &rect);
+        }
+    }
+
+    /// Returns `true` if every point of `rect` is covered. Points outside the
+    /// domain are never covered, while a rect with zero area is trivially
+    /// covered.
+    pub fn is_fully_covered(&self, rect: &IRect) -> bool {
+        if !rect.is_positive_area() {
+            return true;
+        }
+        match self.local(rect) {
+            Some(local) if local.size() == rect.size() => {
+                self.query_full(self.levels.len() - 1, UVec2::ZERO, &local)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if any point of `rect` is covered.
+    pub fn is_partially_covered(&self, rect: &IRect) -> bool {
+        self.local(rect)
+            .is_some_and(|local| self.query_any(self.levels.len() - 1, UVec2::ZERO, &local))
+    }
+
+    /// Clips `rect` to the domain and translates it so that the domain
+    /// starts at the origin.
+    fn local(&self, rect: &IRect) -> Option<IRect> {
+        rect.intersect(&self.domain)
+            .map(|rect| rect.with_negative_offset(self.domain.top_left))
+    }
+
+    /// Returns the area of a cell in local coordinates, clipped to the
+    /// domain.
+    fn cell_rect(&self, level: usize, cell: UVec2) -> IRect {
+        let start = (cell << level as u32).as_ivec2();
+        let end = ((cell + 1) << level as u32)
+            .as_ivec2()
+            .min(self.domain.size().max(IVec2::ONE));
+        IRect::new(start, end)
+    }
+
+    fn state(&self, level: usize, cell: UVec2) -> Coverage {
+        let level = &self.levels[level];
+        level.cells[(cell.y * level.size.x + cell.x) as usize]
+    }
+
+    fn children(&self, level: usize, cell: UVec2) -> impl Iterator<Item = UVec2> {
+        let size = self.levels[level - 1].size;
+        [UVec2::ZERO, UVec2::X, UVec2::Y, UVec2::ONE]
+            .into_iter()
+            .map(move |offset| cell * 2 + offset)
+            .filter(move |child| child.x < size.x && child.y < size.y)
+    }
+
+    fn cover_cell(&mut self, level: usize, cell: UVec2, rect: &IRect) {
+        let area = self.cell_rect(level, cell);
+        let state = match area.intersect(rect) {
+            None => return,
+            Some(_) if self.state(level, cell) == Coverage::Full => return,
+            Some(overlap) if overlap == area => Coverage::Full,
+            Some(_) => {
+                let children: Vec<_> = self.children(level, cell).collect();
+                for &child in &children {
+                    self.cover_cell(level - 1, child, rect);
+                }
+                if children
+                    .iter()
+                    .all(|&child| self.state(level - 1, child) == Coverage::Full)
+                {
+                    Coverage::Full
+                } else {
+                    Coverage::Partial
+                }
+            }
+        };
+        let level = &mut self.levels[level];
+        level.cells[(cell.y * level.size.x + cell.x) as usize] = state;
+    }
+
+    fn query_full(&self, level: usize, cell: UVec2, rect: &IRect) -> bool {
+        if !self.cell_rect(level, cell).intersects(rect) {
+            return true;
+        }
+        match self.state(level, cell) {
+            Coverage::Full => true,
+            Coverage::Empty => false,
+            Coverage::Partial => self
+                .children(level, cell)
+                .all(|child| self.query_full(level - 1, child, rect)),
+        }
+    }
+
+    fn query_any(&self, level: usize, cell: UVec2, rect: &IRect) -> bool {
+        let area = self.cell_rect(level, cell);
+        let Some(overlap) = area.intersect(rect) else {
+            return false;
+        };
+        match self.state(level, cell) {
+            Coverage::Full => true,
+            Coverage::Empty => false,
+            Coverage::Partial if overlap == area => true,
+            Coverage::Partial => self
+                .children(level, cell)
+                .any(|child| self.query_any(level - 1, child, rect)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_coverage_pyramid() {
+        let mut pyramid = CoveragePyramid::new(IRect::from_tuples((-10, -10), (27, 17)));
+        pyramid.cover(&IRect::from_tuples((-3, -2), (5, 6)));
+        pyramid.cover(&IRect::from_tuples((5, -2), (9, 6)));
+
+        assert!(pyramid.is_fully_covered(&IRect::from_tuples((-3, -2), (9, 6))));
+        assert!(pyramid.is_fully_covered(&IRect::from_tuples((4, 0), (6, 1))));
+        assert!(!pyramid.is_fully_covered(&IRect::from_tuples((4, 0), (10, 1))));
+        assert!(pyramid.is_partially_covered(&IRect::from_tuples((8, 5), (20, 16))));
+        assert!(!pyramid.is_partially_covered(&IRect::from_tuples((9, 5), (20, 16))));
+        assert!(!pyramid.is_fully_covered(&IRect::from_tuples((-20, -2), (0, 0))));
+
+        // Covering everything, including outside the domain.
+        pyramid.cover(&IRect::from_tuples((-100, -100), (100, 100)));
+        assert!(pyramid.is_fully_covered(pyramid.domain()));
+        pyramid.clear();
+        assert!(!pyramid.is_partially_covered(pyramid.domain()));
+    }
+}
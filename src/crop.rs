@@ -0,0 +1,143 @@
+use glam::UVec2;
+
+use crate::{IRect, URect};
+
+/// What [`crop_with_policy`] does when the requested rect reaches outside
+/// the image.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum CropPolicy<T> {
+    /// Shrink the crop to the part inside the image.
+    Clamp,
+    /// Fail unless the requested rect is entirely inside the image.
+    Reject,
+    /// Keep the requested size and fill the part outside the image with the
+    /// given value.
+    PadBy(T),
+}
+
+/// The result of [`crop_with_policy`]: copy the `source` pixels of the image
+/// to `destination` in an output of `size`, filling the rest with `fill`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Crop<T> {
+    /// The pixels to copy from the image. This has zero area if a padded
+    /// crop lies entirely outside the image.
+    pub source: URect,
+    /// Where the top left pixel of `source` goes in the output.
+    pub destination: UVec2,
+    /// The size of the output.
+    pub size: UVec2,
+    /// The value for output pixels not covered by `source`, if there are
+    /// any.
+    pub fill: Option<T>,
+}
+
+/// Crops `requested` out of an image covering `image_bounds`, handling the
+/// parts of it outside the image according to `policy`. Returns `None` if
+/// the policy rejects the request, or if a clamped crop would be empty.
+///
+/// `requested` is expected to be normalized.
+pub fn crop_with_policy<T>(
+    image_bounds: &URect,
+    requested: &IRect,
+    policy: CropPolicy<T>,
+) -> Option<Crop<T>> {
+    let clamp =
+        |value: i32, min: u32, max: u32| (value as i64).clamp(min as i64, max as i64) as u32;
+    let top_left = UVec2::new(
+        clamp(
+            requested.top_left.x,
+            image_bounds.top_left.x,
+            image_bounds.bottom_right.x,
+        ),
+        clamp(
+            requested.top_left.y,
+            image_bounds.top_left.y,
+            image_bounds.bottom_right.y,
+        ),
+    );
+    let bottom_right = UVec2::new(
+        clamp(
+            requested.bottom_right.x,
+            top_left.x,
+            image_bounds.bottom_right.x,
+        ),
+        clamp(
+            requested.bottom_right.y,
+            top_left.y,
+            image_bounds.bottom_right.y,
+        ),
+    );
+    let source = URect::new(top_left, bottom_right);
+    let size = requested.size().as_uvec2();
+    let inside = source.size() == size;
+
+    match policy {
+        CropPolicy::Clamp => source.is_positive_area().then_some(Crop {
+            source,
+            destination: UVec2::ZERO,
+            size: source.size(),
+            fill: None,
+        }),
+        CropPolicy::Reject => inside.then_some(Crop {
+            source,
+            destination: UVec2::ZERO,
+            size,
+            fill: None,
+        }),
+        CropPolicy::PadBy(value) => {
+            let offset = |start: u32, requested: i32| {
+                if source.is_positive_area() {
+                    (start as i64 - requested as i64) as u32
+                } else {
+                    0
+                }
+            };
+            Some(Crop {
+                source,
+                destination: UVec2::new(
+                    offset(source.top_left.x, requested.top_left.x),
+                    offset(source.top_left.y, requested.top_left.y),
+                ),
+                size,
+                fill: (!inside).then_some(value),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_crop_with_policy() {
+        let image = URect::from_tuples((0, 0), (100, 50));
+        let inside = IRect::from_tuples((10, 10), (30, 20));
+        let overhanging = IRect::from_tuples((-10, 40), (20, 60));
+        let outside = IRect::from_tuples((200, 0), (210, 10));
+
+        let crop = crop_with_policy(&image, &inside, CropPolicy::<u8>::Reject).unwrap();
+        assert_eq!(crop.source, URect::from_tuples((10, 10), (30, 20)));
+        assert_eq!(crop.size, UVec2::new(20, 10));
+        assert!(crop_with_policy(&image, &overhanging, CropPolicy::<u8>::Reject).is_none());
+
+        let crop = crop_with_policy(&image, &overhanging, CropPolicy::<u8>::Clamp).unwrap();
+        assert_eq!(crop.source, URect::from_tuples((0, 40), (20, 50)));
+        assert_eq!(crop.destination, UVec2::ZERO);
+        assert_eq!(crop.size, UVec2::new(20, 10));
+        assert!(crop_with_policy(&image, &outside, CropPolicy::<u8>::Clamp).is_none());
+
+        let crop = crop_with_policy(&image, &overhanging, CropPolicy::PadBy(0u8)).unwrap();
+        assert_eq!(crop.source, URect::from_tuples((0, 40), (20, 50)));
+        assert_eq!(crop.destination, UVec2::new(10, 0));
+        assert_eq!(crop.size, UVec2::new(30, 20));
+        assert_eq!(crop.fill, Some(0));
+
+        let crop = crop_with_policy(&image, &inside, CropPolicy::PadBy(0u8)).unwrap();
+        assert_eq!(crop.fill, None);
+        let crop = crop_with_policy(&image, &outside, CropPolicy::PadBy(0u8)).unwrap();
+        assert!(crop.source.is_zero_area());
+        assert_eq!(crop.destination, UVec2::ZERO);
+        assert_eq!(crop.size, UVec2::new(10, 10));
+    }
+}
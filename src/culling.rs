@@ -0,0 +1,111 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use crate::Rect;
+
+/// The result of updating an item in a [`HysteresisCuller`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Visibility {
+    /// The item became visible in this update.
+    Entered,
+    /// The item was and still is visible.
+    Visible,
+    /// The item stopped being visible in this update.
+    Exited,
+    /// The item was and still is hidden.
+    Hidden,
+}
+
+impl Visibility {
+    /// Returns `true` for [`Visibility::Entered`] and [`Visibility::Visible`].
+    #[inline]
+    pub fn is_visible(self) -> bool {
+        matches!(self, Visibility::Entered | Visibility::Visible)
+    }
+}
+
+/// Culls items against a camera rect with separate margins for becoming
+/// visible and becoming hidden, so that items near the edge of the view do
+/// not flicker in and out as the camera moves slightly.
+///
+/// A hidden item becomes visible once it intersects the camera rect grown by
+/// the cull-in margin, and stays visible until it no longer intersects the
+/// camera rect grown by the larger cull-out margin.
+#[derive(Debug, Clone)]
+pub struct HysteresisCuller<K> {
+    cull_in_margin: f32,
+    cull_out_margin: f32,
+    visible: HashSet<K>,
+}
+
+impl<K: Eq + Hash> HysteresisCuller<K> {
+    /// Constructs a new culler with every item hidden.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cull_out_margin` is smaller than `cull_in_margin`.
+    pub fn new(cull_in_margin: f32, cull_out_margin: f32) -> Self {
+        assert!(
+            cull_out_margin >= cull_in_margin,
+            "the cull-out margin must not be smaller than the cull-in margin"
+        );
+        Self {
+            cull_in_margin,
+            cull_out_margin,
+            visible: HashSet::new(),
+        }
+    }
+
+    /// Updates the visibility of the item `key` with bounds `bounds`.
+    pub fn update(&mut self, camera: &Rect, key: K, bounds: &Rect) -> Visibility {
+        if self.visible.contains(&key) {
+            if bounds.intersects(&camera.inflate(self.cull_out_margin)) {
+                Visibility::Visible
+            } else {
+                self.visible.remove(&key);
+                Visibility::Exited
+            }
+        } else if bounds.intersects(&camera.inflate(self.cull_in_margin)) {
+            self.visible.insert(key);
+            Visibility::Entered
+        } else {
+            Visibility::Hidden
+        }
+    }
+
+    /// Returns `true` if the item was visible after its last update.
+    pub fn is_visible(&self, key: &K) -> bool {
+        self.visible.contains(key)
+    }
+
+    /// Forgets the item `key`, e.g. when it is despawned.
+    pub fn remove(&mut self, key: &K) {
+        self.visible.remove(key);
+    }
+
+    /// Iterates over the currently visible items.
+    pub fn visible(&self) -> impl Iterator<Item = &K> {
+        self.visible.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::Vec2;
+
+    use super::*;
+
+    #[test]
+    pub fn test_hysteresis() {
+        let mut culler = HysteresisCuller::new(0.0, 10.0);
+        let item = Rect::from_tuples((100.0, 0.0), (110.0, 10.0));
+        let camera = |x: f32| Rect::from_position_size(Vec2::new(x, 0.0), Vec2::new(100.0, 100.0));
+
+        assert_eq!(culler.update(&camera(-5.0), 1, &item), Visibility::Hidden);
+        assert_eq!(culler.update(&camera(5.0), 1, &item), Visibility::Entered);
+        // Moving back slightly keeps the item visible thanks to the margin.
+        assert_eq!(culler.update(&camera(-5.0), 1, &item), Visibility::Visible);
+        assert_eq!(culler.update(&camera(-15.0), 1, &item), Visibility::Exited);
+        assert!(!culler.is_visible(&1));
+    }
+}
@@ -0,0 +1,121 @@
+use crate::Rect;
+
+/// Accumulates the dirty rects of a frame for a software renderer or
+/// embedded UI to redraw.
+///
+/// Rects that overlap, or are within `merge_distance` of each other, are
+/// merged into their union, trading a little overdraw for fewer draw calls.
+/// If more than `max_rects` separate rects remain, they all collapse into a
+/// single union.
+#[derive(Debug, Clone)]
+pub struct DamageTracker {
+    rects: Vec<Rect>,
+    max_rects: usize,
+    merge_distance: f32,
+}
+
+impl DamageTracker {
+    /// Constructs a new tracker without damage.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_rects` is zero.
+    pub fn new(max_rects: usize, merge_distance: f32) -> Self {
+        assert!(
+            max_rects > 0,
+            "the tracker needs room for at least one rect"
+        );
+        Self {
+            rects: Vec::new(),
+            max_rects,
+            merge_distance,
+        }
+    }
+
+    /// Returns `true` if nothing was damaged.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.rects.is_empty()
+    }
+
+    /// Returns the damaged rects, which never overlap each other.
+    #[inline]
+    pub fn rects(&self) -> &[Rect] {
+        &self.rects
+    }
+
+    /// Returns the smallest rect containing all damage, or `None` if there
+    /// is none.
+    pub fn bounds(&self) -> Option<Rect> {
+        self.rects.iter().copied().reduce(|a, b| a.union(&b))
+    }
+
+    /// Marks `rect` as damaged. Rects without area are ignored.
+    pub fn add(&mut self, rect: Rect) {
+        if !rect.is_positive_area() {
+            return;
+        }
+        let mut merged = rect;
+        while let Some(index) = self
+            .rects
+            .iter()
+            .position(|other| self.is_near(&merged, other))
+        {
+            merged = merged.union(&self.rects.swap_remove(index));
+        }
+        self.rects.push(merged);
+        if self.rects.len() > self.max_rects {
+            let bounds = self.bounds().unwrap();
+            self.rects.clear();
+            self.rects.push(bounds);
+        }
+    }
+
+    /// Removes all damage.
+    pub fn clear(&mut self) {
+        self.rects.clear();
+    }
+
+    /// Returns the damaged rects and clears the tracker for the next frame.
+    pub fn take(&mut self) -> Vec<Rect> {
+        std::mem::take(&mut self.rects)
+    }
+
+    fn is_near(&self, a: &Rect, b: &Rect) -> bool {
+        a.inflate(self.merge_distance).intersects(b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_damage_tracker() {
+        let mut damage = DamageTracker::new(3, 2.0);
+        damage.add(Rect::from_tuples((0.0, 0.0), (10.0, 10.0)));
+        damage.add(Rect::from_tuples((5.0, 5.0), (15.0, 15.0)));
+        assert_eq!(
+            damage.rects(),
+            [Rect::from_tuples((0.0, 0.0), (15.0, 15.0))]
+        );
+
+        // Within the merge distance of the first rect.
+        damage.add(Rect::from_tuples((16.0, 0.0), (20.0, 4.0)));
+        assert_eq!(damage.rects().len(), 1);
+
+        damage.add(Rect::from_tuples((50.0, 50.0), (60.0, 60.0)));
+        damage.add(Rect::from_tuples((100.0, 0.0), (110.0, 10.0)));
+        damage.add(Rect::from_tuples((0.0, 0.0), (0.0, 100.0)));
+        assert_eq!(damage.rects().len(), 3);
+
+        damage.add(Rect::from_tuples((0.0, 100.0), (1.0, 101.0)));
+        assert_eq!(
+            damage.rects(),
+            [Rect::from_tuples((0.0, 0.0), (110.0, 101.0))]
+        );
+
+        assert_eq!(damage.take().len(), 1);
+        assert!(damage.is_empty());
+    }
+}
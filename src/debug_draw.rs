@@ -0,0 +1,192 @@
+use glam::Vec2;
+
+use crate::{DRect, IRect, RTree, Rect, RectPacker, Region, SpatialGrid, URect};
+
+/// A sink for debug geometry. Implement it on top of an engine's immediate
+/// mode drawing API to visualize the structures in this crate.
+pub trait DebugDraw {
+    /// Draws a line segment from `start` to `end`.
+    fn draw_line(&mut self, start: Vec2, end: Vec2);
+
+    /// Draws a text label with its top left corner at `position`.
+    fn draw_text(&mut self, position: Vec2, text: &str);
+
+    /// Draws the outline of a rectangle. By default this draws the four edges
+    /// with [`DebugDraw::draw_line`], clockwise from the top left vertex.
+    fn draw_rect(&mut self, rect: &Rect) {
+        let corners = (*rect).corners();
+        for i in 0..4 {
+            self.draw_line(corners[i], corners[(i + 1) % 4]);
+        }
+    }
+}
+
+/// A type that can render itself into a [`DebugDraw`] sink.
+pub trait DebugVisualize {
+    /// Draws a visualization of `self` into `draw`.
+    fn debug_draw<D: DebugDraw + ?Sized>(&self, draw: &mut D);
+}
+
+impl DebugVisualize for Rect {
+    fn debug_draw<D: DebugDraw + ?Sized>(&self, draw: &mut D) {
+        draw.draw_rect(self);
+    }
+}
+
+impl DebugVisualize for DRect {
+    fn debug_draw<D: DebugDraw + ?Sized>(&self, draw: &mut D) {
+        draw.draw_rect(&self.as_rect());
+    }
+}
+
+impl DebugVisualize for URect {
+    fn debug_draw<D: DebugDraw + ?Sized>(&self, draw: &mut D) {
+        draw.draw_rect(&self.as_rect());
+    }
+}
+
+impl DebugVisualize for IRect {
+    fn debug_draw<D: DebugDraw + ?Sized>(&self, draw: &mut D) {
+        draw.draw_rect(&self.as_rect());
+    }
+}
+
+/// Draws the atlas bounds, each free rect, and the free area as a label at
+/// the top left of the bounds.
+impl DebugVisualize for RectPacker {
+    fn debug_draw<D: DebugDraw + ?Sized>(&self, draw: &mut D) {
+        let bounds = self.bounds().as_rect();
+        draw.draw_rect(&bounds);
+        for free in self.free_rects() {
+            draw.draw_rect(&free.as_rect());
+        }
+        draw.draw_text(bounds.top_left, &format!("free: {}", self.free_area()));
+    }
+}
+
+/// Draws each of the disjoint rects making up the region.
+impl DebugVisualize for Region {
+    fn debug_draw<D: DebugDraw + ?Sized>(&self, draw: &mut D) {
+        for rect in self {
+            draw.draw_rect(rect);
+        }
+    }
+}
+
+/// Draws the bounds of every node, from the leaves up to the root.
+impl<T> DebugVisualize for RTree<T> {
+    fn debug_draw<D: DebugDraw + ?Sized>(&self, draw: &mut D) {
+        for bounds in self.node_bounds() {
+            draw.draw_rect(&bounds);
+        }
+    }
+}
+
+/// Draws the bounds of every cell that holds at least one item.
+impl<T> DebugVisualize for SpatialGrid<T> {
+    fn debug_draw<D: DebugDraw + ?Sized>(&self, draw: &mut D) {
+        for cell in self.occupied_cells() {
+            draw.draw_rect(&cell);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::UVec2;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Recorder {
+        lines: Vec<(Vec2, Vec2)>,
+        texts: Vec<String>,
+    }
+
+    impl DebugDraw for Recorder {
+        fn draw_line(&mut self, start: Vec2, end: Vec2) {
+            self.lines.push((start, end));
+        }
+
+        fn draw_text(&mut self, _position: Vec2, text: &str) {
+            self.texts.push(text.to_owned());
+        }
+    }
+
+    #[test]
+    pub fn test_draw_rect_outline() {
+        let mut recorder = Recorder::default();
+        IRect::from_tuples((0, 0), (2, 1)).debug_draw(&mut recorder);
+
+        assert_eq!(
+            recorder.lines,
+            vec![
+                (Vec2::new(0.0, 0.0), Vec2::new(2.0, 0.0)),
+                (Vec2::new(2.0, 0.0), Vec2::new(2.0, 1.0)),
+                (Vec2::new(2.0, 1.0), Vec2::new(0.0, 1.0)),
+                (Vec2::new(0.0, 1.0), Vec2::new(0.0, 0.0)),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn test_draw_structures() {
+        let mut packer = RectPacker::new(URect::from_tuples((0, 0), (8, 8)));
+        packer.allocate(UVec2::new(8, 4)).unwrap();
+        let mut recorder = Recorder::default();
+        packer.debug_draw(&mut recorder);
+        assert_eq!(recorder.lines.len(), 4 * (1 + packer.free_rects().len()));
+        assert_eq!(recorder.texts, ["free: 32"]);
+
+        let region: Region = [
+            Rect::from_tuples((0.0, 0.0), (1.0, 1.0)),
+            Rect::from_tuples((2.0, 0.0), (3.0, 1.0)),
+        ]
+        .into_iter()
+        .collect();
+        let mut recorder = Recorder::default();
+        region.debug_draw(&mut recorder);
+        assert_eq!(recorder.lines.len(), 8);
+        assert!(recorder.texts.is_empty());
+    }
+
+    #[test]
+    pub fn test_draw_rtree() {
+        let items = (0..20).map(|i| {
+            let position = Vec2::new(i as f32 * 2.0, 0.0);
+            (Rect::from_position_size(position, Vec2::ONE), i)
+        });
+        let tree = RTree::new(items);
+        let mut recorder = Recorder::default();
+        tree.debug_draw(&mut recorder);
+        // Two leaves of at most 16 items under the root.
+        assert_eq!(recorder.lines.len(), 4 * 3);
+        let root = tree.bounds().unwrap();
+        assert!(recorder
+            .lines
+            .iter()
+            .all(|&(start, end)| root.contains_inclusive(start) && root.contains_inclusive(end)));
+
+        let mut recorder = Recorder::default();
+        RTree::<()>::new([]).debug_draw(&mut recorder);
+        assert!(recorder.lines.is_empty());
+    }
+
+    #[test]
+    pub fn test_draw_spatial_grid() {
+        let mut grid = SpatialGrid::new(Vec2::splat(10.0));
+        let wide = grid.insert(Rect::from_tuples((1.0, 1.0), (15.0, 5.0)), 0);
+        grid.insert(Rect::from_tuples((31.0, 31.0), (32.0, 32.0)), 1);
+        let mut recorder = Recorder::default();
+        grid.debug_draw(&mut recorder);
+        assert_eq!(recorder.lines.len(), 4 * 3);
+        assert!(recorder
+            .lines
+            .contains(&(Vec2::new(30.0, 30.0), Vec2::new(40.0, 30.0))));
+
+        grid.remove(wide);
+        let mut recorder = Recorder::default();
+        grid.debug_draw(&mut recorder);
+        assert_eq!(recorder.lines.len(), 4);
+    }
+}
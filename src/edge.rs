@@ -0,0 +1,24 @@
+/// One of the four edges of a rectangle.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Edge {
+    Left,
+    Top,
+    Right,
+    Bottom,
+}
+
+impl Edge {
+    /// All four edges, clockwise from the left one.
+    pub const ALL: [Edge; 4] = [Edge::Left, Edge::Top, Edge::Right, Edge::Bottom];
+
+    /// Returns the edge on the other side of the rectangle.
+    #[inline]
+    pub const fn opposite(self) -> Edge {
+        match self {
+            Edge::Left => Edge::Right,
+            Edge::Top => Edge::Bottom,
+            Edge::Right => Edge::Left,
+            Edge::Bottom => Edge::Top,
+        }
+    }
+}
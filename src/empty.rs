@@ -0,0 +1,141 @@
+use glam::Vec2;
+
+use crate::Rect;
+
+/// Calls `visit` with every rect inside `container` that avoids `obstacles`
+/// and spans whole columns and maximal runs of rows of the grid formed by
+/// the edges of the container and obstacles. Every maximal empty rect is
+/// among them. Stops early once `visit` returns `true`.
+fn visit_empty_rects(container: &Rect, obstacles: &[Rect], mut visit: impl FnMut(Rect) -> bool) {
+    let clamp = |v: f32, min: f32, max: f32| v.max(min).min(max);
+    let mut xs = vec![container.left(), container.right()];
+    let mut ys = vec![container.top(), container.bottom()];
+    let obstacles: Vec<Rect> = obstacles
+        .iter()
+        .filter(|obstacle| obstacle.intersects(container))
+        .copied()
+        .collect();
+    for obstacle in &obstacles {
+        xs.push(clamp(obstacle.left(), container.left(), container.right()));
+        xs.push(clamp(obstacle.right(), container.left(), container.right()));
+        ys.push(clamp(obstacle.top(), container.top(), container.bottom()));
+        ys.push(clamp(
+            obstacle.bottom(),
+            container.top(),
+            container.bottom(),
+        ));
+    }
+    for values in [&mut xs, &mut ys] {
+        values.sort_by(f32::total_cmp);
+        values.dedup();
+    }
+    let (columns, rows) = (xs.len() - 1, ys.len() - 1);
+    let mut blocked = vec![false; columns * rows];
+    for obstacle in &obstacles {
+        let x0 = xs.partition_point(|&x| x < obstacle.left());
+        let x1 = xs.partition_point(|&x| x < obstacle.right()).min(columns);
+        let y0 = ys.partition_point(|&y| y < obstacle.top());
+        let y1 = ys.partition_point(|&y| y < obstacle.bottom()).min(rows);
+        for row in y0..y1 {
+            blocked[row * columns + x0..row * columns + x1].fill(true);
+        }
+    }
+
+    let mut free_rows = vec![true; rows];
+    for first in 0..columns {
+        free_rows.fill(true);
+        for last in first..columns {
+            for (row, free) in free_rows.iter_mut().enumerate() {
+                *free &= !blocked[row * columns + last];
+            }
+            let mut row = 0;
+            while row < rows {
+                if !free_rows[row] {
+                    row += 1;
+                    continue;
+                }
+                let start = row;
+                while row < rows && free_rows[row] {
+                    row += 1;
+                }
+                let rect = Rect::from_tuples((xs[first], ys[start]), (xs[last + 1], ys[row]));
+                if visit(rect) {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// Returns the largest rect by area inside `container` that does not overlap
+/// any of `obstacles`, or `None` if there is no empty space. Rects that only
+/// touch an obstacle do not overlap it.
+///
+/// This takes `O(n³)` time for `n` obstacles inside the container, which is
+/// fine for placing popups or structures among a few hundred obstacles.
+pub fn largest_empty_rect(container: &Rect, obstacles: &[Rect]) -> Option<Rect> {
+    if !container.is_positive_area() {
+        return None;
+    }
+    let mut largest: Option<Rect> = None;
+    visit_empty_rects(container, obstacles, |rect| {
+        if largest.is_none_or(|largest| rect.area() > largest.area()) {
+            largest = Some(rect);
+        }
+        false
+    });
+    largest
+}
+
+/// Places the largest of `sizes` by area that fits inside `container` without
+/// overlapping any of `obstacles`, returning where it was placed, or `None`
+/// if none fits. The rect is placed at the top left of an empty area.
+pub fn largest_fit(container: &Rect, obstacles: &[Rect], sizes: &[Vec2]) -> Option<Rect> {
+    if !container.is_positive_area() {
+        return None;
+    }
+    let mut sizes = sizes.to_vec();
+    sizes.sort_by(|a, b| (b.x * b.y).total_cmp(&(a.x * a.y)));
+    sizes.into_iter().find_map(|size| {
+        let mut placed = None;
+        visit_empty_rects(container, obstacles, |rect| {
+            if rect.width() >= size.x && rect.height() >= size.y {
+                placed = Some(Rect::from_position_size(rect.top_left, size));
+            }
+            placed.is_some()
+        });
+        placed
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_largest_empty_rect() {
+        let container = Rect::from_tuples((0.0, 0.0), (10.0, 10.0));
+        let obstacles = [
+            Rect::from_tuples((0.0, 0.0), (4.0, 4.0)),
+            Rect::from_tuples((7.0, 6.0), (12.0, 12.0)),
+            Rect::from_tuples((20.0, 0.0), (30.0, 30.0)),
+        ];
+        assert_eq!(
+            largest_empty_rect(&container, &obstacles),
+            Some(Rect::from_tuples((0.0, 4.0), (7.0, 10.0)))
+        );
+        assert_eq!(largest_empty_rect(&container, &[]), Some(container));
+        assert_eq!(largest_empty_rect(&container, &[container]), None);
+
+        let sizes = [
+            Vec2::new(5.0, 5.0),
+            Vec2::new(2.0, 8.0),
+            Vec2::new(1.0, 1.0),
+        ];
+        assert_eq!(
+            largest_fit(&container, &obstacles, &sizes),
+            Some(Rect::from_tuples((0.0, 4.0), (5.0, 9.0)))
+        );
+        assert_eq!(largest_fit(&container, &[container], &sizes), None);
+    }
+}
@@ -0,0 +1,93 @@
+use std::error::Error;
+use std::fmt;
+
+/// The reasons a rect can be rejected by a checked constructor such as
+/// [`Rect::try_new`](crate::Rect::try_new).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum RectError {
+    /// The top left vertex is below or to the right of the bottom right
+    /// vertex.
+    Inverted,
+    /// A coordinate is NaN or infinite.
+    NonFinite,
+    /// The width or height does not fit in the scalar type.
+    Overflow,
+}
+
+impl fmt::Display for RectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RectError::Inverted => {
+                f.write_str("top left vertex is not above and to the left of bottom right vertex")
+            }
+            RectError::NonFinite => f.write_str("rect has non-finite coordinates"),
+            RectError::Overflow => f.write_str("rect size overflows its scalar type"),
+        }
+    }
+}
+
+impl Error for RectError {}
+
+/// The reasons parsing a rect from a string can fail, see the `FromStr`
+/// implementation of [`Rect`](crate::Rect).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum ParseRectError {
+    /// The string is not in one of the accepted forms.
+    Syntax,
+    /// A coordinate or size is not a valid number.
+    Number,
+    /// The numbers do not describe a valid rect.
+    Invalid(RectError),
+}
+
+impl fmt::Display for ParseRectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseRectError::Syntax => {
+                f.write_str("expected a rect as `x, y, w, h`, `x, y, wxh`, or `x1, y1..x2, y2`")
+            }
+            ParseRectError::Number => f.write_str("invalid number in rect"),
+            ParseRectError::Invalid(error) => write!(f, "invalid rect: {error}"),
+        }
+    }
+}
+
+impl Error for ParseRectError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseRectError::Invalid(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<RectError> for ParseRectError {
+    fn from(error: RectError) -> Self {
+        ParseRectError::Invalid(error)
+    }
+}
+
+/// The reasons converting between [`IRect`](crate::IRect) and
+/// [`URect`](crate::URect) with `TryFrom` can fail.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum RectConversionError {
+    /// A coordinate is negative, which `URect` cannot represent.
+    Negative,
+    /// A coordinate is above `i32::MAX`, which `IRect` cannot represent.
+    TooLarge,
+}
+
+impl fmt::Display for RectConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RectConversionError::Negative => {
+                f.write_str("rect has negative coordinates, which are not unsigned")
+            }
+            RectConversionError::TooLarge => {
+                f.write_str("rect has coordinates above i32::MAX, which are not signed")
+            }
+        }
+    }
+}
+
+impl Error for RectConversionError {}
@@ -0,0 +1,149 @@
+//! `extern "C"` entry points over the `#[repr(C)]` rect types, for use from C
+//! and C++. The signatures only use plain structs, scalars and nullable
+//! pointers so that headers can be generated with cbindgen.
+
+use glam::{IVec2, UVec2, Vec2};
+
+use crate::{IRect, Rect, RectPacker, URect};
+
+/// Writes the intersection of `a` and `b` to `out` (if it is not null) and
+/// returns `true`, or returns `false` if the rectangles have no common area.
+#[no_mangle]
+pub extern "C" fn glam_rect_intersect(a: Rect, b: Rect, out: Option<&mut Rect>) -> bool {
+    write_result(a.intersect(&b), out)
+}
+
+/// Writes the intersection of `a` and `b` to `out` (if it is not null) and
+/// returns `true`, or returns `false` if the rectangles have no common area.
+#[no_mangle]
+pub extern "C" fn glam_urect_intersect(a: URect, b: URect, out: Option<&mut URect>) -> bool {
+    write_result(a.intersect(&b), out)
+}
+
+/// Writes the intersection of `a` and `b` to `out` (if it is not null) and
+/// returns `true`, or returns `false` if the rectangles have no common area.
+#[no_mangle]
+pub extern "C" fn glam_irect_intersect(a: IRect, b: IRect, out: Option<&mut IRect>) -> bool {
+    write_result(a.intersect(&b), out)
+}
+
+/// Returns the smallest rectangle containing both `a` and `b`.
+#[no_mangle]
+pub extern "C" fn glam_rect_union(a: Rect, b: Rect) -> Rect {
+    a.union(&b)
+}
+
+/// Returns the smallest rectangle containing both `a` and `b`.
+#[no_mangle]
+pub extern "C" fn glam_urect_union(a: URect, b: URect) -> URect {
+    a.union(&b)
+}
+
+/// Returns the smallest rectangle containing both `a` and `b`.
+#[no_mangle]
+pub extern "C" fn glam_irect_union(a: IRect, b: IRect) -> IRect {
+    a.union(&b)
+}
+
+/// Returns true if `point` is inside `rect`. See [`Rect::contains`].
+#[no_mangle]
+pub extern "C" fn glam_rect_contains(rect: Rect, point: Vec2) -> bool {
+    rect.contains(point)
+}
+
+/// Returns true if `point` is inside `rect`. See [`URect::contains`].
+#[no_mangle]
+pub extern "C" fn glam_urect_contains(rect: URect, point: UVec2) -> bool {
+    rect.contains(point)
+}
+
+/// Returns true if `point` is inside `rect`. See [`IRect::contains`].
+#[no_mangle]
+pub extern "C" fn glam_irect_contains(rect: IRect, point: IVec2) -> bool {
+    rect.contains(point)
+}
+
+/// Packs `len` sizes read from `sizes` into `bounds` with a [`RectPacker`],
+/// writing the placed rects to the matching slots of `out`. Packing stops at
+/// the first size that does not fit; the number of rects written is returned.
+///
+/// # Safety
+///
+/// `sizes` must be valid for reading `len` values and `out` must be valid for
+/// writing `len` values. Either may be null only if `len` is zero.
+#[no_mangle]
+pub unsafe extern "C" fn glam_pack_rects(
+    bounds: URect,
+    sizes: *const UVec2,
+    len: usize,
+    out: *mut URect,
+) -> usize {
+    if len == 0 || sizes.is_null() || out.is_null() {
+        return 0;
+    }
+    // SAFETY: the caller guarantees both pointers are valid for `len` values.
+    let (sizes, out) = unsafe {
+        (
+            std::slice::from_raw_parts(sizes, len),
+            std::slice::from_raw_parts_mut(out, len),
+        )
+    };
+    let mut packer = RectPacker::new(bounds);
+    for (index, size) in sizes.iter().enumerate() {
+        match packer.allocate(*size) {
+            Some(rect) => out[index] = rect,
+            None => return index,
+        }
+    }
+    len
+}
+
+fn write_result<T>(result: Option<T>, out: Option<&mut T>) -> bool {
+    match (result, out) {
+        (Some(result), Some(out)) => {
+            *out = result;
+            true
+        }
+        (result, _) => result.is_some(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_intersect_out_param() {
+        let a = Rect::from_tuples((0.0, 0.0), (2.0, 2.0));
+        let b = Rect::from_tuples((1.0, 1.0), (3.0, 3.0));
+        let mut out = Rect::ZERO;
+
+        assert!(glam_rect_intersect(a, b, Some(&mut out)));
+        assert_eq!(out, Rect::from_tuples((1.0, 1.0), (2.0, 2.0)));
+
+        let far = Rect::from_tuples((5.0, 5.0), (6.0, 6.0));
+        assert!(!glam_rect_intersect(a, far, Some(&mut out)));
+        assert_eq!(out, Rect::from_tuples((1.0, 1.0), (2.0, 2.0)));
+        assert!(glam_rect_intersect(a, a, None));
+    }
+
+    #[test]
+    pub fn test_pack_rects() {
+        let bounds = URect::from_tuples((0, 0), (4, 4));
+        let sizes = [UVec2::new(2, 4), UVec2::new(2, 2), UVec2::new(4, 4)];
+        let mut out = [URect::ZERO; 3];
+        let packed =
+            unsafe { glam_pack_rects(bounds, sizes.as_ptr(), sizes.len(), out.as_mut_ptr()) };
+
+        assert_eq!(packed, 2);
+        assert_eq!(out[0].size(), sizes[0]);
+        assert_eq!(out[1].size(), sizes[1]);
+        assert!(out[0].intersect(&out[1]).is_none());
+        assert!(bounds.contains_rect(&out[0]) && bounds.contains_rect(&out[1]));
+        assert_eq!(out[2], URect::ZERO);
+        assert_eq!(
+            unsafe { glam_pack_rects(bounds, std::ptr::null(), 0, std::ptr::null_mut()) },
+            0
+        );
+    }
+}
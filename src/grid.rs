@@ -0,0 +1,167 @@
+use glam::{IVec2, UVec2, Vec2};
+
+use crate::Rect;
+
+/// Which cells count as neighbors in a [`GridPartition`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Connectivity {
+    /// Cells sharing an edge.
+    Four,
+    /// Cells sharing an edge or a corner.
+    Eight,
+}
+
+/// A rectangle divided into a fixed number of equally sized cells. Cells are
+/// addressed by their column and row, starting from the top left.
+#[derive(Debug, PartialEq, Clone)]
+pub struct GridPartition {
+    bounds: Rect,
+    columns: u32,
+    rows: u32,
+}
+
+impl GridPartition {
+    /// Constructs a new `GridPartition` dividing `bounds` into `columns` by
+    /// `rows` cells.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `columns` or `rows` is zero.
+    pub fn new(bounds: Rect, columns: u32, rows: u32) -> Self {
+        assert!(columns > 0 && rows > 0, "a grid needs at least one cell");
+        Self {
+            bounds,
+            columns,
+            rows,
+        }
+    }
+
+    /// Returns the rectangle covered by the grid.
+    #[inline]
+    pub fn bounds(&self) -> &Rect {
+        &self.bounds
+    }
+
+    /// Returns the number of columns and rows.
+    #[inline]
+    pub fn dimensions(&self) -> UVec2 {
+        UVec2::new(self.columns, self.rows)
+    }
+
+    /// Returns the total number of cells.
+    #[inline]
+    pub fn cell_count(&self) -> usize {
+        self.columns as usize * self.rows as usize
+    }
+
+    /// Returns the size of a single cell.
+    #[inline]
+    pub fn cell_size(&self) -> Vec2 {
+        self.bounds.size() / self.dimensions().as_vec2()
+    }
+
+    /// Returns the cell containing `point`, or `None` if the point is outside
+    /// the grid. Uses the same edge convention as [`Rect::contains`].
+    pub fn cell_at(&self, point: Vec2) -> Option<UVec2> {
+        if !self.bounds.contains(point) {
+            return None;
+        }
+        let relative = (point - self.bounds.top_left) / self.cell_size();
+        let cell = relative.floor().as_uvec2();
+        // Rounding can push points right next to the far edges one cell out.
+        Some(cell.min(self.dimensions() - UVec2::ONE))
+    }
+
+    /// Returns the rectangle of the cell at `cell`. Adjacent cells share their
+    /// edges exactly.
+    pub fn cell_rect(&self, cell: UVec2) -> Rect {
+        let edge = |i: UVec2| {
+            let fraction = i.as_vec2() / self.dimensions().as_vec2();
+            self.bounds.top_left + self.bounds.size() * fraction
+        };
+        Rect::new(edge(cell), edge(cell + UVec2::ONE))
+    }
+
+    /// Returns the linear row-major index of `cell`.
+    #[inline]
+    pub fn index_of(&self, cell: UVec2) -> usize {
+        cell.y as usize * self.columns as usize + cell.x as usize
+    }
+
+    /// Returns the cell with the linear row-major index `index`.
+    #[inline]
+    pub fn cell_of(&self, index: usize) -> UVec2 {
+        let columns = self.columns as usize;
+        UVec2::new((index % columns) as u32, (index / columns) as u32)
+    }
+
+    /// Returns the cells adjacent to `cell` that lie within the grid.
+    pub fn neighbors(
+        &self,
+        cell: UVec2,
+        connectivity: Connectivity,
+    ) -> impl Iterator<Item = UVec2> + '_ {
+        const OFFSETS: [IVec2; 8] = [
+            IVec2::new(0, -1),
+            IVec2::new(1, 0),
+            IVec2::new(0, 1),
+            IVec2::new(-1, 0),
+            IVec2::new(1, -1),
+            IVec2::new(1, 1),
+            IVec2::new(-1, 1),
+            IVec2::new(-1, -1),
+        ];
+        let count = match connectivity {
+            Connectivity::Four => 4,
+            Connectivity::Eight => 8,
+        };
+        let dimensions = self.dimensions().as_ivec2();
+        OFFSETS[..count].iter().filter_map(move |offset| {
+            let neighbor = cell.as_ivec2() + *offset;
+            let inside = neighbor.cmpge(IVec2::ZERO).all() && neighbor.cmplt(dimensions).all();
+            inside.then(|| neighbor.as_uvec2())
+        })
+    }
+
+    /// Iterates over every cell and its rectangle in row-major order.
+    pub fn cells(&self) -> impl Iterator<Item = (UVec2, Rect)> + '_ {
+        (0..self.cell_count()).map(move |index| {
+            let cell = self.cell_of(index);
+            (cell, self.cell_rect(cell))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_grid_cells() {
+        let grid = GridPartition::new(Rect::from_tuples((0.0, 0.0), (30.0, 20.0)), 3, 2);
+
+        assert_eq!(grid.cell_size(), Vec2::new(10.0, 10.0));
+        assert_eq!(grid.cell_at(Vec2::new(25.0, 5.0)), Some(UVec2::new(2, 0)));
+        assert_eq!(grid.cell_at(Vec2::new(10.0, 10.0)), Some(UVec2::new(1, 1)));
+        assert_eq!(grid.cell_at(Vec2::new(30.0, 5.0)), None);
+        assert_eq!(
+            grid.cell_rect(UVec2::new(1, 1)),
+            Rect::from_tuples((10.0, 10.0), (20.0, 20.0))
+        );
+        assert_eq!(grid.cells().count(), 6);
+        assert_eq!(
+            grid.cell_of(grid.index_of(UVec2::new(2, 1))),
+            UVec2::new(2, 1)
+        );
+    }
+
+    #[test]
+    pub fn test_grid_neighbors() {
+        let grid = GridPartition::new(Rect::from_tuples((0.0, 0.0), (3.0, 3.0)), 3, 3);
+
+        let corner: Vec<_> = grid.neighbors(UVec2::ZERO, Connectivity::Four).collect();
+        assert_eq!(corner, vec![UVec2::new(1, 0), UVec2::new(0, 1)]);
+        assert_eq!(grid.neighbors(UVec2::ZERO, Connectivity::Eight).count(), 3);
+        assert_eq!(grid.neighbors(UVec2::ONE, Connectivity::Eight).count(), 8);
+    }
+}
@@ -0,0 +1,67 @@
+use glam::Vec2;
+
+use crate::Rect;
+
+/// Returns the value of the topmost rect containing `point`, where `rects`
+/// are in drawing order, back to front, so that later rects are on top.
+///
+/// This has to look at every rect. If the rects are available front to
+/// back, [`first_containing`] stops at the first hit instead.
+pub fn topmost_containing<T>(point: Vec2, rects: impl IntoIterator<Item = (Rect, T)>) -> Option<T> {
+    rects
+        .into_iter()
+        .filter(|(rect, _)| rect.contains(point))
+        .last()
+        .map(|(_, value)| value)
+}
+
+/// Returns the value of the first rect containing `point`, where `rects`
+/// are ordered front to back, such as a draw list iterated in reverse.
+pub fn first_containing<T>(point: Vec2, rects: impl IntoIterator<Item = (Rect, T)>) -> Option<T> {
+    rects
+        .into_iter()
+        .find(|(rect, _)| rect.contains(point))
+        .map(|(_, value)| value)
+}
+
+/// Returns the values of all rects containing `point`, topmost first, where
+/// `rects` are in drawing order, back to front. This is the order in which
+/// an event would bubble through the hit elements.
+pub fn all_containing<T>(point: Vec2, rects: impl IntoIterator<Item = (Rect, T)>) -> Vec<T> {
+    let mut hits: Vec<T> = rects
+        .into_iter()
+        .filter(|(rect, _)| rect.contains(point))
+        .map(|(_, value)| value)
+        .collect();
+    hits.reverse();
+    hits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_hit_testing() {
+        let draw_list = [
+            (Rect::from_tuples((0.0, 0.0), (100.0, 100.0)), "window"),
+            (Rect::from_tuples((10.0, 10.0), (50.0, 30.0)), "button"),
+            (Rect::from_tuples((40.0, 20.0), (80.0, 60.0)), "popup"),
+        ];
+        let point = Vec2::new(45.0, 25.0);
+        assert_eq!(topmost_containing(point, draw_list), Some("popup"));
+        assert_eq!(
+            first_containing(point, draw_list.into_iter().rev()),
+            Some("popup")
+        );
+        assert_eq!(
+            all_containing(point, draw_list),
+            ["popup", "button", "window"]
+        );
+        assert_eq!(
+            topmost_containing(Vec2::new(20.0, 20.0), draw_list),
+            Some("button")
+        );
+        assert_eq!(topmost_containing(Vec2::new(100.0, 0.0), draw_list), None);
+    }
+}
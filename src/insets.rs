@@ -0,0 +1,68 @@
+use glam::Vec2;
+
+/// Distances from each edge of a rectangle, used for padding and margins.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Insets {
+    pub left: f32,
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+}
+
+impl Insets {
+    /// Constructs new `Insets` from the distance to each edge.
+    #[inline]
+    pub const fn new(left: f32, top: f32, right: f32, bottom: f32) -> Self {
+        Self {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+
+    /// Insets of zero on every side.
+    pub const ZERO: Insets = Insets::uniform(0.0);
+
+    /// Constructs `Insets` with the same distance on every side.
+    #[inline]
+    pub const fn uniform(amount: f32) -> Self {
+        Self::new(amount, amount, amount, amount)
+    }
+
+    /// Constructs `Insets` with `horizontal` on the left and right sides and
+    /// `vertical` on the top and bottom sides.
+    #[inline]
+    pub const fn symmetric(horizontal: f32, vertical: f32) -> Self {
+        Self::new(horizontal, vertical, horizontal, vertical)
+    }
+
+    /// Returns the sum of the left and right insets.
+    #[inline]
+    pub fn horizontal(&self) -> f32 {
+        self.left + self.right
+    }
+
+    /// Returns the sum of the top and bottom insets.
+    #[inline]
+    pub fn vertical(&self) -> f32 {
+        self.top + self.bottom
+    }
+}
+
+impl From<f32> for Insets {
+    #[inline]
+    fn from(amount: f32) -> Self {
+        Insets::uniform(amount)
+    }
+}
+
+/// Uses `x` for the left and right sides and `y` for the top and bottom.
+impl From<Vec2> for Insets {
+    #[inline]
+    fn from(amount: Vec2) -> Self {
+        Insets::symmetric(amount.x, amount.y)
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A small handle to a rect stored in a [`RectSetInterner`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub struct RectId(pub u32);
+
+/// Deduplicates integer rects, handing out the same [`RectId`] for equal
+/// rects. Ids are assigned sequentially from zero in insertion order, so they
+/// are stable and can be stored in place of the rects themselves.
+///
+/// Works with any rect type that is `Eq + Hash`, i.e. [`IRect`](crate::IRect)
+/// and [`URect`](crate::URect).
+#[derive(Debug, Clone)]
+pub struct RectSetInterner<R> {
+    rects: Vec<R>,
+    ids: HashMap<R, RectId>,
+}
+
+impl<R> Default for RectSetInterner<R> {
+    fn default() -> Self {
+        Self {
+            rects: Vec::new(),
+            ids: HashMap::new(),
+        }
+    }
+}
+
+impl<R: Clone + Eq + Hash> RectSetInterner<R> {
+    /// Constructs a new, empty `RectSetInterner`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the id of `rect`, storing it first if it has not been seen.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than `u32::MAX` distinct rects are interned.
+    pub fn intern(&mut self, rect: R) -> RectId {
+        if let Some(id) = self.ids.get(&rect) {
+            return *id;
+        }
+        let id = RectId(u32::try_from(self.rects.len()).expect("too many interned rects"));
+        self.rects.push(rect.clone());
+        self.ids.insert(rect, id);
+        id
+    }
+
+    /// Returns the id of `rect` if it has been interned.
+    pub fn id_of(&self, rect: &R) -> Option<RectId> {
+        self.ids.get(rect).copied()
+    }
+
+    /// Returns the rect with the given id.
+    pub fn get(&self, id: RectId) -> Option<&R> {
+        self.rects.get(id.0 as usize)
+    }
+
+    /// Returns the number of distinct rects.
+    pub fn len(&self) -> usize {
+        self.rects.len()
+    }
+
+    /// Returns `true` if no rects have been interned.
+    pub fn is_empty(&self) -> bool {
+        self.rects.is_empty()
+    }
+
+    /// Returns all distinct rects, indexed by id.
+    pub fn as_slice(&self) -> &[R] {
+        &self.rects
+    }
+
+    /// Iterates over the ids and rects in id order.
+    pub fn iter(&self) -> impl Iterator<Item = (RectId, &R)> {
+        self.rects
+            .iter()
+            .enumerate()
+            .map(|(index, rect)| (RectId(index as u32), rect))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IRect;
+
+    #[test]
+    pub fn test_intern() {
+        let mut interner = RectSetInterner::new();
+        let a = interner.intern(IRect::from_tuples((0, 0), (16, 16)));
+        let b = interner.intern(IRect::from_tuples((16, 0), (32, 16)));
+        let c = interner.intern(IRect::from_tuples((0, 0), (16, 16)));
+
+        assert_eq!(a, RectId(0));
+        assert_eq!(b, RectId(1));
+        assert_eq!(a, c);
+        assert_eq!(interner.len(), 2);
+        assert_eq!(
+            interner.get(b),
+            Some(&IRect::from_tuples((16, 0), (32, 16)))
+        );
+        assert_eq!(interner.id_of(&IRect::from_tuples((1, 1), (2, 2))), None);
+    }
+}
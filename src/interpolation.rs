@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::Rect;
+
+/// Stores the previous and current simulation rect of each entity in a fixed
+/// timestep loop, and blends between them for rendering.
+///
+/// Call [`RectInterpolationBuffer::update`] once per simulation step for each
+/// entity, then [`RectInterpolationBuffer::interpolate`] when rendering with
+/// `alpha` being the fraction of a step accumulated since the last update.
+#[derive(Debug, Clone)]
+pub struct RectInterpolationBuffer<K> {
+    entries: HashMap<K, (Rect, Rect)>,
+    teleport_distance: f32,
+}
+
+impl<K: Eq + Hash> RectInterpolationBuffer<K> {
+    /// Constructs a new, empty buffer. Entities whose vertices move further
+    /// than `teleport_distance` in a single step are considered teleported
+    /// and rendered at their current rect without interpolation.
+    pub fn new(teleport_distance: f32) -> Self {
+        Self {
+            entries: HashMap::new(),
+            teleport_distance,
+        }
+    }
+
+    /// Records the rect of `key` for a new simulation step. The first update
+    /// of an entity sets both its previous and current rect.
+    pub fn update(&mut self, key: K, rect: Rect) {
+        self.entries
+            .entry(key)
+            .and_modify(|(previous, current)| {
+                *previous = std::mem::replace(current, rect);
+            })
+            .or_insert_with(|| (rect, rect));
+    }
+
+    /// Sets the rect of `key` without interpolating from its old position on
+    /// the next frame.
+    pub fn teleport(&mut self, key: K, rect: Rect) {
+        self.entries.insert(key, (rect, rect));
+    }
+
+    /// Stops tracking `key`, returning its current rect.
+    pub fn remove(&mut self, key: &K) -> Option<Rect> {
+        self.entries.remove(key).map(|(_, current)| current)
+    }
+
+    /// Returns the current simulation rect of `key`.
+    pub fn current(&self, key: &K) -> Option<&Rect> {
+        self.entries.get(key).map(|(_, current)| current)
+    }
+
+    /// Returns the render rect of `key`, blended between its previous and
+    /// current rect by `alpha` in `[0, 1]`.
+    pub fn interpolate(&self, key: &K, alpha: f32) -> Option<Rect> {
+        self.entries
+            .get(key)
+            .map(|(previous, current)| self.blend(previous, current, alpha))
+    }
+
+    /// Iterates over the render rects of all entities.
+    pub fn interpolate_all(&self, alpha: f32) -> impl Iterator<Item = (&K, Rect)> {
+        self.entries
+            .iter()
+            .map(move |(key, (previous, current))| (key, self.blend(previous, current, alpha)))
+    }
+
+    fn blend(&self, previous: &Rect, current: &Rect, alpha: f32) -> Rect {
+        let moved = previous
+            .top_left
+            .distance(current.top_left)
+            .max(previous.bottom_right.distance(current.bottom_right));
+        if moved > self.teleport_distance {
+            return *current;
+        }
+        previous.lerp(current, alpha)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_interpolate() {
+        let mut buffer = RectInterpolationBuffer::new(50.0);
+        buffer.update("player", Rect::from_tuples((0.0, 0.0), (10.0, 10.0)));
+        assert_eq!(
+            buffer.interpolate(&"player", 0.5),
+            Some(Rect::from_tuples((0.0, 0.0), (10.0, 10.0)))
+        );
+
+        buffer.update("player", Rect::from_tuples((10.0, 0.0), (20.0, 10.0)));
+        assert_eq!(
+            buffer.interpolate(&"player", 0.5),
+            Some(Rect::from_tuples((5.0, 0.0), (15.0, 10.0)))
+        );
+
+        buffer.update("player", Rect::from_tuples((500.0, 0.0), (510.0, 10.0)));
+        assert_eq!(
+            buffer.interpolate(&"player", 0.5),
+            Some(Rect::from_tuples((500.0, 0.0), (510.0, 10.0)))
+        );
+        assert_eq!(buffer.interpolate(&"enemy", 0.5), None);
+    }
+}
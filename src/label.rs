@@ -0,0 +1,105 @@
+use glam::Vec2;
+
+use crate::{Anchor, Rect};
+
+/// The order in which [`place_label`] tries positions when no preferences
+/// are given: the four diagonal positions first, as is conventional for map
+/// labels, then the sides.
+pub const DEFAULT_LABEL_PREFERENCES: [Anchor; 8] = [
+    Anchor::TopRight,
+    Anchor::BottomRight,
+    Anchor::TopLeft,
+    Anchor::BottomLeft,
+    Anchor::Right,
+    Anchor::Top,
+    Anchor::Left,
+    Anchor::Bottom,
+];
+
+/// Returns the rect of a label of `label_size` placed outside `anchor` at
+/// the given position, touching it. [`Anchor::Center`] centers the label on
+/// the anchor instead.
+pub fn label_rect(anchor: &Rect, label_size: Vec2, position: Anchor) -> Rect {
+    let fraction = position.fraction();
+    let point = anchor.top_left + anchor.size() * fraction;
+    let offset = if position == Anchor::Center {
+        label_size * 0.5
+    } else {
+        label_size * (Vec2::ONE - fraction)
+    };
+    Rect::from_position_size(point - offset, label_size)
+}
+
+/// Picks a position for a label of `label_size` around `anchor`.
+///
+/// The positions in `preferences` are tried in order, and the first one
+/// whose label does not overlap any of the `obstacles` is chosen. If every
+/// position overlaps something, the one with the least total overlap area
+/// wins, with ties going to the earlier preference. An empty `preferences`
+/// uses [`DEFAULT_LABEL_PREFERENCES`].
+///
+/// Returns the chosen position and the label's rect.
+pub fn place_label(
+    anchor: &Rect,
+    label_size: Vec2,
+    obstacles: &[Rect],
+    preferences: &[Anchor],
+) -> (Anchor, Rect) {
+    let preferences = if preferences.is_empty() {
+        &DEFAULT_LABEL_PREFERENCES[..]
+    } else {
+        preferences
+    };
+    let mut best = None;
+    let mut best_overlap = f32::INFINITY;
+    for &position in preferences {
+        let rect = label_rect(anchor, label_size, position);
+        let overlap: f32 = obstacles
+            .iter()
+            .filter_map(|obstacle| rect.intersect(obstacle))
+            .map(|overlap| overlap.area())
+            .sum();
+        if overlap == 0.0 {
+            return (position, rect);
+        }
+        if overlap < best_overlap {
+            best = Some((position, rect));
+            best_overlap = overlap;
+        }
+    }
+    best.expect("preferences are not empty")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_place_label() {
+        let pin = Rect::from_tuples((10.0, 10.0), (12.0, 12.0));
+        let size = Vec2::new(4.0, 2.0);
+        assert_eq!(
+            label_rect(&pin, size, Anchor::TopRight),
+            Rect::from_tuples((12.0, 8.0), (16.0, 10.0))
+        );
+        assert_eq!(
+            label_rect(&pin, size, Anchor::Left),
+            Rect::from_tuples((6.0, 10.0), (10.0, 12.0))
+        );
+
+        assert_eq!(place_label(&pin, size, &[], &[]).0, Anchor::TopRight);
+        let obstacles = [Rect::from_tuples((12.0, 0.0), (20.0, 20.0))];
+        assert_eq!(place_label(&pin, size, &obstacles, &[]).0, Anchor::TopLeft);
+
+        // Everything is covered, so the least overlapping position wins.
+        let obstacles = [
+            Rect::from_tuples((0.0, 0.0), (30.0, 30.0)),
+            Rect::from_tuples((0.0, 0.0), (11.0, 30.0)),
+        ];
+        let preferences = [Anchor::Left, Anchor::Right];
+        assert_eq!(
+            place_label(&pin, size, &obstacles, &preferences).0,
+            Anchor::Right
+        );
+    }
+}
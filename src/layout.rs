@@ -0,0 +1,109 @@
+use crate::{Rect, SplitAxis};
+
+/// The size of a child in [`Rect::split_sized`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SizeSpec {
+    /// A fixed size.
+    Px(f32),
+    /// A share of the space left over by the fixed sizes, proportional to
+    /// the weight.
+    Flex(f32),
+}
+
+impl Rect {
+    /// Divides the rectangle along `axis` into children proportional to
+    /// `weights`, separated by `gap`, see [`Rect::split_sized`].
+    pub fn split_weighted(&self, axis: SplitAxis, weights: &[f32], gap: f32) -> Vec<Rect> {
+        let specs: Vec<SizeSpec> = weights
+            .iter()
+            .map(|&weight| SizeSpec::Flex(weight))
+            .collect();
+        self.split_sized(axis, &specs, gap)
+    }
+
+    /// Divides the rectangle along `axis` into one child per spec, separated
+    /// by `gap`. A [`SplitAxis::Horizontal`] split places the children side by
+    /// side from left to right, a [`SplitAxis::Vertical`] one stacks them from
+    /// top to bottom.
+    ///
+    /// Fixed children get their size, and the flexible children share what is
+    /// left. Negative sizes and weights count as zero. If the fixed sizes and
+    /// gaps do not fit, the children are cut off at the end of the rectangle.
+    pub fn split_sized(&self, axis: SplitAxis, specs: &[SizeSpec], gap: f32) -> Vec<Rect> {
+        let (start, end) = match axis {
+            SplitAxis::Horizontal => (self.left(), self.right()),
+            SplitAxis::Vertical => (self.top(), self.bottom()),
+        };
+        let gaps = gap.max(0.0) * specs.len().saturating_sub(1) as f32;
+        let (fixed, flex) = specs
+            .iter()
+            .fold((0.0, 0.0), |(fixed, flex), spec| match *spec {
+                SizeSpec::Px(size) => (fixed + size.max(0.0), flex),
+                SizeSpec::Flex(weight) => (fixed, flex + weight.max(0.0)),
+            });
+        let flex_space = (end - start - gaps - fixed).max(0.0);
+
+        let mut position = start;
+        specs
+            .iter()
+            .map(|spec| {
+                let size = match *spec {
+                    SizeSpec::Px(size) => size.max(0.0),
+                    SizeSpec::Flex(weight) if flex > 0.0 => flex_space * weight.max(0.0) / flex,
+                    SizeSpec::Flex(_) => 0.0,
+                };
+                let child_start = position.min(end);
+                let child_end = (position + size).min(end);
+                position += size + gap.max(0.0);
+                match axis {
+                    SplitAxis::Horizontal => {
+                        Rect::from_tuples((child_start, self.top()), (child_end, self.bottom()))
+                    }
+                    SplitAxis::Vertical => {
+                        Rect::from_tuples((self.left(), child_start), (self.right(), child_end))
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_split_sized() {
+        let rect = Rect::from_tuples((0.0, 0.0), (100.0, 20.0));
+        assert_eq!(
+            rect.split_weighted(SplitAxis::Horizontal, &[1.0, 3.0], 4.0),
+            [
+                Rect::from_tuples((0.0, 0.0), (24.0, 20.0)),
+                Rect::from_tuples((28.0, 0.0), (100.0, 20.0)),
+            ]
+        );
+        assert_eq!(
+            rect.split_sized(
+                SplitAxis::Vertical,
+                &[SizeSpec::Px(5.0), SizeSpec::Flex(1.0), SizeSpec::Px(5.0)],
+                0.0
+            ),
+            [
+                Rect::from_tuples((0.0, 0.0), (100.0, 5.0)),
+                Rect::from_tuples((0.0, 5.0), (100.0, 15.0)),
+                Rect::from_tuples((0.0, 15.0), (100.0, 20.0)),
+            ]
+        );
+        assert_eq!(
+            rect.split_sized(
+                SplitAxis::Vertical,
+                &[SizeSpec::Px(15.0), SizeSpec::Px(15.0)],
+                0.0
+            )[1],
+            Rect::from_tuples((0.0, 15.0), (100.0, 20.0))
+        );
+        assert!(rect
+            .split_weighted(SplitAxis::Horizontal, &[], 4.0)
+            .is_empty());
+    }
+}
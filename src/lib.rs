@@ -1,427 +1,1678 @@
-use glam::{IVec2, UVec2, Vec2};
+// glam is re-exported under its usual name from the one `glam-*` feature that
+// is enabled. The features select the type of every vector in the public API,
+// so enabling two would silently change the types seen by other dependents of
+// this crate; that is rejected below. The newest enabled version is still
+// re-exported so that only that error is reported.
+#[cfg(all(
+    feature = "glam-0_23",
+    not(any(
+        feature = "glam-0_24",
+        feature = "glam-0_25",
+        feature = "glam-0_26",
+        feature = "glam-0_27",
+        feature = "glam-0_28",
+        feature = "glam-0_29"
+    ))
+))]
+pub extern crate glam_0_23 as glam;
+#[cfg(all(
+    feature = "glam-0_24",
+    not(any(
+        feature = "glam-0_25",
+        feature = "glam-0_26",
+        feature = "glam-0_27",
+        feature = "glam-0_28",
+        feature = "glam-0_29"
+    ))
+))]
+pub extern crate glam_0_24 as glam;
+#[cfg(all(
+    feature = "glam-0_25",
+    not(any(
+        feature = "glam-0_26",
+        feature = "glam-0_27",
+        feature = "glam-0_28",
+        feature = "glam-0_29"
+    ))
+))]
+pub extern crate glam_0_25 as glam;
+#[cfg(all(
+    feature = "glam-0_26",
+    not(any(feature = "glam-0_27", feature = "glam-0_28", feature = "glam-0_29"))
+))]
+pub extern crate glam_0_26 as glam;
+#[cfg(all(
+    feature = "glam-0_27",
+    not(any(feature = "glam-0_28", feature = "glam-0_29"))
+))]
+pub extern crate glam_0_27 as glam;
+#[cfg(all(feature = "glam-0_28", not(feature = "glam-0_29")))]
+pub extern crate glam_0_28 as glam;
+#[cfg(feature = "glam-0_29")]
+pub extern crate glam_0_29 as glam;
+#[cfg(not(any(
+    feature = "glam-0_23",
+    feature = "glam-0_24",
+    feature = "glam-0_25",
+    feature = "glam-0_26",
+    feature = "glam-0_27",
+    feature = "glam-0_28",
+    feature = "glam-0_29"
+)))]
+compile_error!("glam_rect requires one of the `glam-0_*` features to be enabled");
+#[cfg(any(
+    all(
+        feature = "glam-0_23",
+        any(
+            feature = "glam-0_24",
+            feature = "glam-0_25",
+            feature = "glam-0_26",
+            feature = "glam-0_27",
+            feature = "glam-0_28",
+            feature = "glam-0_29"
+        )
+    ),
+    all(
+        feature = "glam-0_24",
+        any(
+            feature = "glam-0_25",
+            feature = "glam-0_26",
+            feature = "glam-0_27",
+            feature = "glam-0_28",
+            feature = "glam-0_29"
+        )
+    ),
+    all(
+        feature = "glam-0_25",
+        any(
+            feature = "glam-0_26",
+            feature = "glam-0_27",
+            feature = "glam-0_28",
+            feature = "glam-0_29"
+        )
+    ),
+    all(
+        feature = "glam-0_26",
+        any(feature = "glam-0_27", feature = "glam-0_28", feature = "glam-0_29")
+    ),
+    all(
+        feature = "glam-0_27",
+        any(feature = "glam-0_28", feature = "glam-0_29")
+    ),
+    all(feature = "glam-0_28", feature = "glam-0_29")
+))]
+compile_error!(
+    "only one `glam-0_*` feature of glam_rect may be enabled; \
+     disable default features to select a version other than 0.23"
+);
+
+use glam::{DVec2, DVec4, IVec2, IVec4, UVec2, UVec4, Vec2, Vec4};
+
+#[macro_use]
+mod macros;
+
+mod anchor;
+mod audit;
+mod batch;
+mod broadphase;
+#[cfg(feature = "bytemuck")]
+mod bytemuck_impls;
+mod centered;
+mod circle;
+mod clip;
+mod constraint;
+mod contains_mode;
+mod corner;
+mod coverage;
+mod crop;
+mod culling;
+mod damage;
+mod debug_draw;
+mod edge;
+mod empty;
+mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod grid;
+mod hit;
+mod insets;
+mod interner;
+mod interpolation;
+mod label;
+mod layout;
+mod lod;
+mod mesh;
+#[cfg(feature = "mint")]
+mod mint_impls;
+mod order;
+mod oriented;
+mod overlap;
+mod pack;
+mod parse;
+mod pool;
+#[cfg(any(
+    feature = "glam-0_25",
+    feature = "glam-0_26",
+    feature = "glam-0_27",
+    feature = "glam-0_28",
+    feature = "glam-0_29"
+))]
+mod rect16;
+#[cfg(any(
+    feature = "glam-0_24",
+    feature = "glam-0_25",
+    feature = "glam-0_26",
+    feature = "glam-0_27",
+    feature = "glam-0_28",
+    feature = "glam-0_29"
+))]
+mod rect64;
+mod region;
+mod relation;
+mod round;
+mod rounded;
+mod rtree;
+#[cfg(feature = "rand")]
+mod sampling;
+#[cfg(feature = "serde")]
+pub mod serde_formats;
+mod spatial;
+mod store;
+mod sweep;
+mod tiling;
+mod track;
+mod transaction;
+mod transform;
+mod union_area;
+mod viewport;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use anchor::Anchor;
+pub use arrayvec::ArrayVec;
+pub use audit::{AuditReport, AuditedRect};
+pub use batch::{contains_points, intersect_many, intersects_many};
+pub use broadphase::{overlapping_pairs, SweepAndPrune};
+pub use centered::{Aabb2, CenteredRect};
+pub use circle::Circle;
+pub use clip::ClipStack;
+pub use constraint::{Constraint, ConstraintSolver, EdgeVar, RectHandle, SolveError};
+pub use contains_mode::ContainsMode;
+pub use corner::Corner;
+pub use coverage::CoveragePyramid;
+pub use crop::{crop_with_policy, Crop, CropPolicy};
+pub use culling::{HysteresisCuller, Visibility};
+pub use damage::DamageTracker;
+pub use debug_draw::{DebugDraw, DebugVisualize};
+pub use edge::Edge;
+pub use empty::{largest_empty_rect, largest_fit};
+pub use error::{ParseRectError, RectConversionError, RectError};
+pub use grid::{Connectivity, GridPartition};
+pub use hit::{all_containing, first_containing, topmost_containing};
+pub use insets::Insets;
+pub use interner::{RectId, RectSetInterner};
+pub use interpolation::RectInterpolationBuffer;
+pub use label::{label_rect, place_label, DEFAULT_LABEL_PREFERENCES};
+pub use layout::SizeSpec;
+pub use lod::{group_impostors, ImpostorGroup};
+pub use mesh::{greedy_mesh, merge_cells};
+#[cfg(feature = "mint")]
+pub use mint_impls::MintRect;
+pub use order::{column_order_sort, reading_order_sort, OrderedRect};
+pub use oriented::OrientedRect;
+pub use overlap::remove_overlaps;
+pub use pack::RectPacker;
+pub use pool::{PoolSlice, RectPool};
+#[cfg(any(
+    feature = "glam-0_25",
+    feature = "glam-0_26",
+    feature = "glam-0_27",
+    feature = "glam-0_28",
+    feature = "glam-0_29"
+))]
+pub use rect16::{I16Rect, U16Rect};
+#[cfg(any(
+    feature = "glam-0_24",
+    feature = "glam-0_25",
+    feature = "glam-0_26",
+    feature = "glam-0_27",
+    feature = "glam-0_28",
+    feature = "glam-0_29"
+))]
+pub use rect64::{I64Rect, U64Rect};
+pub use region::{dilate_rects, erode_rects, Region, RegionDiff};
+pub use relation::RectRelation;
+pub use round::{CoverMode, RoundMode};
+pub use rounded::{BorderHit, RoundedRect};
+pub use rtree::RTree;
+#[cfg(feature = "rand")]
+pub use sampling::{pick_weighted_by_area, sample_point_in_union};
+pub use spatial::{SpatialGrid, SpatialId};
+pub use store::{RectRule, RectStore};
+pub use sweep::SweepHit;
+pub use tiling::{SplitAxis, TilingTree};
+pub use track::{RectTrack, TrackInterpolation};
+pub use transaction::RectTransaction;
+pub use transform::transform_aabbs;
+pub use viewport::{split_screen, SplitLayout};
 
 /// A struct representing an axis-aligned rectangle. Two points are stored: the
 /// top left vertex, and the bottom right vertex.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct Rect {
     pub top_left: Vec2,
     pub bottom_right: Vec2,
 }
 
-impl Rect {
-    /// Constructs a new `Rect`. The top left vertex must be above and to
-    /// the left of the bottom right vertex.
-    #[inline]
-    pub const fn new(top_left: Vec2, bottom_right: Vec2) -> Self {
-        Self {
-            top_left,
-            bottom_right,
-        }
-    }
-
-    #[inline]
-    pub const fn corners(self) -> [Vec2; 4] {
-        let top_right = Vec2::new(self.bottom_right.x, self.top_left.y);
-        let bottom_left = Vec2::new(self.top_left.x, self.bottom_right.y);
-        [self.top_left, top_right, self.bottom_right, bottom_left]
-    }
-
-    #[inline]
-    pub const fn top_right(&self) -> Vec2 {
-        Vec2::new(self.bottom_right.x, self.top_left.y)
-    }
-
-    #[inline]
-    pub const fn bottom_left(&self) -> Vec2 {
-        Vec2::new(self.top_left.x, self.bottom_right.y)
-    }
-
-    /// Constructs a new `Rect`. The top left vertex must be above and to
-    /// the left of the bottom right vertex.
-    #[inline]
-    pub fn from_tuples(top_left: (f32, f32), bottom_right: (f32, f32)) -> Self {
-        Self {
-            top_left: Vec2::new(top_left.0, top_left.1),
-            bottom_right: Vec2::new(bottom_right.0, bottom_right.1),
-        }
-    }
+impl_float_rect!(Rect, Vec2, Vec4, f32);
 
-    /// Returns the width of the rectangle.
-    #[inline]
-    pub fn width(&self) -> f32 {
-        self.bottom_right.x - self.top_left.x
-    }
+/// A double precision version of [`Rect`], for work where `f32` is not
+/// precise enough.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct DRect {
+    pub top_left: DVec2,
+    pub bottom_right: DVec2,
+}
 
-    /// Returns the height of the rectangle.
-    #[inline]
-    pub fn height(&self) -> f32 {
-        self.bottom_right.y - self.top_left.y
-    }
+impl_float_rect!(DRect, DVec2, DVec4, f64);
 
-    /// Returns a `Vector2` containing the width and height of the rectangle.
+impl DRect {
+    /// Converts to a `Rect`, rounding the coordinates to the nearest `f32`.
     #[inline]
-    pub fn size(&self) -> Vec2 {
-        Vec2::new(self.width(), self.height())
+    pub fn as_rect(&self) -> Rect {
+        Rect::new(self.top_left.as_vec2(), self.bottom_right.as_vec2())
     }
-    /// Returns true if the specified point is inside this rectangle. This is
-    /// inclusive of the top and left coordinates, and exclusive of the bottom
-    /// and right coordinates.
-    #[inline]
-    #[must_use]
-    pub fn contains(&self, point: Vec2) -> bool {
-        point.x >= self.top_left.x
-            && point.y >= self.top_left.y
-            && point.x < self.bottom_right.x
-            && point.y < self.bottom_right.y
-    }
-    /// Finds the intersection of two rectangles -- in other words, the area
-    /// that is common to both of them.
-    ///
-    /// If there is no common area between the two rectangles, then this
-    /// function will return `None`.
-    #[inline]
-    #[must_use]
-    pub fn intersect(&self, other: &Self) -> Option<Self> {
-        let result = Self {
-            top_left: Vec2::new(
-                self.top_left.x.max(other.top_left.x),
-                self.top_left.y.max(other.top_left.y),
-            ),
-            bottom_right: Vec2::new(
-                self.bottom_right.x.min(other.bottom_right.x),
-                self.bottom_right.y.min(other.bottom_right.y),
-            ),
-        };
+}
 
-        if result.is_positive_area() {
-            Some(result)
-        } else {
-            None
-        }
-    }
-    /// A constant representing a rectangle with position (0, 0) and zero area.
-    /// Each component is set to zero.
-    pub const ZERO: Rect = Rect::new(Vec2::ZERO, Vec2::ZERO);
-    /// Returns `true` if the rectangle has zero area.
-    #[inline]
-    pub fn is_zero_area(&self) -> bool {
-        self.top_left.x == self.bottom_right.x || self.top_left.y == self.bottom_right.y
-    }
-    /// Returns `true` if the rectangle has an area greater than zero.
-    #[inline]
-    pub fn is_positive_area(&self) -> bool {
-        self.top_left.x < self.bottom_right.x && self.top_left.y < self.bottom_right.y
-    }
-    /// Returns a new rectangle, whose vertices are offset relative to the
-    /// current rectangle by the specified amount. This is equivalent to
-    /// adding the specified vector to each vertex.
-    #[inline]
-    pub fn with_offset(&self, offset: impl Into<Vec2>) -> Self {
-        let offset = offset.into();
-        Self::new(self.top_left + offset, self.bottom_right + offset)
-    }
-    /// Returns a new rectangle, whose vertices are negatively offset relative
-    /// to the current rectangle by the specified amount. This is equivalent
-    /// to subtracting the specified vector to each vertex.
+impl From<Rect> for DRect {
+    /// Converts a `Rect` to a `DRect` without loss of precision.
     #[inline]
-    pub fn with_negative_offset(&self, offset: impl Into<Vec2>) -> Self {
-        let offset = offset.into();
-        Self::new(self.top_left - offset, self.bottom_right - offset)
+    fn from(rect: Rect) -> Self {
+        DRect::new(rect.top_left.as_dvec2(), rect.bottom_right.as_dvec2())
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct URect {
     pub top_left: UVec2,
     pub bottom_right: UVec2,
 }
 
+impl_int_rect!(URect, UVec2, UVec4, u32, u64, Rect, Vec2, f32, as_vec2, as_uvec2);
+
 impl URect {
-    /// Constructs a new `Rect`. The top left vertex must be above and to
-    /// the left of the bottom right vertex.
+    /// Converts to a `Rect`. Coordinates beyond 2^24 may lose precision.
     #[inline]
-    pub const fn new(top_left: UVec2, bottom_right: UVec2) -> Self {
-        Self {
-            top_left,
-            bottom_right,
-        }
+    pub fn as_rect(&self) -> Rect {
+        Rect::new(self.top_left.as_vec2(), self.bottom_right.as_vec2())
     }
+}
 
-    #[inline]
-    pub const fn corners(self) -> [UVec2; 4] {
-        let top_right = UVec2::new(self.bottom_right.x, self.top_left.y);
-        let bottom_left = UVec2::new(self.top_left.x, self.bottom_right.y);
-        [self.top_left, top_right, self.bottom_right, bottom_left]
-    }
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct IRect {
+    pub top_left: IVec2,
+    pub bottom_right: IVec2,
+}
 
-    #[inline]
-    pub const fn top_right(&self) -> UVec2 {
-        UVec2::new(self.bottom_right.x, self.top_left.y)
-    }
+impl_int_rect!(IRect, IVec2, IVec4, i32, i64, Rect, Vec2, f32, as_vec2, as_ivec2);
 
+impl IRect {
+    /// Converts to a `Rect`. Coordinates beyond 2^24 may lose precision.
     #[inline]
-    pub const fn bottom_left(&self) -> UVec2 {
-        UVec2::new(self.top_left.x, self.bottom_right.y)
+    pub fn as_rect(&self) -> Rect {
+        Rect::new(self.top_left.as_vec2(), self.bottom_right.as_vec2())
     }
 
-    /// Constructs a new `Rect`. The top left vertex must be above and to
-    /// the left of the bottom right vertex.
+    /// Converts to a `URect`, or returns `None` if any coordinate is
+    /// negative.
     #[inline]
-    pub fn from_tuples(top_left: (u32, u32), bottom_right: (u32, u32)) -> Self {
-        Self {
-            top_left: UVec2::new(top_left.0, top_left.1),
-            bottom_right: UVec2::new(bottom_right.0, bottom_right.1),
+    pub fn to_urect(&self) -> Option<URect> {
+        if self.top_left.min_element() < 0 || self.bottom_right.min_element() < 0 {
+            return None;
         }
+        Some(URect::new(
+            self.top_left.as_uvec2(),
+            self.bottom_right.as_uvec2(),
+        ))
     }
+}
 
-    /// Returns the width of the rectangle.
-    #[inline]
-    pub fn width(&self) -> u32 {
-        self.bottom_right.x - self.top_left.x
-    }
+impl TryFrom<IRect> for URect {
+    type Error = RectConversionError;
 
-    /// Returns the height of the rectangle.
+    /// Converts to a `URect`, failing if any coordinate is negative.
     #[inline]
-    pub fn height(&self) -> u32 {
-        self.bottom_right.y - self.top_left.y
+    fn try_from(rect: IRect) -> Result<Self, Self::Error> {
+        rect.to_urect().ok_or(RectConversionError::Negative)
     }
+}
 
-    /// Returns a `Vector2` containing the width and height of the rectangle.
-    #[inline]
-    pub fn size(&self) -> UVec2 {
-        UVec2::new(self.width(), self.height())
-    }
-    /// Returns true if the specified point is inside this rectangle. This is
-    /// inclusive of the top and left coordinates, and exclusive of the bottom
-    /// and right coordinates.
-    #[inline]
-    #[must_use]
-    pub fn contains(&self, point: UVec2) -> bool {
-        point.x >= self.top_left.x
-            && point.y >= self.top_left.y
-            && point.x < self.bottom_right.x
-            && point.y < self.bottom_right.y
-    }
-    /// Finds the intersection of two rectangles -- in other words, the area
-    /// that is common to both of them.
-    ///
-    /// If there is no common area between the two rectangles, then this
-    /// function will return `None`.
-    #[inline]
-    #[must_use]
-    pub fn intersect(&self, other: &Self) -> Option<Self> {
-        let result = Self {
-            top_left: UVec2::new(
-                self.top_left.x.max(other.top_left.x),
-                self.top_left.y.max(other.top_left.y),
-            ),
-            bottom_right: UVec2::new(
-                self.bottom_right.x.min(other.bottom_right.x),
-                self.bottom_right.y.min(other.bottom_right.y),
-            ),
-        };
+impl TryFrom<URect> for IRect {
+    type Error = RectConversionError;
 
-        if result.is_positive_area() {
-            Some(result)
-        } else {
-            None
+    /// Converts to an `IRect`, failing if any coordinate is above `i32::MAX`.
+    #[inline]
+    fn try_from(rect: URect) -> Result<Self, Self::Error> {
+        if rect.top_left.max_element() > i32::MAX as u32
+            || rect.bottom_right.max_element() > i32::MAX as u32
+        {
+            return Err(RectConversionError::TooLarge);
         }
+        Ok(IRect::new(
+            rect.top_left.as_ivec2(),
+            rect.bottom_right.as_ivec2(),
+        ))
     }
-    /// A constant representing a rectangle with position (0, 0) and zero area.
-    /// Each component is set to zero.
-    pub const ZERO: URect = URect::new(UVec2::ZERO, UVec2::ZERO);
-    /// Returns `true` if the rectangle has zero area.
-    #[inline]
-    pub fn is_zero_area(&self) -> bool {
-        self.top_left.x == self.bottom_right.x || self.top_left.y == self.bottom_right.y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_intersect_1() {
+        let r1 = Rect::from_tuples((100.0, 100.0), (200.0, 200.0));
+        let r2 = Rect::from_tuples((100.0, 300.0), (200.0, 400.0));
+        let r3 = Rect::from_tuples((125.0, 50.0), (175.0, 500.0));
+
+        assert_eq!(None, r1.intersect(&r2));
+
+        assert_eq!(
+            Some(Rect::from_tuples((125.0, 100.0), (175.0, 200.0))),
+            r1.intersect(&r3)
+        );
+
+        assert_eq!(
+            Some(Rect::from_tuples((125.0, 300.0), (175.0, 400.0))),
+            r2.intersect(&r3)
+        );
+
+        assert_eq!(Some(r1), r1.intersect(&r1));
+        assert_eq!(Some(r2), r2.intersect(&r2));
+        assert_eq!(Some(r3), r3.intersect(&r3));
     }
-    /// Returns `true` if the rectangle has an area greater than zero.
-    #[inline]
-    pub fn is_positive_area(&self) -> bool {
-        self.top_left.x < self.bottom_right.x && self.top_left.y < self.bottom_right.y
+
+    #[test]
+    pub fn test_intersect_2() {
+        let r1 = Rect::from_tuples((100.0, 100.0), (200.0, 200.0));
+        let r2 = Rect::from_tuples((100.0, 200.0), (200.0, 300.0));
+
+        assert_eq!(None, r1.intersect(&r2));
     }
-    /// Returns a new rectangle, whose vertices are offset relative to the
-    /// current rectangle by the specified amount. This is equivalent to
-    /// adding the specified vector to each vertex.
-    #[inline]
-    pub fn with_offset(&self, offset: impl Into<UVec2>) -> Self {
-        let offset = offset.into();
-        Self::new(self.top_left + offset, self.bottom_right + offset)
+
+    #[test]
+    #[cfg(feature = "strict")]
+    #[should_panic(expected = "top_left must be above and to the left of bottom_right")]
+    pub fn test_strict_inverted() {
+        let _ = Rect::from_tuples((200.0, 100.0), (100.0, 200.0));
     }
-    /// Returns a new rectangle, whose vertices are negatively offset relative
-    /// to the current rectangle by the specified amount. This is equivalent
-    /// to subtracting the specified vector to each vertex.
-    #[inline]
-    pub fn with_negative_offset(&self, offset: impl Into<UVec2>) -> Self {
-        let offset = offset.into();
-        Self::new(self.top_left - offset, self.bottom_right - offset)
+
+    #[test]
+    #[cfg(feature = "strict")]
+    #[should_panic(expected = "non-finite")]
+    pub fn test_strict_nan() {
+        let _ = Rect::new(Vec2::new(f32::NAN, 0.0), Vec2::ONE);
     }
-}
 
-#[derive(Debug, PartialEq, Eq, Clone)]
-#[repr(C)]
-pub struct IRect {
-    pub top_left: IVec2,
-    pub bottom_right: IVec2,
-}
+    #[test]
+    pub fn test_expand_to_aspect() {
+        let r = Rect::from_tuples((0.0, 0.0), (100.0, 100.0));
 
-impl IRect {
-    /// Constructs a new `Rect`. The top left vertex must be above and to
-    /// the left of the bottom right vertex.
-    #[inline]
-    pub const fn new(top_left: IVec2, bottom_right: IVec2) -> Self {
-        Self {
-            top_left,
-            bottom_right,
-        }
+        assert_eq!(
+            r.expand_to_aspect(2.0, Anchor::Center),
+            Rect::from_tuples((-50.0, 0.0), (150.0, 100.0))
+        );
+        assert_eq!(
+            r.expand_to_aspect(0.5, Anchor::TopLeft),
+            Rect::from_tuples((0.0, 0.0), (100.0, 200.0))
+        );
+        assert_eq!(
+            r.expand_to_aspect(0.5, Anchor::Bottom),
+            Rect::from_tuples((0.0, -100.0), (100.0, 100.0))
+        );
+        assert_eq!(r.expand_to_aspect(1.0, Anchor::Center), r);
     }
 
-    #[inline]
-    pub const fn corners(self) -> [IVec2; 4] {
-        let top_right = IVec2::new(self.bottom_right.x, self.top_left.y);
-        let bottom_left = IVec2::new(self.top_left.x, self.bottom_right.y);
-        [self.top_left, top_right, self.bottom_right, bottom_left]
-    }
+    #[test]
+    pub fn test_union() {
+        let r1 = IRect::from_tuples((0, 0), (10, 10));
+        let r2 = IRect::from_tuples((-5, 20), (5, 30));
 
-    #[inline]
-    pub const fn top_right(&self) -> IVec2 {
-        IVec2::new(self.bottom_right.x, self.top_left.y)
+        assert_eq!(r1.union(&r2), IRect::from_tuples((-5, 0), (10, 30)));
+        assert_eq!(r1.union(&r1), r1);
+        assert_eq!(
+            r1.union_point(IVec2::new(15, -1)),
+            IRect::from_tuples((0, -1), (16, 10))
+        );
+        assert_eq!(r1.union_point(IVec2::new(5, 5)), r1);
+        // The point on the bottom right vertex is outside `r1`, so its cell is
+        // added.
+        assert_eq!(
+            r1.union_point(IVec2::new(10, 10)),
+            IRect::from_tuples((0, 0), (11, 11))
+        );
+        let edge = URect::from_tuples((0, 0), (1, 1)).union_point(UVec2::new(u32::MAX, 0));
+        assert_eq!(edge.bottom_right.x, u32::MAX);
     }
 
-    #[inline]
-    pub const fn bottom_left(&self) -> IVec2 {
-        IVec2::new(self.top_left.x, self.bottom_right.y)
+    #[test]
+    pub fn test_expand_edges() {
+        let r = Rect::from_tuples((10.0, 10.0), (20.0, 20.0));
+        assert_eq!(
+            r.expand_edges(1.0, 2.0, 3.0, -4.0),
+            Rect::from_tuples((9.0, 8.0), (23.0, 16.0))
+        );
+
+        let u = URect::from_tuples((2, 2), (u32::MAX - 1, 10));
+        assert_eq!(
+            u.expand_edges(5, 1, 5, 1),
+            URect::from_tuples((0, 1), (u32::MAX, 11))
+        );
     }
 
-    /// Constructs a new `Rect`. The top left vertex must be above and to
-    /// the left of the bottom right vertex.
-    #[inline]
-    pub fn from_tuples(top_left: (i32, i32), bottom_right: (i32, i32)) -> Self {
-        Self {
-            top_left: IVec2::new(top_left.0, top_left.1),
-            bottom_right: IVec2::new(bottom_right.0, bottom_right.1),
-        }
+    #[test]
+    pub fn test_from_points() {
+        assert_eq!(None, Rect::from_points([]));
+        assert_eq!(
+            Some(Rect::from_tuples((1.0, 2.0), (1.0, 2.0))),
+            Rect::from_points([Vec2::new(1.0, 2.0)])
+        );
+        let points = [IVec2::new(4, 0), IVec2::new(-3, 7), IVec2::new(0, 2)];
+        let bounds = IRect::from_points(points).unwrap();
+        assert_eq!(bounds, IRect::from_tuples((-3, 0), (5, 8)));
+        assert!(points.iter().all(|&point| bounds.contains(point)));
+        assert_eq!(
+            URect::from_points([UVec2::new(2, 3)]),
+            Some(URect::from_tuples((2, 3), (3, 4)))
+        );
+        assert_eq!(IRect::from_points([]), None);
     }
 
-    /// Returns the width of the rectangle.
-    #[inline]
-    pub fn width(&self) -> i32 {
-        self.bottom_right.x - self.top_left.x
+    #[test]
+    pub fn test_side_queries() {
+        let r = Rect::from_tuples((0.0, 0.0), (3.0, 4.0));
+        assert_eq!(r.diagonal_length(), 5.0);
+        assert_eq!(r.longest_side(), 4.0);
+        assert_eq!(r.shortest_side(), 3.0);
+        assert!(!r.is_square(0.5));
+        assert!(r.is_square(1.0));
+
+        let u = URect::from_tuples((0, 0), (6, 8));
+        assert_eq!(u.diagonal_length(), 10.0);
+        assert!(!u.is_square());
     }
 
-    /// Returns the height of the rectangle.
-    #[inline]
-    pub fn height(&self) -> i32 {
-        self.bottom_right.y - self.top_left.y
+    #[test]
+    pub fn test_intersects_touches() {
+        let r1 = URect::from_tuples((0, 0), (10, 10));
+        let edge = URect::from_tuples((10, 5), (20, 15));
+        let corner = URect::from_tuples((10, 10), (20, 20));
+        let overlap = URect::from_tuples((5, 5), (15, 15));
+        let apart = URect::from_tuples((11, 0), (20, 10));
+
+        assert!(r1.intersects(&overlap) && !r1.touches(&overlap));
+        assert!(!r1.intersects(&edge) && r1.touches(&edge));
+        assert!(!r1.intersects(&corner) && r1.touches(&corner));
+        assert!(!r1.intersects(&apart) && !r1.touches(&apart));
+        assert_eq!(r1.intersects(&overlap), r1.intersect(&overlap).is_some());
     }
 
-    /// Returns a `Vector2` containing the width and height of the rectangle.
-    #[inline]
-    pub fn size(&self) -> IVec2 {
-        IVec2::new(self.width(), self.height())
+    #[test]
+    pub fn test_vec4_packing() {
+        let r = Rect::from_tuples((1.0, 2.0), (4.0, 8.0));
+        assert_eq!(r.to_vec4(), Vec4::new(1.0, 2.0, 4.0, 8.0));
+        assert_eq!(r.to_xywh_vec4(), Vec4::new(1.0, 2.0, 3.0, 6.0));
+        assert_eq!(Rect::from_vec4(r.to_vec4()), r);
+
+        let i = IRect::from_tuples((-1, -2), (3, 4));
+        assert_eq!(i.to_vec4(), IVec4::new(-1, -2, 3, 4));
+        assert_eq!(IRect::from_vec4(i.to_vec4()), i);
     }
-    /// Returns true if the specified point is inside this rectangle. This is
-    /// inclusive of the top and left coordinates, and exclusive of the bottom
-    /// and right coordinates.
-    #[inline]
-    #[must_use]
-    pub fn contains(&self, point: IVec2) -> bool {
-        point.x >= self.top_left.x
-            && point.y >= self.top_left.y
-            && point.x < self.bottom_right.x
-            && point.y < self.bottom_right.y
-    }
-    /// Finds the intersection of two rectangles -- in other words, the area
-    /// that is common to both of them.
-    ///
-    /// If there is no common area between the two rectangles, then this
-    /// function will return `None`.
-    #[inline]
-    #[must_use]
-    pub fn intersect(&self, other: &Self) -> Option<Self> {
-        let result = Self {
-            top_left: IVec2::new(
-                self.top_left.x.max(other.top_left.x),
-                self.top_left.y.max(other.top_left.y),
-            ),
-            bottom_right: IVec2::new(
-                self.bottom_right.x.min(other.bottom_right.x),
-                self.bottom_right.y.min(other.bottom_right.y),
-            ),
-        };
 
-        if result.is_positive_area() {
-            Some(result)
-        } else {
-            None
-        }
+    #[test]
+    pub fn test_metrics() {
+        let r = Rect::from_tuples((1.0, 2.0), (5.0, 4.0));
+        assert_eq!(r.area(), 8.0);
+        assert_eq!(r.perimeter(), 12.0);
+        assert_eq!(r.center(), Vec2::new(3.0, 3.0));
+        assert_eq!(r.aspect_ratio(), 2.0);
+
+        let u = URect::from_tuples((0, 0), (u32::MAX, u32::MAX));
+        assert_eq!(u.area(), u32::MAX as u64 * u32::MAX as u64);
+
+        let i = IRect::from_tuples((-3, -3), (0, 0));
+        assert_eq!(i.center(), IVec2::new(-2, -2));
     }
-    /// A constant representing a rectangle with position (0, 0) and zero area.
-    /// Each component is set to zero.
-    pub const ZERO: URect = URect::new(UVec2::ZERO, UVec2::ZERO);
-    /// Returns `true` if the rectangle has zero area.
-    #[inline]
-    pub fn is_zero_area(&self) -> bool {
-        self.top_left.x == self.bottom_right.x || self.top_left.y == self.bottom_right.y
+
+    #[test]
+    pub fn test_boundary_intersections() {
+        let r = Rect::from_tuples((0.0, 0.0), (10.0, 10.0));
+
+        let through = r.boundary_intersections(Vec2::new(-5.0, 5.0), Vec2::new(15.0, 5.0));
+        assert_eq!(
+            through.as_slice(),
+            &[Vec2::new(0.0, 5.0), Vec2::new(10.0, 5.0)]
+        );
+
+        let from_inside = r.boundary_intersections(Vec2::new(5.0, 5.0), Vec2::new(5.0, 20.0));
+        assert_eq!(from_inside.as_slice(), &[Vec2::new(5.0, 10.0)]);
+
+        let inside = r.boundary_intersections(Vec2::new(2.0, 2.0), Vec2::new(8.0, 8.0));
+        assert!(inside.is_empty());
+
+        let outside = r.boundary_intersections(Vec2::new(-5.0, 0.0), Vec2::new(0.0, -5.0));
+        assert!(outside.is_empty());
+
+        let corner = r.boundary_intersections(Vec2::new(-5.0, 5.0), Vec2::new(5.0, -5.0));
+        assert_eq!(corner.as_slice(), &[Vec2::new(0.0, 0.0)]);
     }
-    /// Returns `true` if the rectangle has an area greater than zero.
-    #[inline]
-    pub fn is_positive_area(&self) -> bool {
-        self.top_left.x < self.bottom_right.x && self.top_left.y < self.bottom_right.y
+
+    #[test]
+    pub fn test_position_and_center_constructors() {
+        assert_eq!(
+            Rect::from_position_size(Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0)),
+            Rect::from_tuples((1.0, 2.0), (4.0, 6.0))
+        );
+        assert_eq!(
+            Rect::from_center_size(Vec2::new(0.0, 0.0), Vec2::new(4.0, 2.0)),
+            Rect::from_tuples((-2.0, -1.0), (2.0, 1.0))
+        );
+        assert_eq!(
+            URect::from_center_size(UVec2::new(10, 10), UVec2::new(5, 4)),
+            URect::from_tuples((8, 8), (13, 12))
+        );
     }
-    /// Returns a new rectangle, whose vertices are offset relative to the
-    /// current rectangle by the specified amount. This is equivalent to
-    /// adding the specified vector to each vertex.
-    #[inline]
-    pub fn with_offset(&self, offset: impl Into<IVec2>) -> Self {
-        let offset = offset.into();
-        Self::new(self.top_left + offset, self.bottom_right + offset)
+
+    #[test]
+    pub fn test_rect_distance() {
+        let r = Rect::from_tuples((0.0, 0.0), (10.0, 10.0));
+        let diagonal = Rect::from_tuples((13.0, 14.0), (20.0, 20.0));
+        let left = Rect::from_tuples((-8.0, 2.0), (-5.0, 8.0));
+        let overlapping = Rect::from_tuples((5.0, 5.0), (15.0, 15.0));
+
+        assert_eq!(r.separation_vector(&diagonal), Vec2::new(3.0, 4.0));
+        assert_eq!(r.distance_to_rect(&diagonal), 5.0);
+        assert_eq!(r.separation_vector(&left), Vec2::new(-5.0, 0.0));
+        assert_eq!(r.separation_vector(&overlapping), Vec2::ZERO);
+        assert_eq!(r.distance_to_rect(&overlapping), 0.0);
     }
-    /// Returns a new rectangle, whose vertices are negatively offset relative
-    /// to the current rectangle by the specified amount. This is equivalent
-    /// to subtracting the specified vector to each vertex.
-    #[inline]
-    pub fn with_negative_offset(&self, offset: impl Into<IVec2>) -> Self {
-        let offset = offset.into();
-        Self::new(self.top_left - offset, self.bottom_right - offset)
+
+    #[test]
+    pub fn test_inflate_deflate() {
+        let r = Rect::from_tuples((0.0, 0.0), (10.0, 10.0));
+
+        assert_eq!(
+            r.inflate(1.0),
+            Rect::from_tuples((-1.0, -1.0), (11.0, 11.0))
+        );
+        assert_eq!(
+            r.inflate(Vec2::new(1.0, 2.0)),
+            Rect::from_tuples((-1.0, -2.0), (11.0, 12.0))
+        );
+        assert_eq!(
+            r.deflate(Insets::new(1.0, 2.0, 3.0, 4.0)),
+            Some(Rect::from_tuples((1.0, 2.0), (7.0, 6.0)))
+        );
+        assert_eq!(
+            r.deflate(5.0),
+            Some(Rect::from_tuples((5.0, 5.0), (5.0, 5.0)))
+        );
+        assert_eq!(r.deflate(6.0), None);
+        assert_eq!(
+            r.deflate_clamped(Insets::new(8.0, 1.0, 8.0, 1.0)),
+            Rect::from_tuples((5.0, 1.0), (5.0, 9.0))
+        );
+        assert_eq!(
+            r.deflate_clamped(Insets::new(20.0, 0.0, 0.0, 0.0)),
+            Rect::from_tuples((10.0, 0.0), (10.0, 10.0))
+        );
     }
-}
 
+    #[test]
+    pub fn test_scale() {
+        let r = Rect::from_tuples((1.0, 1.0), (3.0, 5.0));
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        assert_eq!(
+            r.scale(Vec2::splat(2.0)),
+            Rect::from_tuples((2.0, 2.0), (6.0, 10.0))
+        );
+        assert_eq!(
+            r.scale_around(r.center(), Vec2::new(2.0, 0.5)),
+            Rect::from_tuples((0.0, 2.0), (4.0, 4.0))
+        );
+        assert_eq!(
+            r.scale_around(Vec2::ZERO, Vec2::new(-1.0, 1.0)),
+            Rect::from_tuples((-3.0, 1.0), (-1.0, 5.0))
+        );
+        assert_eq!(
+            IRect::from_tuples((0, 0), (3, 3)).scale_around_rounded(Vec2::ZERO, Vec2::splat(0.5)),
+            IRect::from_tuples((0, 0), (2, 2))
+        );
+    }
 
     #[test]
-    pub fn test_intersect_1() {
-        let r1 = Rect::from_tuples((100.0, 100.0), (200.0, 200.0));
-        let r2 = Rect::from_tuples((100.0, 300.0), (200.0, 400.0));
-        let r3 = Rect::from_tuples((125.0, 50.0), (175.0, 500.0));
+    pub fn test_clamp() {
+        let screen = Rect::from_tuples((0.0, 0.0), (100.0, 50.0));
 
-        assert_eq!(None, r1.intersect(&r2));
+        assert_eq!(
+            screen.clamp_point(Vec2::new(-5.0, 20.0)),
+            Vec2::new(0.0, 20.0)
+        );
+        assert_eq!(
+            screen.clamp_point(Vec2::new(150.0, 60.0)),
+            Vec2::new(100.0, 50.0)
+        );
 
+        let tooltip = Rect::from_tuples((90.0, -10.0), (120.0, 10.0));
         assert_eq!(
-            Some(Rect::from_tuples((125.0, 100.0), (175.0, 200.0))),
-            r1.intersect(&r3)
+            tooltip.clamp_inside(&screen),
+            Rect::from_tuples((70.0, 0.0), (100.0, 20.0))
+        );
+        let tall = Rect::from_tuples((10.0, -10.0), (20.0, 80.0));
+        assert_eq!(
+            tall.clamp_inside(&screen),
+            Rect::from_tuples((10.0, 0.0), (20.0, 50.0))
+        );
+    }
+
+    #[test]
+    pub fn test_swept_bounds() {
+        let r = Rect::from_tuples((0.0, 0.0), (10.0, 10.0));
+        assert_eq!(
+            r.swept_bounds(Vec2::new(20.0, -10.0), 0.5),
+            Rect::from_tuples((0.0, -5.0), (20.0, 10.0))
         );
+        assert_eq!(r.swept_bounds(Vec2::ZERO, 1.0), r);
+    }
 
+    #[test]
+    pub fn test_lerp() {
+        let a = Rect::from_tuples((0.0, 0.0), (10.0, 10.0));
+        let b = Rect::from_tuples((10.0, 20.0), (30.0, 40.0));
+        assert_eq!(a.lerp(&b, 0.0), a);
+        assert_eq!(a.lerp(&b, 1.0), b);
         assert_eq!(
-            Some(Rect::from_tuples((125.0, 300.0), (175.0, 400.0))),
-            r2.intersect(&r3)
+            a.lerp(&b, 0.5),
+            Rect::from_tuples((5.0, 10.0), (20.0, 25.0))
         );
 
-        assert_eq!(Some(r1.clone()), r1.intersect(&r1));
-        assert_eq!(Some(r2.clone()), r2.intersect(&r2));
-        assert_eq!(Some(r3.clone()), r3.intersect(&r3));
+        let a = IRect::from_tuples((0, 0), (1, 1));
+        let b = IRect::from_tuples((-3, 3), (4, 4));
+        assert_eq!(a.lerp(&b, 0.5), IRect::from_tuples((-2, 2), (3, 3)));
     }
 
     #[test]
-    pub fn test_intersect_2() {
-        let r1 = Rect::from_tuples((100.0, 100.0), (200.0, 200.0));
-        let r2 = Rect::from_tuples((100.0, 200.0), (200.0, 300.0));
+    pub fn test_normalize_and_try_new() {
+        let inverted = Rect {
+            top_left: Vec2::new(10.0, 0.0),
+            bottom_right: Vec2::new(0.0, 10.0),
+        };
+        assert!(!inverted.is_normalized());
+        assert_eq!(
+            inverted.normalize(),
+            Rect::from_tuples((0.0, 0.0), (10.0, 10.0))
+        );
+        assert!(inverted.normalize().is_normalized());
 
-        assert_eq!(None, r1.intersect(&r2));
+        assert_eq!(
+            Rect::try_new(Vec2::new(10.0, 0.0), Vec2::new(0.0, 10.0)),
+            Err(RectError::Inverted)
+        );
+        assert_eq!(
+            Rect::try_new(Vec2::new(f32::NAN, 0.0), Vec2::ONE),
+            Err(RectError::NonFinite)
+        );
+        assert_eq!(
+            Rect::try_new(Vec2::ZERO, Vec2::ONE),
+            Ok(Rect::new(Vec2::ZERO, Vec2::ONE))
+        );
+        assert_eq!(
+            IRect::try_new(IVec2::new(i32::MIN, 0), IVec2::new(i32::MAX, 0)),
+            Err(RectError::Overflow)
+        );
+    }
+
+    #[test]
+    pub fn test_rect_conversions() {
+        let r = Rect::from_tuples((0.5, -1.5), (2.4, 3.6));
+        assert_eq!(
+            r.to_irect(RoundMode::Floor),
+            IRect::from_tuples((0, -2), (2, 3))
+        );
+        assert_eq!(
+            r.to_irect(RoundMode::Ceil),
+            IRect::from_tuples((1, -1), (3, 4))
+        );
+        assert_eq!(
+            r.to_irect(RoundMode::Round),
+            IRect::from_tuples((1, -2), (2, 4))
+        );
+        assert_eq!(
+            r.to_irect(RoundMode::Expand),
+            IRect::from_tuples((0, -2), (3, 4))
+        );
+
+        let i = IRect::from_tuples((-1, 0), (5, 5));
+        assert_eq!(i.as_rect(), Rect::from_tuples((-1.0, 0.0), (5.0, 5.0)));
+        assert_eq!(i.to_urect(), None);
+        assert_eq!(
+            IRect::from_tuples((1, 0), (5, 5)).to_urect(),
+            Some(URect::from_tuples((1, 0), (5, 5)))
+        );
+    }
+
+    #[test]
+    pub fn test_drect() {
+        let r = DRect::from_tuples((1_000_000_000.0, 0.0), (1_000_000_000.5, 0.25));
+        assert_eq!(r.size(), DVec2::new(0.5, 0.25));
+        assert_eq!(
+            r.intersect(&r.with_offset(DVec2::new(0.25, 0.0))),
+            Some(DRect::from_tuples(
+                (1_000_000_000.25, 0.0),
+                (1_000_000_000.5, 0.25)
+            ))
+        );
+
+        let single = Rect::from_tuples((0.5, 1.0), (2.0, 3.0));
+        assert_eq!(DRect::from(single).as_rect(), single);
+    }
+
+    #[test]
+    pub fn test_zero() {
+        let zero: IRect = IRect::ZERO;
+        assert_eq!(zero, IRect::new(IVec2::ZERO, IVec2::ZERO));
+        assert!(URect::ZERO.is_zero_area());
+        assert!(DRect::ZERO.is_zero_area());
+    }
+
+    #[test]
+    pub fn test_operators() {
+        let mut r = Rect::from_tuples((1.0, 2.0), (3.0, 4.0));
+        assert_eq!(r + Vec2::ONE, Rect::from_tuples((2.0, 3.0), (4.0, 5.0)));
+        assert_eq!(r - Vec2::ONE, Rect::from_tuples((0.0, 1.0), (2.0, 3.0)));
+        assert_eq!(r * 2.0, Rect::from_tuples((2.0, 4.0), (6.0, 8.0)));
+        assert_eq!(r * -1.0, Rect::from_tuples((-3.0, -4.0), (-1.0, -2.0)));
+        assert_eq!(r / 2.0, Rect::from_tuples((0.5, 1.0), (1.5, 2.0)));
+        r += Vec2::X;
+        r *= 2.0;
+        assert_eq!(r, Rect::from_tuples((4.0, 4.0), (8.0, 8.0)));
+
+        let mut i = IRect::from_tuples((1, 2), (3, 4));
+        i -= IVec2::new(1, 2);
+        i *= -2;
+        assert_eq!(i, IRect::from_tuples((-4, -4), (0, 0)));
+        assert_eq!(
+            URect::from_tuples((2, 4), (6, 8)) / 2,
+            URect::from_tuples((1, 2), (3, 4))
+        );
+    }
+
+    #[test]
+    pub fn test_points() {
+        let r = IRect::from_tuples((-1, 2), (1, 4));
+        let points: Vec<_> = r.points().collect();
+        assert_eq!(
+            points,
+            [
+                IVec2::new(-1, 2),
+                IVec2::new(0, 2),
+                IVec2::new(-1, 3),
+                IVec2::new(0, 3)
+            ]
+        );
+        assert!(points.iter().all(|&p| r.contains(p)));
+        assert_eq!(URect::from_tuples((3, 3), (3, 9)).points().count(), 0);
+    }
+
+    #[test]
+    pub fn test_row_spans() {
+        let r = IRect::from_tuples((1, 2), (3, 5));
+        assert_eq!(
+            r.rows().collect::<Vec<_>>(),
+            [(2, 1..3), (3, 1..3), (4, 1..3)]
+        );
+        assert_eq!(r.columns().collect::<Vec<_>>(), [(1, 2..5), (2, 2..5)]);
+
+        let mut pixels = [0u8; 16];
+        URect::from_tuples((1, 1), (3, 3)).for_each_row(&mut pixels, 4, |y, row| {
+            row.fill(y as u8);
+        });
+        assert_eq!(pixels, [0, 0, 0, 0, 0, 1, 1, 0, 0, 2, 2, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "past the end of a row")]
+    pub fn test_for_each_row_past_stride() {
+        let mut pixels = [0u8; 16];
+        // The rect fits in the buffer, but its rows would wrap into the next.
+        URect::from_tuples((3, 0), (5, 2)).for_each_row(&mut pixels, 4, |_, row| row.fill(1));
+    }
+
+    #[test]
+    pub fn test_split_grid() {
+        let r = Rect::from_tuples((0.0, 0.0), (32.0, 10.0));
+        let cells: Vec<_> = r.split_grid(3, 1, Vec2::new(1.0, 0.0)).collect();
+        assert_eq!(cells[0], Rect::from_tuples((0.0, 0.0), (10.0, 10.0)));
+        assert_eq!(cells[2], Rect::from_tuples((22.0, 0.0), (32.0, 10.0)));
+
+        let r = URect::from_tuples((0, 0), (10, 4));
+        let widths: Vec<_> = r.split_grid(3, 2, UVec2::ZERO).map(|c| c.width()).collect();
+        assert_eq!(widths, [3, 3, 4, 3, 3, 4]);
+        let cells: Vec<_> = IRect::from_tuples((-5, 0), (6, 1))
+            .split_grid(2, 1, IVec2::new(1, 0))
+            .collect();
+        assert_eq!(
+            cells,
+            [
+                IRect::from_tuples((-5, 0), (0, 1)),
+                IRect::from_tuples((1, 0), (6, 1))
+            ]
+        );
+        assert!(URect::from_tuples((0, 0), (2, 2))
+            .split_grid(2, 2, UVec2::new(5, 5))
+            .all(|c| c.is_zero_area()));
+    }
+
+    #[test]
+    pub fn test_subtract() {
+        let r = IRect::from_tuples((0, 0), (10, 10));
+        let parts = r.subtract(&IRect::from_tuples((2, 3), (5, 7)));
+        assert_eq!(
+            parts.as_slice(),
+            [
+                IRect::from_tuples((0, 0), (10, 3)),
+                IRect::from_tuples((0, 7), (10, 10)),
+                IRect::from_tuples((0, 3), (2, 7)),
+                IRect::from_tuples((5, 3), (10, 7)),
+            ]
+        );
+        assert_eq!(parts.iter().map(IRect::area).sum::<i64>(), 100 - 12);
+
+        let parts = r.subtract(&IRect::from_tuples((5, -5), (20, 20)));
+        assert_eq!(parts.as_slice(), [IRect::from_tuples((0, 0), (5, 10))]);
+        assert!(r.subtract(&r).is_empty());
+        assert_eq!(
+            r.subtract(&IRect::from_tuples((10, 0), (20, 10)))
+                .as_slice(),
+            [r]
+        );
+
+        let f = Rect::from_tuples((0.0, 0.0), (1.0, 1.0));
+        assert_eq!(f.subtract(&f.with_offset(Vec2::splat(0.5))).len(), 2);
+    }
+
+    #[test]
+    pub fn test_covered_cells() {
+        let r = Rect::from_tuples((-3.0, 2.0), (17.0, 12.0));
+        let cell = Vec2::splat(10.0);
+        assert_eq!(
+            r.covered_cells(cell, CoverMode::Touched),
+            IRect::from_tuples((-1, 0), (2, 2))
+        );
+        assert_eq!(
+            r.covered_cells(cell, CoverMode::Majority),
+            IRect::from_tuples((0, 0), (2, 1))
+        );
+        assert_eq!(
+            r.covered_cells(cell, CoverMode::Full),
+            IRect::from_tuples((0, 1), (1, 1))
+        );
+
+        let narrow = Rect::from_tuples((2.0, 2.0), (6.0, 9.0));
+        assert!(narrow
+            .covered_cells(cell, CoverMode::Majority)
+            .is_zero_area());
+        assert_eq!(
+            Rect::from_tuples((-16.0, 2.0), (-8.0, 9.0)).covered_cells(cell, CoverMode::Majority),
+            IRect::from_tuples((-2, 0), (-1, 1))
+        );
+    }
+
+    #[test]
+    pub fn test_ray_segment() {
+        let rect = Rect::from_tuples((0.0, 0.0), (10.0, 10.0));
+        assert_eq!(
+            rect.intersect_ray(Vec2::new(-5.0, 5.0), Vec2::new(1.0, 0.0)),
+            Some((5.0, 15.0))
+        );
+        assert_eq!(
+            rect.intersect_ray(Vec2::new(5.0, 5.0), Vec2::new(0.0, -2.0)),
+            Some((0.0, 2.5))
+        );
+        assert_eq!(
+            rect.intersect_ray(Vec2::new(-5.0, 5.0), Vec2::new(-1.0, 0.0)),
+            None
+        );
+        assert_eq!(
+            rect.intersect_segment(Vec2::new(-10.0, 5.0), Vec2::new(10.0, 5.0)),
+            Some((0.5, 1.0))
+        );
+        assert_eq!(
+            rect.clip_segment(Vec2::new(-5.0, -5.0), Vec2::new(15.0, 15.0)),
+            Some((Vec2::new(0.0, 0.0), Vec2::new(10.0, 10.0)))
+        );
+        assert_eq!(
+            rect.clip_segment(Vec2::new(-5.0, 0.0), Vec2::new(0.0, -5.0)),
+            None
+        );
+    }
+
+    #[test]
+    pub fn test_point_distance() {
+        let rect = Rect::from_tuples((0.0, 0.0), (10.0, 4.0));
+        assert_eq!(
+            rect.closest_point(Vec2::new(-3.0, 8.0)),
+            Vec2::new(0.0, 4.0)
+        );
+        assert_eq!(rect.distance_squared(Vec2::new(13.0, 8.0)), 25.0);
+        assert_eq!(rect.distance(Vec2::new(13.0, 8.0)), 5.0);
+        assert_eq!(rect.distance(Vec2::new(5.0, 1.0)), 0.0);
+        assert_eq!(rect.signed_distance(Vec2::new(13.0, 8.0)), 5.0);
+        assert_eq!(rect.signed_distance(Vec2::new(5.0, -2.0)), 2.0);
+        assert_eq!(rect.signed_distance(Vec2::new(5.0, 1.0)), -1.0);
+        assert_eq!(rect.signed_distance(Vec2::new(10.0, 2.0)), 0.0);
+    }
+
+    #[test]
+    pub fn test_anchor_alignment() {
+        let container = Rect::from_tuples((0.0, 0.0), (100.0, 50.0));
+        assert_eq!(container.point_at(Anchor::Bottom), Vec2::new(50.0, 50.0));
+
+        let rect = Rect::from_tuples((0.0, 0.0), (20.0, 10.0));
+        let margin = Vec2::new(4.0, 2.0);
+        assert_eq!(
+            rect.aligned_inside(&container, Anchor::Center, margin),
+            Rect::from_tuples((40.0, 20.0), (60.0, 30.0))
+        );
+        assert_eq!(
+            rect.aligned_inside(&container, Anchor::TopRight, margin),
+            Rect::from_tuples((76.0, 2.0), (96.0, 12.0))
+        );
+        assert_eq!(
+            rect.aligned_inside(&container, Anchor::Left, margin),
+            Rect::from_tuples((4.0, 20.0), (24.0, 30.0))
+        );
+    }
+
+    #[test]
+    pub fn test_cache_key() {
+        let rect = Rect::from_tuples((0.0, 0.0), (10.0, 20.0));
+        assert_eq!(rect.cache_key(None), rect.cache_key(None));
+        assert_eq!(
+            rect.cache_key(None),
+            Rect::from_tuples((-0.0, 0.0), (10.0, 20.0)).cache_key(None)
+        );
+        assert_ne!(
+            rect.cache_key(None),
+            rect.with_offset((0.1, 0.0)).cache_key(None)
+        );
+        assert_eq!(
+            rect.cache_key(Some(0.5)),
+            rect.with_offset((0.1, 0.0)).cache_key(Some(0.5))
+        );
+        assert_ne!(rect.cache_key(None), rect.cache_key(Some(1.0)));
+        let nan = |x: f32| Rect {
+            top_left: Vec2::new(x, 0.0),
+            bottom_right: Vec2::new(10.0, 20.0),
+        };
+        assert_eq!(
+            nan(f32::NAN).cache_key(None),
+            nan(-f32::NAN).cache_key(None)
+        );
+    }
+
+    #[test]
+    pub fn test_fit_fill() {
+        let window = Rect::from_tuples((0.0, 0.0), (1920.0, 1080.0));
+        let target = Rect::from_tuples((0.0, 0.0), (640.0, 480.0));
+        assert_eq!(
+            target.fit_inside(&window),
+            Rect::from_tuples((240.0, 0.0), (1680.0, 1080.0))
+        );
+        assert_eq!(
+            target.fill(&window),
+            Rect::from_tuples((0.0, -180.0), (1920.0, 1260.0))
+        );
+        let line = Rect::from_tuples((0.0, 0.0), (10.0, 0.0));
+        assert_eq!(
+            line.fit_inside(&window),
+            Rect::from_tuples((960.0, 540.0), (960.0, 540.0))
+        );
+    }
+
+    #[test]
+    pub fn test_nine_slice() {
+        let panel = Rect::from_tuples((0.0, 0.0), (100.0, 50.0));
+        let slices = panel.nine_slice(Insets::new(10.0, 5.0, 20.0, 15.0));
+        assert_eq!(slices[0], Rect::from_tuples((0.0, 0.0), (10.0, 5.0)));
+        assert_eq!(
+            slices[Anchor::Center as usize],
+            Rect::from_tuples((10.0, 5.0), (80.0, 35.0))
+        );
+        assert_eq!(
+            slices[Anchor::Right as usize],
+            Rect::from_tuples((80.0, 5.0), (100.0, 35.0))
+        );
+        assert_eq!(slices[8], Rect::from_tuples((80.0, 35.0), (100.0, 50.0)));
+        assert_eq!(slices.iter().map(Rect::area).sum::<f32>(), panel.area());
+
+        let squashed = panel.nine_slice(30.0);
+        assert!(squashed[Anchor::Center as usize].is_zero_area());
+        assert_eq!(
+            squashed[Anchor::Left as usize],
+            Rect::from_tuples((0.0, 25.0), (30.0, 25.0))
+        );
+    }
+
+    #[test]
+    pub fn test_normalized_mapping() {
+        let rect = Rect::from_tuples((10.0, 20.0), (30.0, 60.0));
+        assert_eq!(
+            rect.to_normalized(Vec2::new(15.0, 50.0)),
+            Vec2::new(0.25, 0.75)
+        );
+        assert_eq!(
+            rect.to_normalized(Vec2::new(40.0, 20.0)),
+            Vec2::new(1.5, 0.0)
+        );
+        assert_eq!(
+            rect.from_normalized(Vec2::new(0.25, 0.75)),
+            Vec2::new(15.0, 50.0)
+        );
+
+        let viewport = Rect::from_tuples((-1.0, -1.0), (1.0, 1.0));
+        let screen = Rect::from_tuples((0.0, 0.0), (800.0, 600.0));
+        assert_eq!(
+            viewport.remap(&screen, Vec2::new(0.5, -0.5)),
+            Vec2::new(600.0, 150.0)
+        );
+    }
+
+    #[test]
+    pub fn test_array_conversions() {
+        let rect = Rect::from_tuples((1.0, 2.0), (4.0, 8.0));
+        let array: [f32; 4] = rect.into();
+        assert_eq!(array, [1.0, 2.0, 4.0, 8.0]);
+        assert_eq!(Rect::from(array), rect);
+        assert_eq!(Vec4::from(rect), rect.to_vec4());
+        assert_eq!(Rect::from_xywh_vec4(rect.to_xywh_vec4()), rect);
+
+        let irect = IRect::from([-1, -2, 3, 4]);
+        assert_eq!(<[i32; 4]>::from(irect), [-1, -2, 3, 4]);
+        assert_eq!(IRect::from_xywh_vec4(IVec4::new(-1, -2, 4, 6)), irect);
+        assert_eq!(
+            <[u32; 4]>::from(URect::from_tuples((0, 1), (2, 3))),
+            [0, 1, 2, 3]
+        );
+    }
+
+    #[test]
+    pub fn test_iou() {
+        let a = Rect::from_tuples((0.0, 0.0), (10.0, 10.0));
+        let b = Rect::from_tuples((5.0, 0.0), (15.0, 10.0));
+        assert_eq!(a.intersection_area(&b), 50.0);
+        assert_eq!(a.iou(&b), 50.0 / 150.0);
+        assert_eq!(a.iou(&a), 1.0);
+        assert_eq!(a.iou(&b.with_offset((10.0, 0.0))), 0.0);
+
+        let point = IRect::from_tuples((3, 3), (3, 3));
+        assert_eq!(point.iou(&point), 0.0);
+        let c = IRect::from_tuples((0, 0), (4, 4));
+        let d = IRect::from_tuples((2, 2), (6, 6));
+        assert_eq!(c.intersection_area(&d), 4);
+        assert_eq!(c.iou(&d), 4.0 / 28.0);
+        let full = URect::from_tuples((0, 0), (u32::MAX, u32::MAX));
+        assert_eq!(
+            full.intersection_area(&full),
+            u32::MAX as u64 * u32::MAX as u64
+        );
+        assert_eq!(full.iou(&full), 1.0);
+    }
+
+    #[test]
+    pub fn test_in_place_growth() {
+        let mut bounds = Rect::from_tuples((0.0, 0.0), (0.0, 0.0));
+        for point in [Vec2::new(3.0, -1.0), Vec2::new(-2.0, 4.0)] {
+            bounds.expand_to_include(point);
+        }
+        assert_eq!(bounds, Rect::from_tuples((-2.0, -1.0), (3.0, 4.0)));
+        bounds.expand_to_include_rect(&Rect::from_tuples((0.0, 0.0), (5.0, 1.0)));
+        assert_eq!(bounds, Rect::from_tuples((-2.0, -1.0), (5.0, 4.0)));
+        bounds.translate_mut((2.0, 1.0));
+        assert_eq!(bounds, Rect::from_tuples((0.0, 0.0), (7.0, 5.0)));
+        bounds.inflate_mut(1.0);
+        assert_eq!(bounds, Rect::from_tuples((-1.0, -1.0), (8.0, 6.0)));
+
+        let mut cells = URect::from_tuples((4, 4), (5, 5));
+        cells.expand_to_include(UVec2::new(1, 9));
+        assert_eq!(cells, URect::from_tuples((1, 4), (5, 10)));
+        assert!(cells.contains(UVec2::new(1, 9)));
+    }
+
+    #[test]
+    pub fn test_edges() {
+        let rect = Rect::from_tuples((1.0, 2.0), (5.0, 8.0));
+        assert_eq!(
+            (rect.left(), rect.top(), rect.right(), rect.bottom()),
+            (1.0, 2.0, 5.0, 8.0)
+        );
+        assert_eq!(Edge::ALL.map(|edge| rect.edge(edge)), [1.0, 2.0, 5.0, 8.0]);
+        assert_eq!(
+            rect.edge_segment(Edge::Right),
+            (Vec2::new(5.0, 2.0), Vec2::new(5.0, 8.0))
+        );
+        assert_eq!(
+            rect.edge_segment(Edge::Left),
+            (Vec2::new(1.0, 8.0), Vec2::new(1.0, 2.0))
+        );
+        assert_eq!(rect.edge_midpoints()[3], Vec2::new(3.0, 8.0));
+        assert_eq!(IRect::from_tuples((-1, 0), (3, 4)).edge(Edge::Left), -1);
+    }
+
+    #[test]
+    pub fn test_tessellation() {
+        let rect = Rect::from_tuples((0.0, 0.0), (2.0, 1.0));
+        let winding = |a: Vec2, b: Vec2, c: Vec2| (b - a).perp_dot(c - a);
+        let list = rect.to_triangle_list();
+        // Negative with y down means counter-clockwise on screen.
+        assert!(winding(list[0], list[1], list[2]) < 0.0);
+        assert!(winding(list[3], list[4], list[5]) < 0.0);
+        let strip = rect.to_triangle_strip();
+        assert!(winding(strip[0], strip[1], strip[2]) < 0.0);
+        assert!(winding(strip[2], strip[1], strip[3]) < 0.0);
+
+        let uv = Rect::from_tuples((0.5, 0.0), (1.0, 0.25));
+        let vertices = rect.to_triangle_list_uv(&uv);
+        assert_eq!(vertices[2], (Vec2::new(2.0, 1.0), Vec2::new(1.0, 0.25)));
+        let vertices = rect.to_triangle_strip_uv(&uv);
+        assert_eq!(vertices[1], (Vec2::new(0.0, 1.0), Vec2::new(0.5, 0.25)));
+    }
+
+    #[test]
+    pub fn test_pixel_snapping() {
+        let rect = Rect::from_tuples((10.3, 20.0), (30.6, 20.4));
+        assert_eq!(
+            rect.snap_outward(2.0),
+            Rect::from_tuples((10.0, 20.0), (31.0, 20.5))
+        );
+        assert_eq!(
+            rect.snap_inward(2.0),
+            Rect::from_tuples((10.5, 20.0), (30.5, 20.0))
+        );
+        assert_eq!(
+            rect.snap_outward_to_irect(2.0),
+            IRect::from_tuples((20, 40), (62, 41))
+        );
+        assert_eq!(
+            rect.snap_inward_to_irect(1.5),
+            IRect::from_tuples((16, 30), (45, 30))
+        );
+    }
+
+    #[test]
+    pub fn test_checked_arithmetic() {
+        let u = URect::from_tuples((2, 2), (10, 10));
+        assert_eq!(u.checked_negative_offset((3, 0)), None);
+        assert_eq!(
+            u.checked_negative_offset((2, 1)),
+            Some(URect::from_tuples((0, 1), (8, 9)))
+        );
+        assert_eq!(
+            u.saturating_negative_offset((4, 0)),
+            URect::from_tuples((0, 2), (6, 10))
+        );
+        assert_eq!(u.checked_offset((u32::MAX - 5, 0)), None);
+        assert_eq!(
+            u.saturating_offset((u32::MAX - 5, 0)),
+            URect::from_tuples((u32::MAX - 3, 2), (u32::MAX, 10))
+        );
+        assert_eq!(u.checked_expand(3), None);
+        assert_eq!(
+            u.checked_expand(2),
+            Some(URect::from_tuples((0, 0), (12, 12)))
+        );
+        assert_eq!(
+            u.checked_shrink(4),
+            Some(URect::from_tuples((6, 6), (6, 6)))
+        );
+        assert_eq!(u.checked_shrink(5), None);
+
+        let i = IRect::from_tuples((i32::MIN + 1, 0), (0, 1));
+        assert_eq!(i.checked_negative_offset((2, 0)), None);
+        assert_eq!(i.checked_shrink(-1), None);
+    }
+
+    #[test]
+    pub fn test_ranges() {
+        let rect = Rect::from_ranges(1.0..3.0, -2.0..0.5);
+        assert_eq!(rect, Rect::from_tuples((1.0, -2.0), (3.0, 0.5)));
+        assert_eq!(rect.x_range(), 1.0..3.0);
+        assert_eq!(rect.y_range(), -2.0..0.5);
+
+        let pixels: Vec<u32> = (0..16).collect();
+        let u = URect::from_ranges(1..3, 2..4);
+        let row = &pixels[u.y_range().start as usize * 4..][..4];
+        assert_eq!(
+            row[u.x_range().start as usize..u.x_range().end as usize],
+            [9, 10]
+        );
+        assert_eq!(IRect::from_ranges(-1..1, 0..2).x_range(), -1..1);
+    }
+
+    #[test]
+    pub fn test_closest_points_between() {
+        let a = Rect::from_tuples((0.0, 0.0), (2.0, 2.0));
+        let b = Rect::from_tuples((5.0, 1.0), (7.0, 6.0));
+        let (p, q) = a.closest_points_between(&b);
+        assert_eq!((p, q), (Vec2::new(2.0, 1.5), Vec2::new(5.0, 1.5)));
+        assert_eq!(p.distance(q), a.distance_to_rect(&b));
+
+        let c = Rect::from_tuples((4.0, 4.0), (6.0, 6.0));
+        let (p, q) = a.closest_points_between(&c);
+        assert_eq!((p, q), (Vec2::new(2.0, 2.0), Vec2::new(4.0, 4.0)));
+        assert_eq!(p.distance(q), a.distance_to_rect(&c));
+
+        let d = Rect::from_tuples((1.0, -1.0), (3.0, 1.0));
+        assert_eq!(
+            a.closest_points_between(&d),
+            (Vec2::new(1.5, 0.5), Vec2::new(1.5, 0.5))
+        );
+        assert_eq!(a.distance_to_rect(&d), 0.0);
+    }
+
+    #[test]
+    pub fn test_clip_polygon() {
+        let rect = Rect::from_tuples((0.0, 0.0), (4.0, 4.0));
+        let triangle = [
+            Vec2::new(1.0, -1.0),
+            Vec2::new(5.0, 3.0),
+            Vec2::new(1.0, 3.0),
+        ];
+        assert_eq!(
+            rect.clip_polygon(&triangle),
+            [
+                Vec2::new(1.0, 0.0),
+                Vec2::new(2.0, 0.0),
+                Vec2::new(4.0, 2.0),
+                Vec2::new(4.0, 3.0),
+                Vec2::new(1.0, 3.0),
+            ]
+        );
+
+        let inside = [
+            Vec2::new(1.0, 1.0),
+            Vec2::new(3.0, 1.0),
+            Vec2::new(2.0, 3.0),
+        ];
+        assert_eq!(rect.clip_polygon(&inside), inside);
+        let outside = [
+            Vec2::new(5.0, 5.0),
+            Vec2::new(6.0, 5.0),
+            Vec2::new(6.0, 6.0),
+        ];
+        assert!(rect.clip_polygon(&outside).is_empty());
+        assert!(rect.clip_polygon(&[]).is_empty());
+    }
+
+    #[test]
+    pub fn test_axis_splits() {
+        let rect = Rect::from_tuples((0.0, 0.0), (10.0, 4.0));
+        assert_eq!(
+            rect.split_at_x(3.0),
+            (
+                Rect::from_tuples((0.0, 0.0), (3.0, 4.0)),
+                Rect::from_tuples((3.0, 0.0), (10.0, 4.0))
+            )
+        );
+        assert_eq!(
+            rect.split_at_y(-1.0).0,
+            Rect::from_tuples((0.0, 0.0), (10.0, 0.0))
+        );
+        assert_eq!(
+            rect.split_fraction(SplitAxis::Vertical, 0.25),
+            rect.split_at_y(1.0)
+        );
+        assert_eq!(rect.split_fraction(SplitAxis::Horizontal, 2.0).0, rect);
+        assert_eq!(
+            URect::from_tuples((2, 2), (8, 8)).split_at_x(20).1,
+            URect::from_tuples((8, 2), (8, 8))
+        );
+    }
+
+    #[test]
+    pub fn test_relation() {
+        let rect = Rect::from_tuples((0.0, 0.0), (10.0, 10.0));
+        let inner = Rect::from_tuples((0.0, 2.0), (10.0, 8.0));
+        assert!(rect.contains_rect(&inner));
+        assert!(!inner.contains_rect(&rect));
+        assert_eq!(rect.relation(&rect), RectRelation::Equal);
+        assert_eq!(rect.relation(&inner), RectRelation::Contains);
+        assert_eq!(inner.relation(&rect), RectRelation::Within);
+        assert_eq!(
+            rect.relation(&Rect::from_tuples((5.0, 5.0), (15.0, 15.0))),
+            RectRelation::Overlaps
+        );
+        assert_eq!(
+            rect.relation(&Rect::from_tuples((10.0, 0.0), (15.0, 15.0))),
+            RectRelation::Disjoint
+        );
+        assert_eq!(
+            IRect::from_tuples((0, 0), (4, 4)).relation(&IRect::from_tuples((1, 1), (2, 2))),
+            RectRelation::Contains
+        );
+    }
+
+    #[test]
+    pub fn test_flips() {
+        let rect = Rect::from_tuples((1.0, 2.0), (4.0, 3.0));
+        assert_eq!(
+            rect.flip_horizontal(5.0),
+            Rect::from_tuples((6.0, 2.0), (9.0, 3.0))
+        );
+        assert_eq!(
+            rect.flip_vertical(0.0),
+            Rect::from_tuples((1.0, -3.0), (4.0, -2.0))
+        );
+        assert_eq!(
+            rect.mirror_around(Vec2::ZERO),
+            rect.flip_horizontal(0.0).flip_vertical(0.0)
+        );
+        assert_eq!(rect.flip_horizontal(2.0).flip_horizontal(2.0), rect);
+    }
+
+    #[test]
+    pub fn test_resize_with_anchor() {
+        let rect = Rect::from_tuples((0.0, 0.0), (4.0, 2.0));
+        assert_eq!(
+            rect.resize_with_anchor(Vec2::new(6.0, 4.0), Anchor::BottomRight),
+            Rect::from_tuples((-2.0, -2.0), (4.0, 2.0))
+        );
+        assert_eq!(
+            rect.resize_with_anchor(Vec2::new(2.0, -1.0), Anchor::Center),
+            Rect::from_tuples((1.0, 1.0), (3.0, 1.0))
+        );
+        assert_eq!(
+            rect.transpose(Anchor::Center),
+            Rect::from_tuples((1.0, -1.0), (3.0, 3.0))
+        );
+        assert_eq!(rect.transpose(Anchor::TopLeft).size(), Vec2::new(2.0, 4.0));
+    }
+
+    #[test]
+    pub fn test_corners() {
+        let mut rect = Rect::from_tuples((0.0, 0.0), (4.0, 2.0));
+        for corner in Corner::ALL {
+            assert_eq!(rect.corners()[corner.index()], rect.corner(corner));
+        }
+        rect.set_corner(Corner::TopRight, Vec2::new(6.0, -1.0));
+        assert_eq!(rect, Rect::from_tuples((0.0, -1.0), (6.0, 2.0)));
+        rect.set_corner(Corner::BottomLeft, Vec2::new(8.0, 3.0));
+        assert_eq!(rect, Rect::from_tuples((6.0, -1.0), (8.0, 3.0)));
+
+        let mut corners = IRect::from_tuples((0, 0), (1, 1)).corners();
+        corners[Corner::BottomLeft.index()].x = -1;
+        assert_eq!(corners[3], IVec2::new(-1, 1));
+    }
+
+    #[test]
+    pub fn test_union_area() {
+        let rects = [
+            Rect::from_tuples((0.0, 0.0), (4.0, 4.0)),
+            Rect::from_tuples((2.0, 2.0), (6.0, 6.0)),
+            Rect::from_tuples((1.0, 1.0), (3.0, 3.0)),
+            Rect::from_tuples((10.0, 0.0), (11.0, 1.0)),
+            Rect::from_tuples((3.0, 0.0), (3.0, 9.0)),
+        ];
+        assert_eq!(Rect::union_area(&rects), 29.0);
+        assert_eq!(Rect::union_area(&[]), 0.0);
+
+        // Compare against counting cells on a small grid.
+        let irects: Vec<IRect> = (0..20)
+            .map(|i| {
+                let x = (i * 7) % 13;
+                let y = (i * 5) % 11;
+                IRect::from_tuples((x, y), (x + 1 + i % 4, y + 1 + i % 3))
+            })
+            .collect();
+        let covered = IRect::from_tuples((0, 0), (20, 20))
+            .points()
+            .filter(|&point| irects.iter().any(|rect| rect.contains(point)))
+            .count();
+        assert_eq!(IRect::union_area(&irects), covered as i64);
+    }
+
+    #[test]
+    pub fn test_contains_mode() {
+        let rect = Rect::from_tuples((0.0, 0.0), (2.0, 2.0));
+        let corners = rect.corners();
+        assert!(corners
+            .iter()
+            .all(|&corner| rect.contains_inclusive(corner)));
+        let half_open: Vec<bool> = corners
+            .iter()
+            .map(|&corner| rect.contains_with(corner, ContainsMode::HalfOpen))
+            .collect();
+        assert_eq!(half_open, [true, false, false, false]);
+        assert!(!rect.contains_with(Vec2::new(1.0, 0.0), ContainsMode::Open));
+        assert!(rect.contains_with(Vec2::new(1.0, 1.0), ContainsMode::Open));
+
+        let u = URect::from_tuples((0, 0), (2, 2));
+        assert!(u.contains_with(UVec2::new(2, 2), ContainsMode::Closed));
+        assert!(!u.contains_with(UVec2::new(2, 2), ContainsMode::default()));
+    }
+
+    #[test]
+    pub fn test_nan_rects() {
+        let rect = Rect::from_tuples((0.0, 0.0), (4.0, 4.0));
+        let nan = Rect {
+            top_left: Vec2::new(f32::NAN, 1.0),
+            bottom_right: Vec2::new(2.0, 2.0),
+        };
+        let infinite = Rect {
+            top_left: Vec2::ZERO,
+            bottom_right: Vec2::new(f32::INFINITY, 1.0),
+        };
+        assert!(rect.is_finite() && !rect.is_nan());
+        assert!(!nan.is_finite() && nan.is_nan());
+        assert!(!infinite.is_finite() && !infinite.is_nan());
+        assert_eq!(rect.intersect(&nan), None);
+        assert_eq!(nan.intersect(&rect), None);
+        assert_eq!(rect.union(&nan), rect);
+    }
+
+    #[test]
+    pub fn test_frame() {
+        let rect = Rect::from_tuples((0.0, 0.0), (10.0, 6.0));
+        assert_eq!(
+            rect.interior(1.0),
+            Rect::from_tuples((1.0, 1.0), (9.0, 5.0))
+        );
+        assert_eq!(
+            rect.frame(1.0),
+            [
+                Rect::from_tuples((0.0, 0.0), (10.0, 1.0)),
+                Rect::from_tuples((0.0, 5.0), (10.0, 6.0)),
+                Rect::from_tuples((0.0, 1.0), (1.0, 5.0)),
+                Rect::from_tuples((9.0, 1.0), (10.0, 5.0)),
+            ]
+        );
+        let frame = rect.frame(Insets::new(2.0, 4.0, 0.0, 4.0));
+        let area: f32 = frame.iter().map(Rect::area).sum();
+        let interior = rect.interior(Insets::new(2.0, 4.0, 0.0, 4.0));
+        assert_eq!(area + interior.area(), rect.area());
+        assert_eq!(rect.interior(-1.0), rect);
+    }
+
+    #[test]
+    pub fn test_perimeter_points() {
+        let points: Vec<_> = IRect::from_tuples((0, 0), (3, 3))
+            .perimeter_points()
+            .collect();
+        assert_eq!(
+            points,
+            [
+                (0, 0),
+                (1, 0),
+                (2, 0),
+                (2, 1),
+                (2, 2),
+                (1, 2),
+                (0, 2),
+                (0, 1)
+            ]
+            .map(|(x, y)| IVec2::new(x, y))
+        );
+        for (w, h) in [(1, 1), (1, 4), (4, 1), (2, 2), (5, 3)] {
+            let rect = URect::from_tuples((2, 3), (2 + w, 3 + h));
+            let mut outline: Vec<_> = rect.perimeter_points().collect();
+            let count = outline.len();
+            outline.sort_by_key(|point| (point.y, point.x));
+            outline.dedup();
+            assert_eq!(outline.len(), count);
+            let expected: Vec<_> = rect
+                .points()
+                .filter(|point| {
+                    point.x == 2 || point.y == 3 || point.x == 1 + w || point.y == 2 + h
+                })
+                .collect();
+            assert_eq!(outline, expected);
+        }
+        assert_eq!(
+            URect::from_tuples((0, 0), (0, 5))
+                .perimeter_points()
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    pub fn test_relative_rects() {
+        let window = Rect::from_tuples((0.0, 0.0), (800.0, 600.0));
+        let viewport = Rect::from_tuples((400.0, 150.0), (800.0, 600.0));
+        let relative = viewport.to_relative(&window);
+        assert_eq!(relative, Rect::from_tuples((0.5, 0.25), (1.0, 1.0)));
+        assert_eq!(relative.resolve_relative(&window), viewport);
+
+        let resized = Rect::from_tuples((100.0, 100.0), (500.0, 300.0));
+        assert_eq!(
+            relative.resolve_relative(&resized),
+            Rect::from_tuples((300.0, 150.0), (500.0, 300.0))
+        );
+    }
+
+    #[test]
+    pub fn test_y_up() {
+        let rect = Rect::from_min_max(Vec2::new(1.0, 2.0), Vec2::new(3.0, 5.0));
+        assert_eq!((rect.min(), rect.max()), (rect.top_left, rect.bottom_right));
+        let y_up = rect.flip_y(10.0);
+        assert_eq!(y_up, Rect::from_tuples((1.0, 5.0), (3.0, 8.0)));
+        assert_eq!(y_up.flip_y(10.0), rect);
+        assert_eq!(
+            IRect::from_min_max(IVec2::ZERO, IVec2::ONE).max(),
+            IVec2::ONE
+        );
+    }
+
+    #[test]
+    pub fn test_signed_unsigned_conversions() {
+        let i = IRect::from_tuples((1, 2), (3, 4));
+        let u = URect::from_tuples((1, 2), (3, 4));
+        assert_eq!(URect::try_from(i), Ok(u));
+        assert_eq!(IRect::try_from(u), Ok(i));
+        assert_eq!(
+            URect::try_from(IRect::from_tuples((-1, 0), (1, 1))),
+            Err(RectConversionError::Negative)
+        );
+        assert_eq!(
+            IRect::try_from(URect::from_tuples((0, 0), (1, i32::MAX as u32 + 1))),
+            Err(RectConversionError::TooLarge)
+        );
+    }
+
+    #[test]
+    pub fn test_pixel_coverage() {
+        let rect = Rect::from_tuples((0.5, 0.0), (2.0, 0.25));
+        let coverage: Vec<_> = rect.pixel_coverage().collect();
+        assert_eq!(
+            coverage,
+            [(IVec2::new(0, 0), 0.125), (IVec2::new(1, 0), 0.25)]
+        );
+
+        let rect = Rect::from_tuples((-1.25, 2.5), (3.75, 6.0));
+        let total: f32 = rect.pixel_coverage().map(|(_, coverage)| coverage).sum();
+        assert_eq!(total, rect.area());
+        assert_eq!(rect.pixel_coverage().count(), 6 * 4);
+        assert_eq!(
+            Rect::from_tuples((1.5, 1.0), (1.5, 3.0))
+                .pixel_coverage()
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    pub fn test_le_bytes() {
+        let rect = Rect::from_tuples((1.0, -2.0), (3.5, 4.0));
+        let bytes = rect.to_le_bytes();
+        assert_eq!(bytes[..4], 1.0f32.to_le_bytes());
+        assert_eq!(bytes[12..], 4.0f32.to_le_bytes());
+        assert_eq!(Rect::from_le_bytes(bytes), rect);
+
+        let u = URect::from_tuples((1, 2), (0x0304_0506, 7));
+        let bytes = u.to_le_bytes();
+        assert_eq!(bytes[8..12], [6, 5, 4, 3]);
+        assert_eq!(URect::from_le_bytes(bytes), u);
+
+        let d = DRect::from_tuples((0.25, 0.5), (1.0, 2.0));
+        assert_eq!(DRect::from_le_bytes(d.to_le_bytes()), d);
+    }
+
+    #[test]
+    pub fn test_const_fns() {
+        const ATLAS: [Rect; 2] = [
+            Rect::from_raw(0.0, 0.0, 16.0, 16.0),
+            Rect::from_position_size(Vec2::new(16.0, 0.0), Vec2::new(32.0, 16.0)),
+        ];
+        const WIDTH: f32 = ATLAS[1].width();
+        const INSIDE: [bool; 2] = [
+            ATLAS[0].contains(Vec2::new(8.0, 8.0)),
+            ATLAS[0].contains(Vec2::new(16.0, 8.0)),
+        ];
+        const TILE: URect = URect::from_tuples((0, 0), (8, 8));
+        const TILE_SIZE: UVec2 = TILE.size();
+        assert_eq!(WIDTH, 32.0);
+        assert_eq!(INSIDE, [true, false]);
+        assert_eq!(TILE_SIZE, UVec2::splat(8));
+        assert_eq!(ATLAS[0], Rect::from_tuples((0.0, 0.0), (16.0, 16.0)));
+    }
+
+    #[test]
+    pub fn test_adjacency() {
+        let rect = Rect::from_tuples((0.0, 0.0), (10.0, 10.0));
+        let right = Rect::from_tuples((10.0001, 5.0), (20.0, 15.0));
+        assert_eq!(rect.is_adjacent(&right, 0.001), Some(Edge::Right));
+        assert_eq!(right.is_adjacent(&rect, 0.001), Some(Edge::Left));
+        assert_eq!(rect.is_adjacent(&right, 0.0), None);
+
+        let above = Rect::from_tuples((2.0, -4.0), (4.0, 0.0));
+        assert_eq!(rect.is_adjacent(&above, 0.0), Some(Edge::Top));
+        assert_eq!(above.is_adjacent(&rect, 0.0), Some(Edge::Bottom));
+
+        let diagonal = Rect::from_tuples((10.0, 10.0), (12.0, 12.0));
+        assert_eq!(rect.is_adjacent(&diagonal, 0.001), None);
+        assert!(rect.shares_corner(&diagonal, 0.0));
+        assert!(!rect.shares_corner(&above, 0.5));
+        assert_eq!(
+            rect.is_adjacent(&Rect::from_tuples((5.0, 5.0), (15.0, 6.0)), 0.1),
+            None
+        );
     }
 }
@@ -0,0 +1,107 @@
+use std::collections::BTreeMap;
+
+use crate::Rect;
+
+/// A set of rects drawn together as one impostor.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ImpostorGroup {
+    /// The union of the members' rects.
+    pub bounds: Rect,
+    /// Indices of the member rects in the input slice, in ascending order.
+    pub members: Vec<usize>,
+}
+
+/// Groups the rects visible through `camera` for level-of-detail rendering.
+///
+/// A rect whose longest side, multiplied by `zoom`, is smaller than
+/// `min_screen_size` screen units is merged with the other small rects whose
+/// top left vertex falls in the same world-space cell. Cells are
+/// `min_screen_size / zoom` world units wide and aligned to the world origin,
+/// so groups stay stable as the camera pans. Larger rects each get a group of
+/// their own. Rects that do not intersect the camera are skipped.
+///
+/// Groups of small rects come first, ordered by cell, followed by the large
+/// rects in input order.
+pub fn group_impostors(
+    rects: &[Rect],
+    camera: &Rect,
+    zoom: f32,
+    min_screen_size: f32,
+) -> Vec<ImpostorGroup> {
+    let cell_size = min_screen_size / zoom;
+    let mut cells: BTreeMap<(i64, i64), ImpostorGroup> = BTreeMap::new();
+    let mut large = Vec::new();
+
+    for (index, rect) in rects.iter().enumerate() {
+        if !rect.intersects(camera) {
+            continue;
+        }
+        if rect.longest_side() * zoom >= min_screen_size {
+            large.push(ImpostorGroup {
+                bounds: *rect,
+                members: vec![index],
+            });
+            continue;
+        }
+        let cell = (rect.top_left / cell_size).floor();
+        cells
+            .entry((cell.x as i64, cell.y as i64))
+            .and_modify(|group| {
+                group.bounds = group.bounds.union(rect);
+                group.members.push(index);
+            })
+            .or_insert_with(|| ImpostorGroup {
+                bounds: *rect,
+                members: vec![index],
+            });
+    }
+
+    cells.into_values().chain(large).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::Vec2;
+
+    use super::*;
+
+    fn square(x: f32, y: f32, size: f32) -> Rect {
+        Rect::from_position_size(Vec2::new(x, y), Vec2::splat(size))
+    }
+
+    #[test]
+    pub fn test_group_impostors() {
+        let rects = [
+            square(1.0, 1.0, 1.0),
+            square(5.0, 2.0, 1.0),
+            square(12.0, 1.0, 1.0),
+            square(0.0, 0.0, 50.0),
+            square(500.0, 500.0, 1.0),
+        ];
+        let camera = Rect::from_tuples((0.0, 0.0), (100.0, 100.0));
+
+        // At zoom 0.5 a 1 unit rect is half a pixel on screen; cells are
+        // 10 world units wide.
+        let groups = group_impostors(&rects, &camera, 0.5, 5.0);
+        assert_eq!(
+            groups,
+            vec![
+                ImpostorGroup {
+                    bounds: Rect::from_tuples((1.0, 1.0), (6.0, 3.0)),
+                    members: vec![0, 1],
+                },
+                ImpostorGroup {
+                    bounds: square(12.0, 1.0, 1.0),
+                    members: vec![2],
+                },
+                ImpostorGroup {
+                    bounds: square(0.0, 0.0, 50.0),
+                    members: vec![3],
+                },
+            ]
+        );
+
+        // Zoomed in, every rect is large enough to draw on its own.
+        assert_eq!(group_impostors(&rects, &camera, 10.0, 5.0).len(), 4);
+    }
+}
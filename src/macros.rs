@@ -0,0 +1,2228 @@
+//! Macros generating the methods shared by the rect types, so that every
+//! scalar variant gets the same implementation.
+
+/// Implements the methods that are identical for every rect type.
+macro_rules! impl_rect_common {
+    ($R:ident, $V:ident, $V4:ident, $S:ty) => {
+        impl $R {
+            #[doc = concat!(" Constructs a new `", stringify!($R), "`. The top left vertex must")]
+            /// be above and to the left of the bottom right vertex.
+            #[inline]
+            #[track_caller]
+            pub const fn new(top_left: $V, bottom_right: $V) -> Self {
+                let rect = Self {
+                    top_left,
+                    bottom_right,
+                };
+                rect.strict_check();
+                rect
+            }
+
+            #[inline]
+            pub const fn corners(self) -> [$V; 4] {
+                let top_right = $V::new(self.bottom_right.x, self.top_left.y);
+                let bottom_left = $V::new(self.top_left.x, self.bottom_right.y);
+                [self.top_left, top_right, self.bottom_right, bottom_left]
+            }
+
+            #[inline]
+            pub const fn top_right(&self) -> $V {
+                $V::new(self.bottom_right.x, self.top_left.y)
+            }
+
+            #[inline]
+            pub const fn bottom_left(&self) -> $V {
+                $V::new(self.top_left.x, self.bottom_right.y)
+            }
+
+            /// Returns the corner with the smallest coordinates. This is the top left
+            /// corner with y growing downward, and the bottom left one in Y-up
+            /// conventions such as OpenGL's.
+            #[inline]
+            pub const fn min(&self) -> $V {
+                self.top_left
+            }
+
+            /// Returns the corner with the largest coordinates. This is the bottom
+            /// right corner with y growing downward, and the top right one in Y-up
+            /// conventions.
+            #[inline]
+            pub const fn max(&self) -> $V {
+                self.bottom_right
+            }
+
+            #[doc = concat!(" Constructs a new `", stringify!($R), "` from its minimum and maximum")]
+            /// corners, which reads naturally regardless of which way y grows.
+            #[inline]
+            #[track_caller]
+            pub const fn from_min_max(min: $V, max: $V) -> Self {
+                Self::new(min, max)
+            }
+
+            /// Returns the x coordinate of the left edge.
+            #[inline]
+            pub const fn left(&self) -> $S {
+                self.top_left.x
+            }
+
+            /// Returns the y coordinate of the top edge.
+            #[inline]
+            pub const fn top(&self) -> $S {
+                self.top_left.y
+            }
+
+            /// Returns the x coordinate of the right edge.
+            #[inline]
+            pub const fn right(&self) -> $S {
+                self.bottom_right.x
+            }
+
+            /// Returns the y coordinate of the bottom edge.
+            #[inline]
+            pub const fn bottom(&self) -> $S {
+                self.bottom_right.y
+            }
+
+            /// Returns the coordinate of `edge`: x for the left and right edges and
+            /// y for the top and bottom ones.
+            #[inline]
+            pub const fn edge(&self, edge: $crate::Edge) -> $S {
+                match edge {
+                    $crate::Edge::Left => self.left(),
+                    $crate::Edge::Top => self.top(),
+                    $crate::Edge::Right => self.right(),
+                    $crate::Edge::Bottom => self.bottom(),
+                }
+            }
+
+            /// Returns the endpoints of `edge`. Following the edges in the order of
+            /// [`corners`](Self::corners), they run clockwise on screen: the top edge
+            /// from left to right, the right edge from top to bottom, the bottom edge
+            /// from right to left, and the left edge from bottom to top.
+            #[inline]
+            pub const fn edge_segment(&self, edge: $crate::Edge) -> ($V, $V) {
+                match edge {
+                    $crate::Edge::Left => (self.bottom_left(), self.top_left),
+                    $crate::Edge::Top => (self.top_left, self.top_right()),
+                    $crate::Edge::Right => (self.top_right(), self.bottom_right),
+                    $crate::Edge::Bottom => (self.bottom_right, self.bottom_left()),
+                }
+            }
+
+            /// Returns the position of `corner`.
+            #[inline]
+            pub const fn corner(&self, corner: $crate::Corner) -> $V {
+                match corner {
+                    $crate::Corner::TopLeft => self.top_left,
+                    $crate::Corner::TopRight => self.top_right(),
+                    $crate::Corner::BottomRight => self.bottom_right,
+                    $crate::Corner::BottomLeft => self.bottom_left(),
+                }
+            }
+
+            /// Moves `corner` to `position`, keeping the opposite corner in place. If
+            /// the corner is moved past the opposite one, the rectangle is
+            /// normalized again, so the moved point may become a different corner.
+            #[inline]
+            pub fn set_corner(&mut self, corner: $crate::Corner, position: $V) {
+                let opposite = self.corner(corner.opposite());
+                *self = Self::new(position.min(opposite), position.max(opposite));
+            }
+
+            #[doc = concat!(" Constructs a new `", stringify!($R), "` from the coordinates of its")]
+            /// top left and bottom right vertices, for defining rects in `const`
+            /// tables such as atlas regions.
+            #[inline]
+            #[track_caller]
+            pub const fn from_raw(left: $S, top: $S, right: $S, bottom: $S) -> Self {
+                Self::new($V::new(left, top), $V::new(right, bottom))
+            }
+
+            #[doc = concat!(" Constructs a new `", stringify!($R), "`. The top left vertex must")]
+            /// be above and to the left of the bottom right vertex.
+            #[inline]
+            #[track_caller]
+            pub const fn from_tuples(top_left: ($S, $S), bottom_right: ($S, $S)) -> Self {
+                Self::new(
+                    $V::new(top_left.0, top_left.1),
+                    $V::new(bottom_right.0, bottom_right.1),
+                )
+            }
+
+            #[doc = concat!(" Constructs a new `", stringify!($R), "` spanning the `x` and `y`")]
+            /// ranges, which must not be reversed.
+            #[inline]
+            #[track_caller]
+            pub fn from_ranges(x: core::ops::Range<$S>, y: core::ops::Range<$S>) -> Self {
+                Self::new($V::new(x.start, y.start), $V::new(x.end, y.end))
+            }
+
+            /// Returns the range of `x` coordinates covered by the rectangle,
+            /// suitable for indexing a row of pixels.
+            #[inline]
+            pub fn x_range(&self) -> core::ops::Range<$S> {
+                self.top_left.x..self.bottom_right.x
+            }
+
+            /// Returns the range of `y` coordinates covered by the rectangle.
+            #[inline]
+            pub fn y_range(&self) -> core::ops::Range<$S> {
+                self.top_left.y..self.bottom_right.y
+            }
+
+            /// Returns the width of the rectangle.
+            #[inline]
+            pub const fn width(&self) -> $S {
+                self.bottom_right.x - self.top_left.x
+            }
+
+            /// Returns the height of the rectangle.
+            #[inline]
+            pub const fn height(&self) -> $S {
+                self.bottom_right.y - self.top_left.y
+            }
+
+            /// Returns a `Vector2` containing the width and height of the rectangle.
+            #[inline]
+            pub const fn size(&self) -> $V {
+                $V::new(self.width(), self.height())
+            }
+
+            /// Returns true if the specified point is inside this rectangle. This is
+            /// inclusive of the top and left coordinates, and exclusive of the bottom
+            /// and right coordinates.
+            #[inline]
+            #[must_use]
+            pub const fn contains(&self, point: $V) -> bool {
+                point.x >= self.top_left.x
+                    && point.y >= self.top_left.y
+                    && point.x < self.bottom_right.x
+                    && point.y < self.bottom_right.y
+            }
+
+            /// Returns true if the specified point is inside this rectangle or on
+            /// any of its edges.
+            #[inline]
+            #[must_use]
+            pub const fn contains_inclusive(&self, point: $V) -> bool {
+                point.x >= self.top_left.x
+                    && point.y >= self.top_left.y
+                    && point.x <= self.bottom_right.x
+                    && point.y <= self.bottom_right.y
+            }
+
+            /// Returns true if the specified point is inside this rectangle, with
+            /// the edges counted as inside or outside according to `mode`.
+            #[inline]
+            #[must_use]
+            pub fn contains_with(&self, point: $V, mode: $crate::ContainsMode) -> bool {
+                match mode {
+                    $crate::ContainsMode::HalfOpen => self.contains(point),
+                    $crate::ContainsMode::Closed => self.contains_inclusive(point),
+                    $crate::ContainsMode::Open => {
+                        point.x > self.top_left.x
+                            && point.y > self.top_left.y
+                            && point.x < self.bottom_right.x
+                            && point.y < self.bottom_right.y
+                    }
+                }
+            }
+
+            /// Finds the intersection of two rectangles -- in other words, the area
+            /// that is common to both of them.
+            ///
+            /// If there is no common area between the two rectangles, then this
+            /// function will return `None`. For float rects, this includes rects with
+            /// NaN coordinates.
+            #[inline]
+            #[must_use]
+            pub fn intersect(&self, other: &Self) -> Option<Self> {
+                let result = Self {
+                    top_left: $V::new(
+                        self.top_left.x.max(other.top_left.x),
+                        self.top_left.y.max(other.top_left.y),
+                    ),
+                    bottom_right: $V::new(
+                        self.bottom_right.x.min(other.bottom_right.x),
+                        self.bottom_right.y.min(other.bottom_right.y),
+                    ),
+                };
+
+                // Checking `intersects` as well rejects NaN coordinates, which the
+                // float `min` and `max` would otherwise silently ignore.
+                if self.intersects(other) && result.is_positive_area() {
+                    Some(result)
+                } else {
+                    None
+                }
+            }
+
+            /// A constant representing a rectangle with position (0, 0) and zero area.
+            /// Each component is set to zero.
+            pub const ZERO: $R = $R::new($V::ZERO, $V::ZERO);
+
+            /// Returns `true` if the rectangle has zero area.
+            #[inline]
+            pub const fn is_zero_area(&self) -> bool {
+                self.top_left.x == self.bottom_right.x || self.top_left.y == self.bottom_right.y
+            }
+
+            /// Returns `true` if the rectangle has an area greater than zero.
+            #[inline]
+            pub const fn is_positive_area(&self) -> bool {
+                self.top_left.x < self.bottom_right.x && self.top_left.y < self.bottom_right.y
+            }
+
+            /// Returns a new rectangle, whose vertices are offset relative to the
+            /// current rectangle by the specified amount. This is equivalent to
+            /// adding the specified vector to each vertex.
+            ///
+            /// For integer rects, overflow panics in debug builds and wraps in
+            /// release builds; use `checked_offset` or `saturating_offset` to
+            /// handle it.
+            #[inline]
+            #[track_caller]
+            pub fn with_offset(&self, offset: impl Into<$V>) -> Self {
+                let offset = offset.into();
+                Self::new(self.top_left + offset, self.bottom_right + offset)
+            }
+
+            /// Returns a new rectangle, whose vertices are negatively offset relative
+            /// to the current rectangle by the specified amount. This is equivalent
+            /// to subtracting the specified vector to each vertex.
+            ///
+            /// For integer rects, overflow panics in debug builds and wraps in
+            /// release builds, e.g. when a `URect` would cross zero; use
+            /// `checked_negative_offset` or `saturating_negative_offset` to handle it.
+            #[inline]
+            #[track_caller]
+            pub fn with_negative_offset(&self, offset: impl Into<$V>) -> Self {
+                let offset = offset.into();
+                Self::new(self.top_left - offset, self.bottom_right - offset)
+            }
+
+            /// Returns the smallest rectangle containing both this rectangle and
+            /// `other`.
+            ///
+            /// For float rects, a NaN coordinate is ignored in favor of the matching
+            /// coordinate of the other rectangle, like in `f32::min` and `f32::max`,
+            /// so check `is_nan` first to reject such input.
+            #[inline]
+            #[must_use]
+            pub fn union(&self, other: &Self) -> Self {
+                Self::new(
+                    self.top_left.min(other.top_left),
+                    self.bottom_right.max(other.bottom_right),
+                )
+            }
+
+            /// Grows this rectangle in place to contain `other`, the mutating form
+            #[doc = concat!(" of [`", stringify!($R), "::union`].")]
+            #[inline]
+            pub fn expand_to_include_rect(&mut self, other: &Self) {
+                self.top_left = self.top_left.min(other.top_left);
+                self.bottom_right = self.bottom_right.max(other.bottom_right);
+            }
+
+            /// Moves this rectangle in place by `offset`, the mutating form of
+            #[doc = concat!(" [`", stringify!($R), "::with_offset`].")]
+            #[inline]
+            #[track_caller]
+            pub fn translate_mut(&mut self, offset: impl Into<$V>) {
+                *self = self.with_offset(offset);
+            }
+
+            /// Returns the larger of the width and the height.
+            #[inline]
+            pub fn longest_side(&self) -> $S {
+                self.width().max(self.height())
+            }
+
+            /// Returns the smaller of the width and the height.
+            #[inline]
+            pub fn shortest_side(&self) -> $S {
+                self.width().min(self.height())
+            }
+
+            /// Returns `true` if the two rectangles have a common area. This is the
+            /// same as `self.intersect(other).is_some()`, but cheaper.
+            #[inline]
+            #[must_use]
+            pub const fn intersects(&self, other: &Self) -> bool {
+                self.top_left.x < other.bottom_right.x
+                    && other.top_left.x < self.bottom_right.x
+                    && self.top_left.y < other.bottom_right.y
+                    && other.top_left.y < self.bottom_right.y
+            }
+
+            /// Returns `true` if `other` lies entirely inside this rectangle, edges
+            /// included.
+            #[inline]
+            #[must_use]
+            pub const fn contains_rect(&self, other: &Self) -> bool {
+                self.top_left.x <= other.top_left.x
+                    && self.top_left.y <= other.top_left.y
+                    && other.bottom_right.x <= self.bottom_right.x
+                    && other.bottom_right.y <= self.bottom_right.y
+            }
+
+            /// Classifies how `other` is positioned relative to this rectangle, for
+            /// example to skip clipping for objects fully inside the view.
+            #[inline]
+            #[must_use]
+            pub fn relation(&self, other: &Self) -> $crate::RectRelation {
+                if self == other {
+                    $crate::RectRelation::Equal
+                } else if self.contains_rect(other) {
+                    $crate::RectRelation::Contains
+                } else if other.contains_rect(self) {
+                    $crate::RectRelation::Within
+                } else if self.intersects(other) {
+                    $crate::RectRelation::Overlaps
+                } else {
+                    $crate::RectRelation::Disjoint
+                }
+            }
+
+            /// Returns `true` if the two rectangles share part of an edge or a corner
+            /// but have no common area.
+            #[inline]
+            #[must_use]
+            pub fn touches(&self, other: &Self) -> bool {
+                let closed_overlap = self.top_left.x <= other.bottom_right.x
+                    && other.top_left.x <= self.bottom_right.x
+                    && self.top_left.y <= other.bottom_right.y
+                    && other.top_left.y <= self.bottom_right.y;
+                closed_overlap && !self.intersects(other)
+            }
+
+            #[doc = concat!(" Packs the rectangle into a `", stringify!($V4), "` laid out as")]
+            /// `(top_left.x, top_left.y, bottom_right.x, bottom_right.y)`.
+            #[inline]
+            pub fn to_vec4(&self) -> $V4 {
+                $V4::new(
+                    self.top_left.x,
+                    self.top_left.y,
+                    self.bottom_right.x,
+                    self.bottom_right.y,
+                )
+            }
+
+            #[doc = concat!(" Unpacks a rectangle from a `", stringify!($V4), "` laid out as in")]
+            #[doc = concat!(" [`", stringify!($R), "::to_vec4`].")]
+            #[inline]
+            pub fn from_vec4(v: $V4) -> Self {
+                Self::new($V::new(v.x, v.y), $V::new(v.z, v.w))
+            }
+
+            /// Encodes the rectangle as the little-endian bytes of `top_left.x`,
+            /// `top_left.y`, `bottom_right.x` and `bottom_right.y`, in that order. This
+            /// layout is stable, so the bytes can be stored or sent over the network.
+            #[inline]
+            pub fn to_le_bytes(&self) -> [u8; 4 * core::mem::size_of::<$S>()] {
+                const SIZE: usize = core::mem::size_of::<$S>();
+                let mut bytes = [0; 4 * SIZE];
+                let coordinates = [
+                    self.top_left.x,
+                    self.top_left.y,
+                    self.bottom_right.x,
+                    self.bottom_right.y,
+                ];
+                for (chunk, coordinate) in bytes.chunks_exact_mut(SIZE).zip(coordinates) {
+                    chunk.copy_from_slice(&coordinate.to_le_bytes());
+                }
+                bytes
+            }
+
+            /// Decodes a rectangle from the bytes written by
+            #[doc = concat!(" [`", stringify!($R), "::to_le_bytes`].")]
+            #[inline]
+            #[track_caller]
+            pub fn from_le_bytes(bytes: [u8; 4 * core::mem::size_of::<$S>()]) -> Self {
+                const SIZE: usize = core::mem::size_of::<$S>();
+                let coordinate = |i: usize| {
+                    <$S>::from_le_bytes(bytes[i * SIZE..(i + 1) * SIZE].try_into().unwrap())
+                };
+                Self::new(
+                    $V::new(coordinate(0), coordinate(1)),
+                    $V::new(coordinate(2), coordinate(3)),
+                )
+            }
+
+            #[doc = concat!(" Packs the rectangle into a `", stringify!($V4), "` laid out as")]
+            /// `(top_left.x, top_left.y, width, height)`.
+            #[inline]
+            pub fn to_xywh_vec4(&self) -> $V4 {
+                $V4::new(
+                    self.top_left.x,
+                    self.top_left.y,
+                    self.width(),
+                    self.height(),
+                )
+            }
+
+            #[doc = concat!(" Unpacks a rectangle from a `", stringify!($V4), "` laid out as in")]
+            #[doc = concat!(" [`", stringify!($R), "::to_xywh_vec4`].")]
+            #[inline]
+            pub fn from_xywh_vec4(v: $V4) -> Self {
+                Self::from_position_size($V::new(v.x, v.y), $V::new(v.z, v.w))
+            }
+
+            #[doc = concat!(" Constructs a new `", stringify!($R), "` from its top left vertex")]
+            /// and its size.
+            #[inline]
+            pub const fn from_position_size(position: $V, size: $V) -> Self {
+                Self::new(
+                    position,
+                    $V::new(position.x + size.x, position.y + size.y),
+                )
+            }
+
+            /// Returns `true` if the top left vertex is above and to the left of, or
+            /// equal to, the bottom right vertex.
+            #[inline]
+            pub const fn is_normalized(&self) -> bool {
+                self.top_left.x <= self.bottom_right.x && self.top_left.y <= self.bottom_right.y
+            }
+
+            /// Returns a rectangle covering the same area with the coordinates of
+            /// each axis swapped if they are inverted.
+            #[inline]
+            #[must_use]
+            pub fn normalize(&self) -> Self {
+                Self::new(
+                    self.top_left.min(self.bottom_right),
+                    self.top_left.max(self.bottom_right),
+                )
+            }
+
+            /// Cuts the rectangle in two at `x`, which is clamped to the rectangle,
+            /// returning the left and right parts.
+            #[inline]
+            #[must_use]
+            pub fn split_at_x(&self, x: $S) -> (Self, Self) {
+                let x = x.max(self.top_left.x).min(self.bottom_right.x);
+                (
+                    Self::new(self.top_left, $V::new(x, self.bottom_right.y)),
+                    Self::new($V::new(x, self.top_left.y), self.bottom_right),
+                )
+            }
+
+            /// Cuts the rectangle in two at `y`, which is clamped to the rectangle,
+            /// returning the top and bottom parts.
+            #[inline]
+            #[must_use]
+            pub fn split_at_y(&self, y: $S) -> (Self, Self) {
+                let y = y.max(self.top_left.y).min(self.bottom_right.y);
+                (
+                    Self::new(self.top_left, $V::new(self.bottom_right.x, y)),
+                    Self::new($V::new(self.top_left.x, y), self.bottom_right),
+                )
+            }
+
+            /// Returns the parts of this rectangle not covered by `other`, as up
+            /// to four non-overlapping rectangles with positive area: a full
+            /// width band above `other`, one below it, and the parts to its left
+            /// and right in between.
+            #[must_use]
+            pub fn subtract(&self, other: &Self) -> $crate::ArrayVec<Self, 4> {
+                let mut parts = $crate::ArrayVec::new();
+                let Some(hole) = self.intersect(other) else {
+                    if self.is_positive_area() {
+                        parts.push(*self);
+                    }
+                    return parts;
+                };
+                let candidates = [
+                    Self::new(self.top_left, $V::new(self.bottom_right.x, hole.top_left.y)),
+                    Self::new(
+                        $V::new(self.top_left.x, hole.bottom_right.y),
+                        self.bottom_right,
+                    ),
+                    Self::new(
+                        $V::new(self.top_left.x, hole.top_left.y),
+                        $V::new(hole.top_left.x, hole.bottom_right.y),
+                    ),
+                    Self::new(
+                        $V::new(hole.bottom_right.x, hole.top_left.y),
+                        $V::new(self.bottom_right.x, hole.bottom_right.y),
+                    ),
+                ];
+                for part in candidates {
+                    if part.is_positive_area() {
+                        parts.push(part);
+                    }
+                }
+                parts
+            }
+        }
+
+        /// Uses the `[min_x, min_y, max_x, max_y]` layout of
+        #[doc = concat!(" [`", stringify!($R), "::to_vec4`]. For `[x, y, width, height]`, go through")]
+        #[doc = concat!(" [`", stringify!($R), "::from_xywh_vec4`] instead.")]
+        impl From<[$S; 4]> for $R {
+            #[inline]
+            fn from(array: [$S; 4]) -> Self {
+                Self::from_vec4($V4::from_array(array))
+            }
+        }
+
+        /// Uses the `[min_x, min_y, max_x, max_y]` layout of
+        #[doc = concat!(" [`", stringify!($R), "::to_vec4`]. For `[x, y, width, height]`, go through")]
+        #[doc = concat!(" [`", stringify!($R), "::to_xywh_vec4`] instead.")]
+        impl From<$R> for [$S; 4] {
+            #[inline]
+            fn from(rect: $R) -> Self {
+                rect.to_vec4().to_array()
+            }
+        }
+
+        #[doc = concat!(" Same as [`", stringify!($R), "::from_vec4`].")]
+        impl From<$V4> for $R {
+            #[inline]
+            fn from(v: $V4) -> Self {
+                Self::from_vec4(v)
+            }
+        }
+
+        #[doc = concat!(" Same as [`", stringify!($R), "::to_vec4`].")]
+        impl From<$R> for $V4 {
+            #[inline]
+            fn from(rect: $R) -> Self {
+                rect.to_vec4()
+            }
+        }
+
+        impl core::ops::Add<$V> for $R {
+            type Output = Self;
+
+            /// Translates the rectangle by `offset`.
+            #[inline]
+            #[track_caller]
+            fn add(self, offset: $V) -> Self {
+                self.with_offset(offset)
+            }
+        }
+
+        impl core::ops::Sub<$V> for $R {
+            type Output = Self;
+
+            /// Translates the rectangle by `-offset`.
+            #[inline]
+            #[track_caller]
+            fn sub(self, offset: $V) -> Self {
+                self.with_negative_offset(offset)
+            }
+        }
+
+        impl core::ops::Mul<$S> for $R {
+            type Output = Self;
+
+            /// Multiplies both vertices by `factor`, i.e. scales about the
+            /// origin. A negative factor mirrors the rectangle, which stays
+            /// normalized.
+            ///
+            /// For integer rects, overflow panics in debug builds and wraps in
+            /// release builds.
+            #[inline]
+            fn mul(self, factor: $S) -> Self {
+                let a = self.top_left * factor;
+                let b = self.bottom_right * factor;
+                Self::new(a.min(b), a.max(b))
+            }
+        }
+
+        impl core::ops::Div<$S> for $R {
+            type Output = Self;
+
+            /// Divides both vertices by `divisor`. A negative divisor mirrors
+            /// the rectangle, which stays normalized.
+            #[inline]
+            fn div(self, divisor: $S) -> Self {
+                let a = self.top_left / divisor;
+                let b = self.bottom_right / divisor;
+                Self::new(a.min(b), a.max(b))
+            }
+        }
+
+        impl core::ops::AddAssign<$V> for $R {
+            #[inline]
+            #[track_caller]
+            fn add_assign(&mut self, offset: $V) {
+                *self = *self + offset;
+            }
+        }
+
+        impl core::ops::SubAssign<$V> for $R {
+            #[inline]
+            #[track_caller]
+            fn sub_assign(&mut self, offset: $V) {
+                *self = *self - offset;
+            }
+        }
+
+        impl core::ops::MulAssign<$S> for $R {
+            #[inline]
+            fn mul_assign(&mut self, factor: $S) {
+                *self = *self * factor;
+            }
+        }
+
+        impl core::ops::DivAssign<$S> for $R {
+            #[inline]
+            fn div_assign(&mut self, divisor: $S) {
+                *self = *self / divisor;
+            }
+        }
+    };
+}
+
+/// Implements the methods of a floating point rect type.
+macro_rules! impl_float_rect {
+    ($R:ident, $V:ident, $V4:ident, $S:ty) => {
+        impl_rect_common!($R, $V, $V4, $S);
+
+        impl $R {
+            /// Returns the smallest rectangle containing both this rectangle and
+            /// `point`. Note that a point on the bottom or right edge is not
+            #[doc = concat!(" considered inside by [`", stringify!($R), "::contains`].")]
+            #[inline]
+            #[must_use]
+            pub fn union_point(&self, point: $V) -> Self {
+                Self::new(self.top_left.min(point), self.bottom_right.max(point))
+            }
+
+            /// Grows this rectangle in place to contain `point`, the mutating form of
+            #[doc = concat!(" [`", stringify!($R), "::union_point`] for accumulating bounds.")]
+            #[inline]
+            pub fn expand_to_include(&mut self, point: $V) {
+                self.top_left = self.top_left.min(point);
+                self.bottom_right = self.bottom_right.max(point);
+            }
+
+            /// Returns the bounding box of the given points, or `None` if there are
+            /// no points. The maximum point lies on the bottom right vertex, so it is
+            #[doc = concat!(" not considered inside by [`", stringify!($R), "::contains`].")]
+            #[inline]
+            pub fn from_points(points: impl IntoIterator<Item = $V>) -> Option<Self> {
+                let mut points = points.into_iter();
+                let first = points.next()?;
+                let (min, max) = points.fold((first, first), |(min, max), point| {
+                    (min.min(point), max.max(point))
+                });
+                Some(Self::new(min, max))
+            }
+
+            /// Returns the smallest rectangle containing this one whose width divided
+            /// by its height equals `ratio`. Only one dimension is grown, and the
+            /// `anchor` point of the rectangle stays in place.
+            ///
+            /// A rectangle with zero width and height is returned unchanged.
+            #[inline]
+            #[must_use]
+            pub fn expand_to_aspect(&self, ratio: $S, anchor: $crate::Anchor) -> Self {
+                let size = self.size();
+                if size == $V::ZERO {
+                    return *self;
+                }
+                let new_size = if size.x < size.y * ratio {
+                    $V::new(size.y * ratio, size.y)
+                } else {
+                    $V::new(size.x, size.x / ratio)
+                };
+                let fraction = anchor.fraction();
+                let fraction = $V::new(fraction.x as $S, fraction.y as $S);
+                let pivot = self.top_left + size * fraction;
+                let top_left = pivot - new_size * fraction;
+                Self::new(top_left, top_left + new_size)
+            }
+
+            /// Returns `true` if all coordinates are finite, i.e. neither infinite
+            /// nor NaN.
+            #[inline]
+            pub fn is_finite(&self) -> bool {
+                self.top_left.is_finite() && self.bottom_right.is_finite()
+            }
+
+            /// Returns `true` if any coordinate is NaN.
+            #[inline]
+            pub fn is_nan(&self) -> bool {
+                self.top_left.is_nan() || self.bottom_right.is_nan()
+            }
+
+            /// Returns the position of `anchor` on the rectangle.
+            #[inline]
+            pub fn point_at(&self, anchor: $crate::Anchor) -> $V {
+                let fraction = anchor.fraction();
+                self.top_left + self.size() * $V::new(fraction.x as $S, fraction.y as $S)
+            }
+
+            /// Returns the rectangle resized to `new_size`, keeping its `anchor` point
+            /// in place, as when dragging the edge or corner opposite to the anchor.
+            /// Negative sizes are clamped to zero.
+            #[inline]
+            #[must_use]
+            pub fn resize_with_anchor(&self, new_size: $V, anchor: $crate::Anchor) -> Self {
+                let fraction = anchor.fraction();
+                let fraction = $V::new(fraction.x as $S, fraction.y as $S);
+                let new_size = new_size.max($V::ZERO);
+                let top_left = self.point_at(anchor) - new_size * fraction;
+                Self::new(top_left, top_left + new_size)
+            }
+
+            /// Returns the rectangle with its width and height swapped, keeping its
+            /// `anchor` point in place.
+            #[inline]
+            #[must_use]
+            pub fn transpose(&self, anchor: $crate::Anchor) -> Self {
+                self.resize_with_anchor($V::new(self.height(), self.width()), anchor)
+            }
+
+            /// Maps `point` to coordinates relative to the rectangle, where the top
+            /// left vertex is (0, 0) and the bottom right vertex is (1, 1), as for
+            /// texture coordinates. Points outside the rectangle map outside that
+            /// range.
+            #[inline]
+            pub fn to_normalized(&self, point: $V) -> $V {
+                (point - self.top_left) / self.size()
+            }
+
+            /// Maps relative coordinates back to a point, the inverse of
+            /// [`to_normalized`](Self::to_normalized).
+            #[inline]
+            pub fn from_normalized(&self, uv: $V) -> $V {
+                self.top_left + uv * self.size()
+            }
+
+            /// Transfers `point` from this rectangle's space to `other`'s, so that
+            /// each vertex of this rectangle maps to the matching vertex of `other`.
+            #[inline]
+            pub fn remap(&self, other: &Self, point: $V) -> $V {
+                other.from_normalized(self.to_normalized(point))
+            }
+
+            /// Expresses this rectangle as fractions of `parent`, as in
+            /// [`to_normalized`](Self::to_normalized), so that it can be stored
+            /// independently of the parent's size, e.g. for a viewport covering the
+            /// right half of a window.
+            #[inline]
+            #[must_use]
+            pub fn to_relative(&self, parent: &Self) -> Self {
+                Self::new(
+                    parent.to_normalized(self.top_left),
+                    parent.to_normalized(self.bottom_right),
+                )
+            }
+
+            /// Resolves a rectangle given as fractions of `parent` to absolute
+            /// coordinates, the inverse of [`to_relative`](Self::to_relative).
+            #[inline]
+            #[must_use]
+            pub fn resolve_relative(&self, parent: &Self) -> Self {
+                Self::new(
+                    parent.from_normalized(self.top_left),
+                    parent.from_normalized(self.bottom_right),
+                )
+            }
+
+            /// Returns this rectangle moved to the `anchor` position inside
+            /// `container`, keeping its size. `margin` is kept between the
+            /// rectangle and the container edges it is aligned to, and has no
+            /// effect on centered axes.
+            #[inline]
+            #[must_use]
+            pub fn aligned_inside(
+                &self,
+                container: &Self,
+                anchor: $crate::Anchor,
+                margin: $V,
+            ) -> Self {
+                let fraction = anchor.fraction();
+                let fraction = $V::new(fraction.x as $S, fraction.y as $S);
+                let size = self.size();
+                let free = container.size() - size - margin * 2.0;
+                Self::from_position_size(container.top_left + margin + free * fraction, size)
+            }
+
+            /// Returns this rectangle scaled, keeping its aspect ratio, to the largest
+            /// size that fits inside `container`, and centered in it. This is CSS
+            /// `object-fit: contain`, leaving letterbox or pillarbox bars.
+            ///
+            /// A rectangle with zero width or height becomes a point at the center of
+            /// the container.
+            #[inline]
+            #[must_use]
+            pub fn fit_inside(&self, container: &Self) -> Self {
+                let ratios = container.size() / self.size();
+                self.scaled_to_center(container, ratios.min_element())
+            }
+
+            /// Returns this rectangle scaled, keeping its aspect ratio, to the
+            /// smallest size that covers `container`, and centered on it. This is
+            /// CSS `object-fit: cover`, cropping whatever extends past the container.
+            ///
+            /// A rectangle with zero width or height becomes a point at the center of
+            /// the container.
+            #[inline]
+            #[must_use]
+            pub fn fill(&self, container: &Self) -> Self {
+                let ratios = container.size() / self.size();
+                self.scaled_to_center(container, ratios.max_element())
+            }
+
+            fn scaled_to_center(&self, container: &Self, factor: $S) -> Self {
+                let size = if factor.is_finite() && self.is_positive_area() {
+                    self.size() * factor
+                } else {
+                    $V::ZERO
+                };
+                Self::from_center_size(container.center(), size)
+            }
+
+            /// Returns a new rectangle with each edge moved outwards by the given
+            /// amount. Negative amounts move the edge inwards.
+            #[inline]
+            #[must_use]
+            pub fn expand_edges(&self, left: $S, top: $S, right: $S, bottom: $S) -> Self {
+                Self::new(
+                    self.top_left - $V::new(left, top),
+                    self.bottom_right + $V::new(right, bottom),
+                )
+            }
+
+            /// Returns the length of the rectangle's diagonal.
+            #[inline]
+            pub fn diagonal_length(&self) -> $S {
+                self.size().length()
+            }
+
+            /// Returns `true` if the width and height differ by at most `epsilon`.
+            #[inline]
+            pub fn is_square(&self, epsilon: $S) -> bool {
+                (self.width() - self.height()).abs() <= epsilon
+            }
+
+            /// Returns the area of the rectangle.
+            #[inline]
+            pub fn area(&self) -> $S {
+                self.width() * self.height()
+            }
+
+            /// Returns the total area covered by `rects`, counting overlapping parts
+            /// only once. This takes `O(n log n)` time with a sweep line, unlike
+            /// adding up pairwise intersections.
+            pub fn union_area(rects: &[Self]) -> $S {
+                $crate::union_area::union_area(rects.iter().map(|r| {
+                    [
+                        r.top_left.x,
+                        r.top_left.y,
+                        r.bottom_right.x,
+                        r.bottom_right.y,
+                    ]
+                }))
+            }
+
+            /// Returns the vertices of two triangles covering the rectangle, for
+            /// drawing it as a triangle list. Both are wound counter-clockwise on
+            /// screen with y pointing down, which stays counter-clockwise after the
+            /// usual y flip into normalized device coordinates.
+            #[inline]
+            pub fn to_triangle_list(&self) -> [$V; 6] {
+                let [top_left, top_right, bottom_right, bottom_left] = self.corners();
+                [
+                    top_left,
+                    bottom_left,
+                    bottom_right,
+                    top_left,
+                    bottom_right,
+                    top_right,
+                ]
+            }
+
+            /// Returns the vertices of the rectangle in triangle strip order, with
+            /// the same winding as [`to_triangle_list`](Self::to_triangle_list).
+            #[inline]
+            pub fn to_triangle_strip(&self) -> [$V; 4] {
+                let [top_left, top_right, bottom_right, bottom_left] = self.corners();
+                [top_left, bottom_left, top_right, bottom_right]
+            }
+
+            /// Returns the vertices of [`to_triangle_list`](Self::to_triangle_list),
+            /// each paired with the matching corner of `uv`, such as the region of
+            /// a texture atlas to draw.
+            #[inline]
+            pub fn to_triangle_list_uv(&self, uv: &Self) -> [($V, $V); 6] {
+                let positions = self.to_triangle_list();
+                let uvs = uv.to_triangle_list();
+                core::array::from_fn(|i| (positions[i], uvs[i]))
+            }
+
+            /// Returns the vertices of [`to_triangle_strip`](Self::to_triangle_strip),
+            /// each paired with the matching corner of `uv`.
+            #[inline]
+            pub fn to_triangle_strip_uv(&self, uv: &Self) -> [($V, $V); 4] {
+                let positions = self.to_triangle_strip();
+                let uvs = uv.to_triangle_strip();
+                core::array::from_fn(|i| (positions[i], uvs[i]))
+            }
+
+            /// Returns the area shared with `other`, or zero if they do not overlap.
+            #[inline]
+            pub fn intersection_area(&self, other: &Self) -> $S {
+                self.intersect(other).map_or(0.0, |overlap| overlap.area())
+            }
+
+            /// Returns the intersection over union of the two rectangles: the shared
+            /// area divided by the area covered by either. This is 1 for identical
+            /// rectangles and 0 for disjoint ones, or if both have zero area.
+            #[inline]
+            pub fn iou(&self, other: &Self) -> $S {
+                let intersection = self.intersection_area(other);
+                let union = self.area() + other.area() - intersection;
+                if union > 0.0 {
+                    intersection / union
+                } else {
+                    0.0
+                }
+            }
+
+            /// Returns the perimeter of the rectangle.
+            #[inline]
+            pub fn perimeter(&self) -> $S {
+                2.0 * (self.width() + self.height())
+            }
+
+            /// Returns the midpoints of the left, top, right, and bottom edges, in the
+            /// order of [`Edge::ALL`](crate::Edge::ALL).
+            #[inline]
+            pub fn edge_midpoints(&self) -> [$V; 4] {
+                let center = self.center();
+                [
+                    $V::new(self.top_left.x, center.y),
+                    $V::new(center.x, self.top_left.y),
+                    $V::new(self.bottom_right.x, center.y),
+                    $V::new(center.x, self.bottom_right.y),
+                ]
+            }
+
+            /// Returns the center point of the rectangle.
+            #[inline]
+            pub fn center(&self) -> $V {
+                (self.top_left + self.bottom_right) * 0.5
+            }
+
+            /// Returns the width divided by the height.
+            #[inline]
+            pub fn aspect_ratio(&self) -> $S {
+                self.width() / self.height()
+            }
+
+            /// Returns the points where the segment from `a` to `b` meets the
+            /// rectangle's border, ordered from `a` to `b`. Unlike clipping, the parts
+            /// of the segment that are strictly inside or outside the rectangle do
+            /// not produce any points. If the segment runs along an edge, the ends of
+            /// the shared part are returned.
+            pub fn boundary_intersections(&self, a: $V, b: $V) -> $crate::ArrayVec<$V, 2> {
+                let mut points = $crate::ArrayVec::new();
+                let delta = b - a;
+                let (mut t_enter, mut t_exit): ($S, $S) = (0.0, 1.0);
+                let (mut entered, mut exited) = (false, false);
+                let slabs = [
+                    (-delta.x, a.x - self.top_left.x),
+                    (delta.x, self.bottom_right.x - a.x),
+                    (-delta.y, a.y - self.top_left.y),
+                    (delta.y, self.bottom_right.y - a.y),
+                ];
+                for (p, q) in slabs {
+                    if p == 0.0 {
+                        if q < 0.0 {
+                            return points;
+                        }
+                    } else {
+                        let t = q / p;
+                        if p < 0.0 && t > t_enter {
+                            t_enter = t;
+                            entered = true;
+                        } else if p > 0.0 && t < t_exit {
+                            t_exit = t;
+                            exited = true;
+                        }
+                    }
+                }
+                if t_enter > t_exit {
+                    return points;
+                }
+
+                let on_border = |point: $V| {
+                    point.x == self.top_left.x
+                        || point.x == self.bottom_right.x
+                        || point.y == self.top_left.y
+                        || point.y == self.bottom_right.y
+                };
+                let start = a + delta * t_enter;
+                let end = a + delta * t_exit;
+                if entered || on_border(start) {
+                    points.push(start);
+                }
+                if (exited || on_border(end)) && points.last() != Some(&end) {
+                    points.push(end);
+                }
+                points
+            }
+
+            /// Returns the entry and exit parameters of the ray from `origin` along
+            /// `direction` through the rectangle, such that `origin + direction * t`
+            /// lies on its border. The entry is zero if `origin` is inside. Returns
+            /// `None` if the ray misses the rectangle.
+            #[inline]
+            pub fn intersect_ray(&self, origin: $V, direction: $V) -> Option<($S, $S)> {
+                self.slab_range(origin, direction, 0.0, <$S>::INFINITY)
+            }
+
+            /// Returns the parameters, between 0 at `a` and 1 at `b`, where the
+            /// segment from `a` to `b` enters and exits the rectangle, or `None` if
+            /// it does not cross it.
+            #[inline]
+            pub fn intersect_segment(&self, a: $V, b: $V) -> Option<($S, $S)> {
+                self.slab_range(a, b - a, 0.0, 1.0)
+            }
+
+            /// Clips the segment from `a` to `b` to the rectangle, returning the
+            /// endpoints of the part inside it, or `None` if no part is inside.
+            #[inline]
+            pub fn clip_segment(&self, a: $V, b: $V) -> Option<($V, $V)> {
+                let delta = b - a;
+                self.slab_range(a, delta, 0.0, 1.0)
+                    .map(|(t_enter, t_exit)| (a + delta * t_enter, a + delta * t_exit))
+            }
+
+            /// Clips a polygon to the rectangle with the Sutherland-Hodgman
+            /// algorithm, returning the vertices of the part inside it, or no
+            /// vertices if no part is inside. Concave polygons may gain degenerate
+            /// edges along the border of the rectangle, and an edge passing exactly
+            /// through a corner of the rectangle may produce a repeated vertex.
+            pub fn clip_polygon(&self, polygon: &[$V]) -> Vec<$V> {
+                let mut output = polygon.to_vec();
+                let mut input = Vec::with_capacity(polygon.len() + 4);
+                let planes = [
+                    (0, self.top_left.x, true),
+                    (1, self.top_left.y, true),
+                    (0, self.bottom_right.x, false),
+                    (1, self.bottom_right.y, false),
+                ];
+                for (axis, bound, is_min) in planes {
+                    core::mem::swap(&mut input, &mut output);
+                    output.clear();
+                    let inside = |point: $V| {
+                        if is_min {
+                            point[axis] >= bound
+                        } else {
+                            point[axis] <= bound
+                        }
+                    };
+                    let crossing = |from: $V, to: $V| {
+                        let t = (bound - from[axis]) / (to[axis] - from[axis]);
+                        let mut point = from + (to - from) * t;
+                        point[axis] = bound;
+                        point
+                    };
+                    let Some(&last) = input.last() else {
+                        break;
+                    };
+                    let mut previous = last;
+                    for &current in &input {
+                        match (inside(previous), inside(current)) {
+                            (true, true) => output.push(current),
+                            (true, false) => output.push(crossing(previous, current)),
+                            (false, true) => {
+                                output.push(crossing(previous, current));
+                                output.push(current);
+                            }
+                            (false, false) => {}
+                        }
+                        previous = current;
+                    }
+                }
+                output
+            }
+
+            /// Narrows `t_enter..=t_exit` to the parameters where `origin + delta * t`
+            /// is inside the rectangle, using the Liang-Barsky slab test.
+            fn slab_range(
+                &self,
+                origin: $V,
+                delta: $V,
+                mut t_enter: $S,
+                mut t_exit: $S,
+            ) -> Option<($S, $S)> {
+                let slabs = [
+                    (-delta.x, origin.x - self.top_left.x),
+                    (delta.x, self.bottom_right.x - origin.x),
+                    (-delta.y, origin.y - self.top_left.y),
+                    (delta.y, self.bottom_right.y - origin.y),
+                ];
+                for (p, q) in slabs {
+                    if p == 0.0 {
+                        if q < 0.0 {
+                            return None;
+                        }
+                    } else {
+                        let t = q / p;
+                        if p < 0.0 {
+                            t_enter = t_enter.max(t);
+                        } else {
+                            t_exit = t_exit.min(t);
+                        }
+                    }
+                }
+                (t_enter <= t_exit).then_some((t_enter, t_exit))
+            }
+
+            #[doc = concat!(" Constructs a new `", stringify!($R), "` with the given size,")]
+            /// centered on `center`.
+            #[inline]
+            pub fn from_center_size(center: $V, size: $V) -> Self {
+                Self::from_position_size(center - size * 0.5, size)
+            }
+
+            /// Returns the shortest vector that moves this rectangle until it touches
+            /// `other`, or zero if the rectangles already touch or overlap.
+            #[inline]
+            #[must_use]
+            pub fn separation_vector(&self, other: &Self) -> $V {
+                let gap = |min: $S, max: $S, other_min: $S, other_max: $S| {
+                    if other_min > max {
+                        other_min - max
+                    } else if min > other_max {
+                        other_max - min
+                    } else {
+                        0.0
+                    }
+                };
+                $V::new(
+                    gap(
+                        self.top_left.x,
+                        self.bottom_right.x,
+                        other.top_left.x,
+                        other.bottom_right.x,
+                    ),
+                    gap(
+                        self.top_left.y,
+                        self.bottom_right.y,
+                        other.top_left.y,
+                        other.bottom_right.y,
+                    ),
+                )
+            }
+
+            /// Returns the distance between the closest points of the two rectangles,
+            /// or zero if they touch or overlap.
+            #[inline]
+            #[must_use]
+            pub fn distance_to_rect(&self, other: &Self) -> $S {
+                self.separation_vector(other).length()
+            }
+
+            /// Returns a point on this rectangle and a point on `other` that are as
+            /// close as possible, so that their distance is
+            #[doc = concat!(" [`", stringify!($R), "::distance_to_rect`]. If the rectangles touch")]
+            /// or overlap, both points are the same point, in the middle of the
+            /// shared area.
+            #[inline]
+            #[must_use]
+            pub fn closest_points_between(&self, other: &Self) -> ($V, $V) {
+                // The midpoint of the overlap on each axis, or of the gap if the
+                // rectangles do not overlap on that axis.
+                let middle = (self.top_left.max(other.top_left)
+                    + self.bottom_right.min(other.bottom_right))
+                    * 0.5;
+                (
+                    middle.max(self.top_left).min(self.bottom_right),
+                    middle.max(other.top_left).min(other.bottom_right),
+                )
+            }
+
+            /// Returns the edge of this rectangle that lies against `other`, if the
+            /// two are within `epsilon` of each other across that edge and share a
+            /// stretch of it longer than `epsilon`. Rects meeting only at a corner
+            /// are not adjacent, see [`shares_corner`](Self::shares_corner).
+            #[must_use]
+            pub fn is_adjacent(&self, other: &Self, epsilon: $S) -> Option<$crate::Edge> {
+                let overlap_x = self.bottom_right.x.min(other.bottom_right.x)
+                    - self.top_left.x.max(other.top_left.x);
+                let overlap_y = self.bottom_right.y.min(other.bottom_right.y)
+                    - self.top_left.y.max(other.top_left.y);
+                $crate::Edge::ALL.into_iter().find(|&edge| {
+                    let (gap, overlap) = match edge {
+                        $crate::Edge::Left => (self.top_left.x - other.bottom_right.x, overlap_y),
+                        $crate::Edge::Top => (self.top_left.y - other.bottom_right.y, overlap_x),
+                        $crate::Edge::Right => (other.top_left.x - self.bottom_right.x, overlap_y),
+                        $crate::Edge::Bottom => (other.top_left.y - self.bottom_right.y, overlap_x),
+                    };
+                    gap.abs() <= epsilon && overlap > epsilon
+                })
+            }
+
+            /// Returns `true` if a corner of this rectangle is within `epsilon` of a
+            /// corner of `other` along both axes.
+            #[must_use]
+            pub fn shares_corner(&self, other: &Self, epsilon: $S) -> bool {
+                let corners = other.corners();
+                self.corners().into_iter().any(|corner| {
+                    corners
+                        .iter()
+                        .any(|other| (corner - *other).abs().max_element() <= epsilon)
+                })
+            }
+
+            /// Returns the rectangle reflected across the vertical line at `axis_x`.
+            /// The left and right edges swap roles, so the result stays normalized.
+            #[inline]
+            #[must_use]
+            pub fn flip_horizontal(&self, axis_x: $S) -> Self {
+                Self::new(
+                    $V::new(2.0 * axis_x - self.bottom_right.x, self.top_left.y),
+                    $V::new(2.0 * axis_x - self.top_left.x, self.bottom_right.y),
+                )
+            }
+
+            /// Returns the rectangle reflected across the horizontal line at
+            /// `axis_y`. The top and bottom edges swap roles, so the result stays
+            /// normalized.
+            #[inline]
+            #[must_use]
+            pub fn flip_vertical(&self, axis_y: $S) -> Self {
+                Self::new(
+                    $V::new(self.top_left.x, 2.0 * axis_y - self.bottom_right.y),
+                    $V::new(self.bottom_right.x, 2.0 * axis_y - self.top_left.y),
+                )
+            }
+
+            /// Converts the rectangle between Y-down and Y-up coordinates in a space
+            /// of the given `height`, such as a window or texture, by mapping y to
+            /// `height - y`. Converting twice returns the original rectangle.
+            #[inline]
+            #[must_use]
+            pub fn flip_y(&self, height: $S) -> Self {
+                self.flip_vertical(height * 0.5)
+            }
+
+            /// Returns the rectangle reflected through `point`, which is the same as
+            /// flipping it both horizontally and vertically about that point.
+            #[inline]
+            #[must_use]
+            pub fn mirror_around(&self, point: $V) -> Self {
+                Self::new(point * 2.0 - self.bottom_right, point * 2.0 - self.top_left)
+            }
+
+            /// Returns a new rectangle grown outwards by `insets`, which may be a uniform
+            #[doc = concat!(" `", stringify!($S), "`, a `", stringify!($V), "` of horizontal and")]
+            /// vertical amounts, or per-side [`Insets`](crate::Insets).
+            #[inline]
+            #[must_use]
+            pub fn inflate(&self, insets: impl Into<$crate::Insets>) -> Self {
+                let insets = insets.into();
+                self.expand_edges(
+                    insets.left as $S,
+                    insets.top as $S,
+                    insets.right as $S,
+                    insets.bottom as $S,
+                )
+            }
+
+            /// Grows this rectangle outwards in place by `insets`, the mutating form
+            #[doc = concat!(" of [`", stringify!($R), "::inflate`].")]
+            #[inline]
+            pub fn inflate_mut(&mut self, insets: impl Into<$crate::Insets>) {
+                *self = self.inflate(insets);
+            }
+
+            /// Returns a new rectangle shrunk inwards by `insets`, or `None` if the opposite edges
+            /// would cross. Shrinking to exactly zero size succeeds. See
+            #[doc = concat!(" [`", stringify!($R), "::deflate_clamped`] for a version that cannot")]
+            /// fail.
+            #[inline]
+            #[must_use]
+            pub fn deflate(&self, insets: impl Into<$crate::Insets>) -> Option<Self> {
+                let insets = insets.into();
+                let top_left = self.top_left + $V::new(insets.left as $S, insets.top as $S);
+                let bottom_right =
+                    self.bottom_right - $V::new(insets.right as $S, insets.bottom as $S);
+                if top_left.x <= bottom_right.x && top_left.y <= bottom_right.y {
+                    Some(Self::new(top_left, bottom_right))
+                } else {
+                    None
+                }
+            }
+
+            /// Returns a new rectangle shrunk inwards by `insets`. An axis whose
+            /// edges would cross collapses to zero size at the midpoint between the
+            /// moved edges, clamped to lie within this rectangle.
+            #[inline]
+            #[must_use]
+            pub fn deflate_clamped(&self, insets: impl Into<$crate::Insets>) -> Self {
+                let insets = insets.into();
+                let mut top_left = self.top_left + $V::new(insets.left as $S, insets.top as $S);
+                let mut bottom_right =
+                    self.bottom_right - $V::new(insets.right as $S, insets.bottom as $S);
+                if top_left.x > bottom_right.x {
+                    let mid = ((top_left.x + bottom_right.x) * 0.5)
+                        .clamp(self.top_left.x, self.bottom_right.x);
+                    top_left.x = mid;
+                    bottom_right.x = mid;
+                }
+                if top_left.y > bottom_right.y {
+                    let mid = ((top_left.y + bottom_right.y) * 0.5)
+                        .clamp(self.top_left.y, self.bottom_right.y);
+                    top_left.y = mid;
+                    bottom_right.y = mid;
+                }
+                Self::new(top_left, bottom_right)
+            }
+
+            /// Returns the part of the rectangle inside a border as thick as `insets`,
+            /// the hole of [`frame`](Self::frame). Borders that do not fit collapse
+            /// as in [`deflate_clamped`](Self::deflate_clamped), and negative insets
+            /// count as zero.
+            #[inline]
+            #[must_use]
+            pub fn interior(&self, insets: impl Into<$crate::Insets>) -> Self {
+                let interior = self.deflate_clamped(insets);
+                Self::new(
+                    interior.top_left.max(self.top_left).min(self.bottom_right),
+                    interior
+                        .bottom_right
+                        .max(self.top_left)
+                        .min(self.bottom_right),
+                )
+            }
+
+            /// Splits the border of the rectangle, as thick as `insets`, into four
+            /// non-overlapping strips: the top and bottom ones spanning the full
+            /// width, then the left and right ones between them. Together with
+            /// [`interior`](Self::interior), they cover the rectangle exactly, which
+            /// suits drawing outlines as four quads or building hollow colliders.
+            #[inline]
+            #[must_use]
+            pub fn frame(&self, insets: impl Into<$crate::Insets>) -> [Self; 4] {
+                let inner = self.interior(insets);
+                [
+                    Self::new(
+                        self.top_left,
+                        $V::new(self.bottom_right.x, inner.top_left.y),
+                    ),
+                    Self::new(
+                        $V::new(self.top_left.x, inner.bottom_right.y),
+                        self.bottom_right,
+                    ),
+                    Self::new(
+                        $V::new(self.top_left.x, inner.top_left.y),
+                        $V::new(inner.top_left.x, inner.bottom_right.y),
+                    ),
+                    Self::new(
+                        $V::new(inner.bottom_right.x, inner.top_left.y),
+                        $V::new(self.bottom_right.x, inner.bottom_right.y),
+                    ),
+                ]
+            }
+
+            /// Splits the rectangle into the nine parts of a nine-slice (9-patch)
+            /// layout, with corners and edges as thick as `insets`. The parts are
+            /// returned row by row from the top left, in the same order as the
+            /// [`Anchor`](crate::Anchor) variants: top left corner, top edge, top
+            /// right corner, left edge, center, and so on. Insets that do not fit
+            /// are clamped like in [`deflate_clamped`](Self::deflate_clamped).
+            #[must_use]
+            pub fn nine_slice(&self, insets: impl Into<$crate::Insets>) -> [Self; 9] {
+                let inner = self.deflate_clamped(insets);
+                let xs = [
+                    self.top_left.x,
+                    inner.top_left.x,
+                    inner.bottom_right.x,
+                    self.bottom_right.x,
+                ];
+                let ys = [
+                    self.top_left.y,
+                    inner.top_left.y,
+                    inner.bottom_right.y,
+                    self.bottom_right.y,
+                ];
+                core::array::from_fn(|i| {
+                    let (column, row) = (i % 3, i / 3);
+                    Self::new(
+                        $V::new(xs[column], ys[row]),
+                        $V::new(xs[column + 1], ys[row + 1]),
+                    )
+                })
+            }
+
+            /// Returns a new rectangle with both vertices multiplied by `factor`,
+            /// i.e. scaled about the origin. Negative factors mirror the rectangle,
+            /// which stays normalized.
+            #[inline]
+            #[must_use]
+            pub fn scale(&self, factor: $V) -> Self {
+                self.scale_around($V::ZERO, factor)
+            }
+
+            /// Returns a new rectangle scaled by `factor` about the `anchor` point,
+            /// which stays in place. Negative factors mirror the rectangle, which
+            /// stays normalized.
+            #[inline]
+            #[must_use]
+            pub fn scale_around(&self, anchor: $V, factor: $V) -> Self {
+                let a = anchor + (self.top_left - anchor) * factor;
+                let b = anchor + (self.bottom_right - anchor) * factor;
+                Self::new(a.min(b), a.max(b))
+            }
+
+            /// Returns the point inside the rectangle, including its edges, that is
+            /// closest to `point`.
+            #[inline]
+            #[must_use]
+            pub fn clamp_point(&self, point: $V) -> $V {
+                point.clamp(self.top_left, self.bottom_right)
+            }
+
+            /// Returns the point on or inside the rectangle closest to `point`. This
+            /// is the same as [`clamp_point`](Self::clamp_point).
+            #[inline]
+            #[must_use]
+            pub fn closest_point(&self, point: $V) -> $V {
+                self.clamp_point(point)
+            }
+
+            /// Returns the squared distance from `point` to the rectangle, which is
+            /// zero for points inside it.
+            #[inline]
+            #[must_use]
+            pub fn distance_squared(&self, point: $V) -> $S {
+                self.closest_point(point).distance_squared(point)
+            }
+
+            /// Returns the distance from `point` to the rectangle, which is zero for
+            /// points inside it.
+            #[inline]
+            #[must_use]
+            pub fn distance(&self, point: $V) -> $S {
+                self.closest_point(point).distance(point)
+            }
+
+            /// Returns the signed distance from `point` to the border of the
+            /// rectangle: positive outside, negative inside, and zero on an edge.
+            #[inline]
+            #[must_use]
+            pub fn signed_distance(&self, point: $V) -> $S {
+                let offset = (point - self.center()).abs() - self.size() * 0.5;
+                offset.max($V::ZERO).length() + offset.x.max(offset.y).min(0.0)
+            }
+
+            /// Returns this rectangle moved the minimal distance needed to lie within
+            /// `container`. Along any axis where it is larger than the container, it
+            /// is shrunk to the container's extent.
+            #[inline]
+            #[must_use]
+            pub fn clamp_inside(&self, container: &Self) -> Self {
+                let size = self.size().min(container.size());
+                let top_left = self
+                    .top_left
+                    .clamp(container.top_left, container.bottom_right - size);
+                Self::new(top_left, top_left + size)
+            }
+
+            /// Returns the smallest rectangle containing this rectangle both at its
+            /// current position and after moving with `velocity` for `dt`. Anything
+            /// the rectangle can hit during the move intersects this bound.
+            #[inline]
+            #[must_use]
+            pub fn swept_bounds(&self, velocity: $V, dt: $S) -> Self {
+                self.union(&self.with_offset(velocity * dt))
+            }
+
+            /// Cuts the rectangle in two at the fraction `t` of its size along
+            /// `axis`, which is clamped to `[0, 1]`. A [`SplitAxis::Horizontal`]
+            /// split places the parts side by side, a [`SplitAxis::Vertical`] one
+            /// stacks them.
+            ///
+            /// [`SplitAxis::Horizontal`]: crate::SplitAxis::Horizontal
+            /// [`SplitAxis::Vertical`]: crate::SplitAxis::Vertical
+            #[inline]
+            #[must_use]
+            pub fn split_fraction(&self, axis: $crate::SplitAxis, t: $S) -> (Self, Self) {
+                let t = t.clamp(0.0, 1.0);
+                match axis {
+                    $crate::SplitAxis::Horizontal => {
+                        self.split_at_x(self.top_left.x + self.width() * t)
+                    }
+                    $crate::SplitAxis::Vertical => {
+                        self.split_at_y(self.top_left.y + self.height() * t)
+                    }
+                }
+            }
+
+            /// Divides the rectangle into `columns` by `rows` equally sized cells
+            /// separated by `gutter`, returned row by row from the top left. Each
+            /// edge is computed directly from its index, so no error accumulates
+            /// across the grid. If the gutters do not fit, the cells have zero
+            /// size.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `columns` or `rows` is zero.
+            pub fn split_grid(
+                &self,
+                columns: u32,
+                rows: u32,
+                gutter: $V,
+            ) -> impl Iterator<Item = Self> {
+                assert!(columns > 0 && rows > 0, "a grid needs at least one cell");
+                let top_left = self.top_left;
+                let gutters = $V::new((columns - 1) as $S, (rows - 1) as $S);
+                let available = (self.size() - gutter * gutters).max($V::ZERO);
+                let counts = $V::new(columns as $S, rows as $S);
+                (0..rows).flat_map(move |row| {
+                    (0..columns).map(move |column| {
+                        let index = $V::new(column as $S, row as $S);
+                        let start = top_left + available * index / counts + gutter * index;
+                        let end =
+                            top_left + available * (index + $V::ONE) / counts + gutter * index;
+                        Self::new(start, end)
+                    })
+                })
+            }
+
+            /// Returns a 64-bit hash of the coordinates that is stable across runs
+            /// and platforms, for keying caches by layout rects. Negative zero is
+            /// treated as zero and all NaNs hash the same.
+            ///
+            /// With `quantization`, coordinates are first rounded to the nearest
+            /// multiple of that step, so rects that differ by less than it usually
+            /// share a key.
+            #[must_use]
+            pub fn cache_key(&self, quantization: Option<$S>) -> u64 {
+                let mut hash = 0xcbf2_9ce4_8422_2325u64;
+                let coordinates = [
+                    self.top_left.x,
+                    self.top_left.y,
+                    self.bottom_right.x,
+                    self.bottom_right.y,
+                ];
+                for coordinate in coordinates {
+                    let bits = match quantization {
+                        Some(step) => (coordinate / step).round() as i64 as u64,
+                        None if coordinate.is_nan() => <$S>::NAN.to_bits() as u64,
+                        None => (coordinate + 0.0).to_bits() as u64,
+                    };
+                    // FNV-1a over the little-endian bytes.
+                    for byte in bits.to_le_bytes() {
+                        hash = (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3);
+                    }
+                }
+                hash
+            }
+
+            /// Linearly interpolates both vertices between this rectangle and
+            /// `other`. `t = 0` returns `self` and `t = 1` returns `other`.
+            #[inline]
+            #[must_use]
+            pub fn lerp(&self, other: &Self, t: $S) -> Self {
+                Self::new(
+                    self.top_left.lerp(other.top_left, t),
+                    self.bottom_right.lerp(other.bottom_right, t),
+                )
+            }
+
+            #[doc = concat!(" Constructs a new `", stringify!($R), "`, or returns an error if a")]
+            /// coordinate is not finite or the vertices are inverted.
+            #[inline]
+            pub fn try_new(top_left: $V, bottom_right: $V) -> Result<Self, $crate::RectError> {
+                let rect = Self {
+                    top_left,
+                    bottom_right,
+                };
+                if !rect.top_left.is_finite() || !rect.bottom_right.is_finite() {
+                    Err($crate::RectError::NonFinite)
+                } else if !rect.is_normalized() {
+                    Err($crate::RectError::Inverted)
+                } else {
+                    Ok(rect)
+                }
+            }
+
+            /// Converts to an `IRect`, rounding the coordinates according to `mode`. Coordinates
+            /// outside the range of `i32` saturate.
+            #[inline]
+            pub fn to_irect(&self, mode: $crate::RoundMode) -> $crate::IRect {
+                let (top_left, bottom_right) = match mode {
+                    $crate::RoundMode::Floor => (self.top_left.floor(), self.bottom_right.floor()),
+                    $crate::RoundMode::Ceil => (self.top_left.ceil(), self.bottom_right.ceil()),
+                    $crate::RoundMode::Round => (self.top_left.round(), self.bottom_right.round()),
+                    $crate::RoundMode::Expand => (self.top_left.floor(), self.bottom_right.ceil()),
+                };
+                $crate::IRect::new(top_left.as_ivec2(), bottom_right.as_ivec2())
+            }
+
+            /// Returns the smallest rectangle containing this one whose edges lie on
+            /// device pixel boundaries, for a display with `scale` device pixels per
+            /// unit. This keeps borders and backgrounds crisp instead of blurring
+            /// them across half pixels.
+            #[inline]
+            #[must_use]
+            pub fn snap_outward(&self, scale: $S) -> Self {
+                let (top_left, bottom_right) = self.snapped_outward(scale);
+                Self::new(top_left / scale, bottom_right / scale)
+            }
+
+            /// Returns the largest rectangle inside this one whose edges lie on
+            /// device pixel boundaries, for a display with `scale` device pixels per
+            /// unit. An axis that spans no whole pixel collapses to zero size.
+            #[inline]
+            #[must_use]
+            pub fn snap_inward(&self, scale: $S) -> Self {
+                let (top_left, bottom_right) = self.snapped_inward(scale);
+                Self::new(top_left / scale, bottom_right / scale)
+            }
+
+            /// Returns the device pixels covered by [`snap_outward`](Self::snap_outward)
+            /// as an `IRect` in device pixel coordinates.
+            #[inline]
+            pub fn snap_outward_to_irect(&self, scale: $S) -> $crate::IRect {
+                let (top_left, bottom_right) = self.snapped_outward(scale);
+                $crate::IRect::new(top_left.as_ivec2(), bottom_right.as_ivec2())
+            }
+
+            /// Returns the device pixels covered by [`snap_inward`](Self::snap_inward)
+            /// as an `IRect` in device pixel coordinates.
+            #[inline]
+            pub fn snap_inward_to_irect(&self, scale: $S) -> $crate::IRect {
+                let (top_left, bottom_right) = self.snapped_inward(scale);
+                $crate::IRect::new(top_left.as_ivec2(), bottom_right.as_ivec2())
+            }
+
+            fn snapped_outward(&self, scale: $S) -> ($V, $V) {
+                (
+                    (self.top_left * scale).floor(),
+                    (self.bottom_right * scale).ceil(),
+                )
+            }
+
+            fn snapped_inward(&self, scale: $S) -> ($V, $V) {
+                let top_left = (self.top_left * scale).ceil();
+                (top_left, (self.bottom_right * scale).floor().max(top_left))
+            }
+
+            /// Returns the cells of a grid with cells of `cell_size`, starting at
+            /// the origin, that are covered by the rectangle according to `mode`.
+            /// The result is in cell coordinates, and has zero area if no cell
+            /// qualifies.
+            pub fn covered_cells(&self, cell_size: $V, mode: $crate::CoverMode) -> $crate::IRect {
+                let a = self.top_left / cell_size;
+                let b = self.bottom_right / cell_size;
+                let (start, end) = match mode {
+                    $crate::CoverMode::Touched => (a.floor(), b.ceil()),
+                    $crate::CoverMode::Majority => {
+                        let start = (a - 0.5).floor() + 1.0;
+                        // A rect no more than half a cell wide cannot cover the
+                        // majority of any cell along that axis.
+                        let narrow = (b - a).cmple($V::splat(0.5));
+                        (start, $V::select(narrow, start, (b - 0.5).ceil()))
+                    }
+                    $crate::CoverMode::Full => (a.ceil(), b.floor()),
+                };
+                let start = start.as_ivec2();
+                $crate::IRect::new(start, end.as_ivec2().max(start))
+            }
+
+            /// Returns each pixel the rectangle overlaps, as integer coordinates of
+            /// unit cells starting at the origin, with the fraction of the pixel it
+            /// covers, for drawing anti-aliased box fills. Pixels are visited row by
+            /// row from the top left, and pixels the rectangle only touches are
+            /// skipped.
+            pub fn pixel_coverage(&self) -> impl Iterator<Item = ($crate::glam::IVec2, $S)> {
+                let rect = *self;
+                self.covered_cells($V::ONE, $crate::CoverMode::Touched)
+                    .points()
+                    .filter_map(move |pixel| {
+                        let cell = $V::new(pixel.x as $S, pixel.y as $S);
+                        let overlap = (rect.bottom_right.min(cell + $V::ONE)
+                            - rect.top_left.max(cell))
+                        .max($V::ZERO);
+                        let coverage = overlap.x * overlap.y;
+                        (coverage > 0.0).then_some((pixel, coverage))
+                    })
+            }
+
+            /// Panics if the rect has non-finite coordinates or if the top left vertex
+            /// is below or to the right of the bottom right vertex. Does nothing
+            /// unless the `strict` feature is enabled in a debug build.
+            #[inline]
+            #[track_caller]
+            const fn strict_check(&self) {
+                if cfg!(all(feature = "strict", debug_assertions)) {
+                    assert!(
+                        self.top_left.x.is_finite()
+                            && self.top_left.y.is_finite()
+                            && self.bottom_right.x.is_finite()
+                            && self.bottom_right.y.is_finite(),
+                        concat!(stringify!($R), " has non-finite coordinates")
+                    );
+                    assert!(
+                        self.top_left.x <= self.bottom_right.x
+                            && self.top_left.y <= self.bottom_right.y,
+                        concat!(
+                            stringify!($R),
+                            " top_left must be above and to the left of bottom_right"
+                        )
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Implements the methods of an integer rect type. `$W` is the type that the
+/// area and perimeter are widened to, and `$FR` the floating point rect type
+/// used for scaling and interpolation.
+macro_rules! impl_int_rect {
+    (
+        $R:ident, $V:ident, $V4:ident, $S:ty, $W:ty,
+        $FR:ident, $FV:ident, $F:ty, $as_fv:ident, $as_v:ident
+    ) => {
+        impl_rect_common!($R, $V, $V4, $S);
+
+        impl $R {
+            /// Returns the smallest rectangle containing both this rectangle and the
+            /// unit cell at `point`, so that `point` is inside the result by
+            #[doc = concat!(" [`", stringify!($R), "::contains`]. A coordinate of")]
+            #[doc = concat!(" `", stringify!($S), "::MAX` cannot be included and ends up on the")]
+            /// bottom or right edge instead.
+            #[inline]
+            #[must_use]
+            pub fn union_point(&self, point: $V) -> Self {
+                Self::new(
+                    self.top_left.min(point),
+                    self.bottom_right.max(Self::cell_end(point)),
+                )
+            }
+
+            /// Grows this rectangle in place to contain `point`, the mutating form of
+            #[doc = concat!(" [`", stringify!($R), "::union_point`] for accumulating bounds.")]
+            #[inline]
+            pub fn expand_to_include(&mut self, point: $V) {
+                *self = self.union_point(point);
+            }
+
+            /// Returns the bounding box of the unit cells at the given points, or
+            /// `None` if there are no points. Every point is inside the result by
+            #[doc = concat!(" [`", stringify!($R), "::contains`], except for coordinates of")]
+            #[doc = concat!(" `", stringify!($S), "::MAX` as in")]
+            /// [`union_point`](Self::union_point).
+            #[inline]
+            pub fn from_points(points: impl IntoIterator<Item = $V>) -> Option<Self> {
+                let mut points = points.into_iter();
+                let first = points.next()?;
+                let bounds = Self::new(first, Self::cell_end(first));
+                Some(points.fold(bounds, |bounds, point| bounds.union_point(point)))
+            }
+
+            /// Returns the bottom right vertex of the unit cell at `point`.
+            #[inline]
+            fn cell_end(point: $V) -> $V {
+                $V::new(point.x.saturating_add(1), point.y.saturating_add(1))
+            }
+
+            /// Returns a new rectangle with each edge moved outwards by the given amount.
+            #[doc = concat!(" Coordinates saturate at the bounds of `", stringify!($S), "`")]
+            /// instead of overflowing.
+            #[inline]
+            #[must_use]
+            pub fn expand_edges(&self, left: $S, top: $S, right: $S, bottom: $S) -> Self {
+                Self::new(
+                    $V::new(
+                        self.top_left.x.saturating_sub(left),
+                        self.top_left.y.saturating_sub(top),
+                    ),
+                    $V::new(
+                        self.bottom_right.x.saturating_add(right),
+                        self.bottom_right.y.saturating_add(bottom),
+                    ),
+                )
+            }
+
+            #[doc = concat!(" Translates like [`", stringify!($R), "::with_offset`], but returns")]
+            /// `None` if any coordinate would overflow.
+            #[inline]
+            #[must_use]
+            pub fn checked_offset(&self, offset: impl Into<$V>) -> Option<Self> {
+                let offset = offset.into();
+                let top_left = $V::new(
+                    self.top_left.x.checked_add(offset.x)?,
+                    self.top_left.y.checked_add(offset.y)?,
+                );
+                let bottom_right = $V::new(
+                    self.bottom_right.x.checked_add(offset.x)?,
+                    self.bottom_right.y.checked_add(offset.y)?,
+                );
+                Some(Self::new(top_left, bottom_right))
+            }
+
+            #[doc = concat!(" Translates like [`", stringify!($R), "::with_negative_offset`], but")]
+            /// returns `None` if any coordinate would overflow.
+            #[inline]
+            #[must_use]
+            pub fn checked_negative_offset(&self, offset: impl Into<$V>) -> Option<Self> {
+                let offset = offset.into();
+                let top_left = $V::new(
+                    self.top_left.x.checked_sub(offset.x)?,
+                    self.top_left.y.checked_sub(offset.y)?,
+                );
+                let bottom_right = $V::new(
+                    self.bottom_right.x.checked_sub(offset.x)?,
+                    self.bottom_right.y.checked_sub(offset.y)?,
+                );
+                Some(Self::new(top_left, bottom_right))
+            }
+
+            #[doc = concat!(" Translates like [`", stringify!($R), "::with_offset`], but")]
+            #[doc = concat!(" coordinates saturate at the bounds of `", stringify!($S), "`,")]
+            /// which may shrink the rectangle.
+            #[inline]
+            #[must_use]
+            pub fn saturating_offset(&self, offset: impl Into<$V>) -> Self {
+                let offset = offset.into();
+                Self::new(
+                    $V::new(
+                        self.top_left.x.saturating_add(offset.x),
+                        self.top_left.y.saturating_add(offset.y),
+                    ),
+                    $V::new(
+                        self.bottom_right.x.saturating_add(offset.x),
+                        self.bottom_right.y.saturating_add(offset.y),
+                    ),
+                )
+            }
+
+            #[doc = concat!(" Translates like [`", stringify!($R), "::with_negative_offset`], but")]
+            #[doc = concat!(" coordinates saturate at the bounds of `", stringify!($S), "`,")]
+            /// which may shrink the rectangle.
+            #[inline]
+            #[must_use]
+            pub fn saturating_negative_offset(&self, offset: impl Into<$V>) -> Self {
+                let offset = offset.into();
+                Self::new(
+                    $V::new(
+                        self.top_left.x.saturating_sub(offset.x),
+                        self.top_left.y.saturating_sub(offset.y),
+                    ),
+                    $V::new(
+                        self.bottom_right.x.saturating_sub(offset.x),
+                        self.bottom_right.y.saturating_sub(offset.y),
+                    ),
+                )
+            }
+
+            /// Returns a new rectangle with each edge moved outwards by `amount`, or
+            /// `None` if any coordinate would overflow.
+            #[inline]
+            #[must_use]
+            pub fn checked_expand(&self, amount: $S) -> Option<Self> {
+                let top_left = $V::new(
+                    self.top_left.x.checked_sub(amount)?,
+                    self.top_left.y.checked_sub(amount)?,
+                );
+                let bottom_right = $V::new(
+                    self.bottom_right.x.checked_add(amount)?,
+                    self.bottom_right.y.checked_add(amount)?,
+                );
+                Self::try_new(top_left, bottom_right).ok()
+            }
+
+            /// Returns a new rectangle with each edge moved inwards by `amount`, or
+            /// `None` if any coordinate would overflow or the edges would cross.
+            #[inline]
+            #[must_use]
+            pub fn checked_shrink(&self, amount: $S) -> Option<Self> {
+                let top_left = $V::new(
+                    self.top_left.x.checked_add(amount)?,
+                    self.top_left.y.checked_add(amount)?,
+                );
+                let bottom_right = $V::new(
+                    self.bottom_right.x.checked_sub(amount)?,
+                    self.bottom_right.y.checked_sub(amount)?,
+                );
+                Self::try_new(top_left, bottom_right).ok()
+            }
+
+            /// Returns the length of the rectangle's diagonal.
+            #[inline]
+            pub fn diagonal_length(&self) -> $F {
+                (self.width() as $F).hypot(self.height() as $F)
+            }
+
+            /// Returns `true` if the width and height are equal.
+            #[inline]
+            pub fn is_square(&self) -> bool {
+                self.width() == self.height()
+            }
+
+            /// Returns the area of the rectangle. The result is widened to
+            #[doc = concat!(" `", stringify!($W), "` so that it cannot overflow.")]
+            #[inline]
+            pub fn area(&self) -> $W {
+                self.width() as $W * self.height() as $W
+            }
+
+            /// Returns the total area covered by `rects`, counting overlapping parts
+            /// only once. This takes `O(n log n)` time with a sweep line, unlike
+            /// adding up pairwise intersections.
+            pub fn union_area(rects: &[Self]) -> $W {
+                $crate::union_area::union_area(rects.iter().map(|r| {
+                    [
+                        r.top_left.x as $W,
+                        r.top_left.y as $W,
+                        r.bottom_right.x as $W,
+                        r.bottom_right.y as $W,
+                    ]
+                }))
+            }
+
+            /// Returns the area shared with `other`, or zero if they do not overlap.
+            /// Like [`area`](Self::area), the result is widened.
+            #[inline]
+            pub fn intersection_area(&self, other: &Self) -> $W {
+                self.intersect(other).map_or(0, |overlap| overlap.area())
+            }
+
+            /// Returns the intersection over union of the two rectangles: the shared
+            /// area divided by the area covered by either. This is 1 for identical
+            /// rectangles and 0 for disjoint ones, or if both have zero area.
+            #[inline]
+            pub fn iou(&self, other: &Self) -> $F {
+                let intersection = self.intersection_area(other);
+                // Subtracting first keeps the sum within the area of the bounds,
+                // which cannot overflow.
+                let union = self.area() + (other.area() - intersection);
+                if union > 0 {
+                    intersection as $F / union as $F
+                } else {
+                    0.0
+                }
+            }
+
+            /// Returns the perimeter of the rectangle, widened to
+            #[doc = concat!(" `", stringify!($W), "`.")]
+            #[inline]
+            pub fn perimeter(&self) -> $W {
+                2 * (self.width() as $W + self.height() as $W)
+            }
+
+            /// Returns the center point of the rectangle, rounded towards the top
+            /// left vertex.
+            #[inline]
+            pub fn center(&self) -> $V {
+                self.top_left + self.size() / 2
+            }
+
+            /// Returns the width divided by the height.
+            #[inline]
+            pub fn aspect_ratio(&self) -> $F {
+                self.width() as $F / self.height() as $F
+            }
+
+            #[doc = concat!(" Constructs a new `", stringify!($R), "` with the given size,")]
+            /// centered on `center`. If the size is odd the extra unit goes to the bottom right.
+            #[inline]
+            pub fn from_center_size(center: $V, size: $V) -> Self {
+                Self::from_position_size(center - size / 2, size)
+            }
+
+            /// Returns a new rectangle with both vertices multiplied by `factor` and rounded to the
+            #[doc = concat!(" nearest integer. See [`", stringify!($FR), "::scale`].")]
+            #[inline]
+            #[must_use]
+            pub fn scale_rounded(&self, factor: $FV) -> Self {
+                self.scale_around_rounded($FV::ZERO, factor)
+            }
+
+            /// Returns a new rectangle scaled by `factor` about the `anchor` point,
+            /// with the vertices rounded to the nearest integer. See
+            #[doc = concat!(" [`", stringify!($FR), "::scale_around`].")]
+            #[inline]
+            #[must_use]
+            pub fn scale_around_rounded(&self, anchor: $FV, factor: $FV) -> Self {
+                let scaled = $FR::new(self.top_left.$as_fv(), self.bottom_right.$as_fv())
+                    .scale_around(anchor, factor);
+                Self::new(
+                    scaled.top_left.round().$as_v(),
+                    scaled.bottom_right.round().$as_v(),
+                )
+            }
+
+            /// Linearly interpolates both vertices between this rectangle and
+            /// `other`, rounding them to the nearest integer. `t = 0` returns `self`
+            /// and `t = 1` returns `other`.
+            #[inline]
+            #[must_use]
+            pub fn lerp(&self, other: &Self, t: $F) -> Self {
+                let lerp = |a: $V, b: $V| a.$as_fv().lerp(b.$as_fv(), t).round().$as_v();
+                Self::new(
+                    lerp(self.top_left, other.top_left),
+                    lerp(self.bottom_right, other.bottom_right),
+                )
+            }
+
+            /// Returns an iterator over every integer point inside the rectangle,
+            /// row by row from the top left. Like [`contains`](Self::contains), this
+            /// includes the top and left edges but not the bottom and right ones.
+            #[inline]
+            pub fn points(&self) -> impl Iterator<Item = $V> {
+                let (top_left, bottom_right) = (self.top_left, self.bottom_right);
+                (top_left.y..bottom_right.y)
+                    .flat_map(move |y| (top_left.x..bottom_right.x).map(move |x| $V::new(x, y)))
+            }
+
+            /// Returns an iterator over the integer points on the border of
+            /// [`points`](Self::points), each exactly once, clockwise from the top
+            /// left one: along the top row, down the right column, back along the bottom
+            /// row and up the left column. This is the outline a 1px border would
+            /// cover.
+            #[inline]
+            pub fn perimeter_points(&self) -> impl Iterator<Item = $V> {
+                let $V { x: l, y: t } = self.top_left;
+                let $V { x: r, y: b } = self.bottom_right;
+                let filled = l < r && t < b;
+                let (wide, tall) = (filled && r - l > 1, filled && b - t > 1);
+                let top = if filled { l..r } else { l..l };
+                let right = if filled { t + 1..b } else { t..t };
+                let bottom = if tall { l..r - 1 } else { l..l };
+                let left = if wide && tall { t + 1..b - 1 } else { t..t };
+                top.map(move |x| $V::new(x, t))
+                    .chain(right.map(move |y| $V::new(r - 1, y)))
+                    .chain(bottom.rev().map(move |x| $V::new(x, b - 1)))
+                    .chain(left.rev().map(move |y| $V::new(l, y)))
+            }
+
+            /// Divides the rectangle into `columns` by `rows` cells separated by
+            /// `gutter`, returned row by row from the top left. When the space
+            /// does not divide evenly, the remainder is spread deterministically
+            /// so that cell sizes differ by at most one. If the gutters do not
+            /// fit, the cells have zero size.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `columns` or `rows` is zero.
+            pub fn split_grid(
+                &self,
+                columns: u32,
+                rows: u32,
+                gutter: $V,
+            ) -> impl Iterator<Item = Self> {
+                assert!(columns > 0 && rows > 0, "a grid needs at least one cell");
+                let edges = |start: $S, size: $S, gutter: $S, count: u32| {
+                    let available = (size as $W)
+                        .saturating_sub(gutter as $W * (count - 1) as $W)
+                        .max(0);
+                    move |index: u32| {
+                        let offset = available * index as $W / count as $W;
+                        let cell_start = start as $W + offset + gutter as $W * index as $W;
+                        let next = available * (index + 1) as $W / count as $W;
+                        (cell_start as $S, (cell_start + next - offset) as $S)
+                    }
+                };
+                let x_edges = edges(self.top_left.x, self.width(), gutter.x, columns);
+                let y_edges = edges(self.top_left.y, self.height(), gutter.y, rows);
+                (0..rows).flat_map(move |row| {
+                    let (top, bottom) = y_edges(row);
+                    (0..columns).map(move |column| {
+                        let (left, right) = x_edges(column);
+                        Self::new($V::new(left, top), $V::new(right, bottom))
+                    })
+                })
+            }
+
+            /// Returns an iterator over the rows of the rectangle from top to
+            /// bottom, each as its `y` coordinate and the range of `x`
+            /// coordinates it covers.
+            #[inline]
+            pub fn rows(&self) -> impl Iterator<Item = ($S, core::ops::Range<$S>)> {
+                let x_range = self.x_range();
+                self.y_range().map(move |y| (y, x_range.clone()))
+            }
+
+            /// Returns an iterator over the columns of the rectangle from left
+            /// to right, each as its `x` coordinate and the range of `y`
+            /// coordinates it covers.
+            #[inline]
+            pub fn columns(&self) -> impl Iterator<Item = ($S, core::ops::Range<$S>)> {
+                let y_range = self.y_range();
+                self.x_range().map(move |x| (x, y_range.clone()))
+            }
+
+            /// Calls `f` with each row's `y` coordinate and the part of `buffer`
+            /// covered by that row, for a row-major buffer with `stride`
+            /// elements per row.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the rectangle has negative coordinates, extends past the
+            /// right edge of a row at `stride`, or extends past the end of `buffer`.
+            pub fn for_each_row<T>(
+                &self,
+                buffer: &mut [T],
+                stride: usize,
+                mut f: impl FnMut($S, &mut [T]),
+            ) {
+                let index = |v: $S| usize::try_from(v).expect("negative buffer coordinate");
+                let (start, end) = (index(self.top_left.x), index(self.bottom_right.x));
+                assert!(end <= stride, "the rect extends past the end of a row");
+                for y in self.top_left.y..self.bottom_right.y {
+                    let row = index(y) * stride;
+                    f(y, &mut buffer[row + start..row + end]);
+                }
+            }
+
+            #[doc = concat!(" Constructs a new `", stringify!($R), "`, or returns an error if the")]
+            /// vertices are inverted or the width or height overflow an
+            #[doc = concat!(" `", stringify!($S), "`.")]
+            #[inline]
+            pub fn try_new(top_left: $V, bottom_right: $V) -> Result<Self, $crate::RectError> {
+                let rect = Self {
+                    top_left,
+                    bottom_right,
+                };
+                if !rect.is_normalized() {
+                    Err($crate::RectError::Inverted)
+                } else if rect.bottom_right.x.checked_sub(rect.top_left.x).is_none()
+                    || rect.bottom_right.y.checked_sub(rect.top_left.y).is_none()
+                {
+                    Err($crate::RectError::Overflow)
+                } else {
+                    Ok(rect)
+                }
+            }
+
+            /// Panics if the top left vertex is below or to the right of the bottom right vertex,
+            #[doc = concat!(" or if the width or height overflow an `", stringify!($S), "`. Does")]
+            /// nothing unless the `strict` feature is enabled in a debug build.
+            #[inline]
+            #[track_caller]
+            const fn strict_check(&self) {
+                if cfg!(all(feature = "strict", debug_assertions)) {
+                    assert!(
+                        self.top_left.x <= self.bottom_right.x
+                            && self.top_left.y <= self.bottom_right.y,
+                        concat!(
+                            stringify!($R),
+                            " top_left must be above and to the left of bottom_right"
+                        )
+                    );
+                    assert!(
+                        self.bottom_right.x.checked_sub(self.top_left.x).is_some()
+                            && self.bottom_right.y.checked_sub(self.top_left.y).is_some(),
+                        concat!(stringify!($R), " size overflows ", stringify!($S))
+                    );
+                }
+            }
+        }
+    };
+}
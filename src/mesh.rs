@@ -0,0 +1,115 @@
+use glam::UVec2;
+
+use crate::{IRect, URect};
+
+/// Merges the occupied cells of a row-major grid `width` cells wide into
+/// few, larger rects with greedy meshing, e.g. to turn the solid tiles of a
+/// tile map into colliders. The rects are in cell coordinates, do not
+/// overlap, and cover exactly the occupied cells.
+///
+/// Each rect starts at the first uncovered cell in reading order, grows as
+/// far right as possible, then as far down as its whole width allows. This
+/// is not always the minimum number of rects, but close to it for typical
+/// maps.
+///
+/// # Panics
+///
+/// Panics if `width` is zero or the length of `occupied` is not a multiple
+/// of it.
+pub fn greedy_mesh(occupied: &[bool], width: usize) -> Vec<URect> {
+    assert!(
+        width > 0 && occupied.len() % width == 0,
+        "the grid must consist of whole rows"
+    );
+    let height = occupied.len() / width;
+    let mut covered = vec![false; occupied.len()];
+    let free = |covered: &[bool], index: usize| occupied[index] && !covered[index];
+    let mut rects = Vec::new();
+    for y in 0..height {
+        for x in 0..width {
+            if !free(&covered, y * width + x) {
+                continue;
+            }
+            let mut right = x + 1;
+            while right < width && free(&covered, y * width + right) {
+                right += 1;
+            }
+            let mut bottom = y + 1;
+            while bottom < height && (x..right).all(|x| free(&covered, bottom * width + x)) {
+                bottom += 1;
+            }
+            for row in y..bottom {
+                covered[row * width + x..row * width + right].fill(true);
+            }
+            rects.push(URect::new(
+                UVec2::new(x as u32, y as u32),
+                UVec2::new(right as u32, bottom as u32),
+            ));
+        }
+    }
+    rects
+}
+
+/// Merges a set of possibly overlapping rects, such as unit cells, into few
+/// larger disjoint rects covering the same cells, see [`greedy_mesh`].
+///
+/// The rects are rasterized over their bounds, so this suits tile maps
+/// rather than rects spread far apart.
+pub fn merge_cells(cells: impl IntoIterator<Item = IRect>) -> Vec<IRect> {
+    let cells: Vec<IRect> = cells
+        .into_iter()
+        .filter(|cell| cell.is_positive_area())
+        .collect();
+    let Some(bounds) = cells.iter().copied().reduce(|a, b| a.union(&b)) else {
+        return Vec::new();
+    };
+    let width = bounds.width() as usize;
+    let mut occupied = vec![false; width * bounds.height() as usize];
+    for cell in &cells {
+        let cell = cell.with_negative_offset(bounds.top_left);
+        for (y, columns) in cell.rows() {
+            let row = y as usize * width;
+            occupied[row + columns.start as usize..row + columns.end as usize].fill(true);
+        }
+    }
+    greedy_mesh(&occupied, width)
+        .into_iter()
+        .map(|rect| {
+            IRect::new(rect.top_left.as_ivec2(), rect.bottom_right.as_ivec2())
+                .with_offset(bounds.top_left)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::IVec2;
+
+    use super::*;
+
+    #[test]
+    pub fn test_greedy_mesh() {
+        #[rustfmt::skip]
+        let grid = [
+            1, 1, 0, 1,
+            1, 1, 0, 1,
+            1, 1, 1, 1,
+        ]
+        .map(|cell| cell == 1);
+        assert_eq!(
+            greedy_mesh(&grid, 4),
+            [
+                URect::from_tuples((0, 0), (2, 3)),
+                URect::from_tuples((3, 0), (4, 3)),
+                URect::from_tuples((2, 2), (3, 3)),
+            ]
+        );
+
+        let cells = (0..3)
+            .flat_map(|y| (-2..1).map(move |x| IVec2::new(x, y)))
+            .map(|cell| IRect::new(cell, cell + IVec2::ONE))
+            .chain([IRect::from_tuples((-2, 0), (0, 2))]);
+        assert_eq!(merge_cells(cells), [IRect::from_tuples((-2, 0), (1, 3))]);
+        assert!(merge_cells([]).is_empty());
+    }
+}
@@ -0,0 +1,110 @@
+use mint::{Point2, Vector2};
+
+use crate::{DRect, IRect, Rect, URect};
+
+/// A rectangle made of mint points, for passing rects between crates that
+/// standardize on mint types. Converts to and from every rect type with
+/// `From`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[repr(C)]
+pub struct MintRect<T> {
+    pub min: Point2<T>,
+    pub max: Point2<T>,
+}
+
+macro_rules! impl_mint {
+    ($rect:ident, $vec:ident, $t:ty) => {
+        impl From<$rect> for MintRect<$t> {
+            #[inline]
+            fn from(rect: $rect) -> Self {
+                let (min, max) = rect.into();
+                MintRect { min, max }
+            }
+        }
+
+        impl From<MintRect<$t>> for $rect {
+            #[inline]
+            fn from(rect: MintRect<$t>) -> Self {
+                (rect.min, rect.max).into()
+            }
+        }
+
+        /// Converts to the `(top_left, bottom_right)` points.
+        impl From<$rect> for (Point2<$t>, Point2<$t>) {
+            #[inline]
+            fn from(rect: $rect) -> Self {
+                let point = |v: glam::$vec| Point2 { x: v.x, y: v.y };
+                (point(rect.top_left), point(rect.bottom_right))
+            }
+        }
+
+        /// Converts from the `(top_left, bottom_right)` points.
+        impl From<(Point2<$t>, Point2<$t>)> for $rect {
+            #[inline]
+            fn from((top_left, bottom_right): (Point2<$t>, Point2<$t>)) -> Self {
+                $rect::new(
+                    glam::$vec::new(top_left.x, top_left.y),
+                    glam::$vec::new(bottom_right.x, bottom_right.y),
+                )
+            }
+        }
+
+        impl $rect {
+            /// Returns the size of the rectangle as a mint vector.
+            #[inline]
+            pub fn mint_size(&self) -> Vector2<$t> {
+                let size = self.size();
+                Vector2 {
+                    x: size.x,
+                    y: size.y,
+                }
+            }
+
+            /// Constructs a rectangle from the mint point of its top left vertex
+            /// and a mint vector of its size.
+            #[inline]
+            pub fn from_mint_position_size(position: Point2<$t>, size: Vector2<$t>) -> Self {
+                $rect::from_position_size(
+                    glam::$vec::new(position.x, position.y),
+                    glam::$vec::new(size.x, size.y),
+                )
+            }
+        }
+    };
+}
+
+impl_mint!(Rect, Vec2, f32);
+impl_mint!(DRect, DVec2, f64);
+impl_mint!(URect, UVec2, u32);
+impl_mint!(IRect, IVec2, i32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_mint_round_trip() {
+        let rect = IRect::from_tuples((-1, 2), (3, 4));
+        let mint = MintRect::from(rect);
+        assert_eq!(mint.min, Point2 { x: -1, y: 2 });
+        assert_eq!(mint.max, Point2 { x: 3, y: 4 });
+        assert_eq!(IRect::from(mint), rect);
+
+        let (min, max): (Point2<f32>, Point2<f32>) =
+            Rect::from_tuples((0.5, 1.0), (2.0, 3.0)).into();
+        assert_eq!(
+            Rect::from((min, max)),
+            Rect::from_tuples((0.5, 1.0), (2.0, 3.0))
+        );
+    }
+
+    #[test]
+    pub fn test_mint_size() {
+        let rect = URect::from_tuples((1, 2), (4, 8));
+        assert_eq!(rect.mint_size(), Vector2 { x: 3, y: 6 });
+        assert_eq!(
+            URect::from_mint_position_size(Point2 { x: 1, y: 2 }, rect.mint_size()),
+            rect
+        );
+    }
+}
@@ -0,0 +1,249 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+use glam::Vec2;
+
+use crate::{DRect, IRect, Rect, URect};
+#[cfg(any(
+    feature = "glam-0_25",
+    feature = "glam-0_26",
+    feature = "glam-0_27",
+    feature = "glam-0_28",
+    feature = "glam-0_29"
+))]
+use crate::{I16Rect, U16Rect};
+#[cfg(any(
+    feature = "glam-0_24",
+    feature = "glam-0_25",
+    feature = "glam-0_26",
+    feature = "glam-0_27",
+    feature = "glam-0_28",
+    feature = "glam-0_29"
+))]
+use crate::{I64Rect, U64Rect};
+
+/// Sorts rects in the order a reader scans a page: top to bottom in rows,
+/// then left to right within each row.
+///
+/// Rows are formed greedily from the topmost rect: every rect whose top edge
+/// is within `tolerance` of the row's first top edge belongs to that row.
+/// The sort is stable, so the result is deterministic for equal rects.
+pub fn reading_order_sort(rects: &mut [Rect], tolerance: f32) {
+    banded_sort(rects, tolerance, |point| point)
+}
+
+/// Sorts rects in column order: left to right in columns, then top to
+/// bottom within each column. This is [`reading_order_sort`] with the axes
+/// swapped.
+pub fn column_order_sort(rects: &mut [Rect], tolerance: f32) {
+    banded_sort(rects, tolerance, |point| Vec2::new(point.y, point.x))
+}
+
+/// Sorts along the band axis (`y` of the mapped point) with `tolerance`,
+/// then along the other axis within each band.
+fn banded_sort(rects: &mut [Rect], tolerance: f32, map: impl Fn(Vec2) -> Vec2) {
+    rects.sort_by(|a, b| map(a.top_left).y.total_cmp(&map(b.top_left).y));
+
+    let mut start = 0;
+    while start < rects.len() {
+        let band_top = map(rects[start].top_left).y;
+        let end = rects[start..]
+            .iter()
+            .position(|rect| map(rect.top_left).y - band_top > tolerance)
+            .map_or(rects.len(), |offset| start + offset);
+        rects[start..end].sort_by(|a, b| map(a.top_left).x.total_cmp(&map(b.top_left).x));
+        start = end;
+    }
+}
+
+/// A rect wrapper with a total order, for sorting rects deterministically
+/// and using them as keys of ordered maps. The order is that of
+/// [`Rect::total_cmp`] and the other rect types' `total_cmp`.
+///
+/// For float rects, equality is bitwise like in [`f32::total_cmp`], so `0.0`
+/// and `-0.0` differ and NaNs equal themselves.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OrderedRect<R>(pub R);
+
+macro_rules! impl_total_order {
+    ($R:ident, $cmp:ident, |$x:ident| $hash_key:expr) => {
+        impl $R {
+            /// Compares rects by their top edge, then left edge, then bottom edge,
+            /// then right edge, so that sorting orders them row by row. This is a
+            /// total order even for float coordinates.
+            pub fn total_cmp(&self, other: &Self) -> Ordering {
+                let key = |rect: &Self| {
+                    [
+                        rect.top_left.y,
+                        rect.top_left.x,
+                        rect.bottom_right.y,
+                        rect.bottom_right.x,
+                    ]
+                };
+                key(self)
+                    .iter()
+                    .zip(key(other).iter())
+                    .map(|(a, b)| a.$cmp(b))
+                    .find(|ordering| ordering.is_ne())
+                    .unwrap_or(Ordering::Equal)
+            }
+        }
+
+        impl PartialEq for OrderedRect<$R> {
+            fn eq(&self, other: &Self) -> bool {
+                self.cmp(other).is_eq()
+            }
+        }
+
+        impl Eq for OrderedRect<$R> {}
+
+        impl PartialOrd for OrderedRect<$R> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for OrderedRect<$R> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.total_cmp(&other.0)
+            }
+        }
+
+        impl Hash for OrderedRect<$R> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                let rect = &self.0;
+                for $x in [
+                    rect.top_left.x,
+                    rect.top_left.y,
+                    rect.bottom_right.x,
+                    rect.bottom_right.y,
+                ] {
+                    $hash_key.hash(state);
+                }
+            }
+        }
+
+        impl From<$R> for OrderedRect<$R> {
+            fn from(rect: $R) -> Self {
+                OrderedRect(rect)
+            }
+        }
+    };
+}
+
+impl_total_order!(Rect, total_cmp, |x| x.to_bits());
+impl_total_order!(DRect, total_cmp, |x| x.to_bits());
+impl_total_order!(IRect, cmp, |x| x);
+impl_total_order!(URect, cmp, |x| x);
+#[cfg(any(
+    feature = "glam-0_24",
+    feature = "glam-0_25",
+    feature = "glam-0_26",
+    feature = "glam-0_27",
+    feature = "glam-0_28",
+    feature = "glam-0_29"
+))]
+impl_total_order!(I64Rect, cmp, |x| x);
+#[cfg(any(
+    feature = "glam-0_24",
+    feature = "glam-0_25",
+    feature = "glam-0_26",
+    feature = "glam-0_27",
+    feature = "glam-0_28",
+    feature = "glam-0_29"
+))]
+impl_total_order!(U64Rect, cmp, |x| x);
+#[cfg(any(
+    feature = "glam-0_25",
+    feature = "glam-0_26",
+    feature = "glam-0_27",
+    feature = "glam-0_28",
+    feature = "glam-0_29"
+))]
+impl_total_order!(I16Rect, cmp, |x| x);
+#[cfg(any(
+    feature = "glam-0_25",
+    feature = "glam-0_26",
+    feature = "glam-0_27",
+    feature = "glam-0_28",
+    feature = "glam-0_29"
+))]
+impl_total_order!(U16Rect, cmp, |x| x);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(x: f32, y: f32) -> Rect {
+        Rect::from_position_size(Vec2::new(x, y), Vec2::new(10.0, 10.0))
+    }
+
+    #[test]
+    pub fn test_reading_order() {
+        let mut words = vec![
+            word(50.0, 21.0),
+            word(0.0, 2.0),
+            word(0.0, 20.0),
+            word(30.0, 0.0),
+            word(60.0, 1.0),
+        ];
+        reading_order_sort(&mut words, 3.0);
+
+        assert_eq!(
+            words,
+            vec![
+                word(0.0, 2.0),
+                word(30.0, 0.0),
+                word(60.0, 1.0),
+                word(0.0, 20.0),
+                word(50.0, 21.0),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn test_column_order() {
+        let mut words = vec![
+            word(21.0, 0.0),
+            word(0.0, 30.0),
+            word(20.0, 5.0),
+            word(1.0, 0.0),
+        ];
+        column_order_sort(&mut words, 3.0);
+
+        assert_eq!(
+            words,
+            vec![
+                word(1.0, 0.0),
+                word(0.0, 30.0),
+                word(21.0, 0.0),
+                word(20.0, 5.0)
+            ]
+        );
+    }
+
+    #[test]
+    pub fn test_ordered_rect() {
+        let a = Rect::from_tuples((5.0, 0.0), (6.0, 1.0));
+        let b = Rect::from_tuples((0.0, 2.0), (1.0, 3.0));
+        let c = Rect::from_tuples((0.0, 0.0), (1.0, 1.0));
+        let mut rects = [a, b, c];
+        rects.sort_by(Rect::total_cmp);
+        assert_eq!(rects, [c, a, b]);
+
+        let set: std::collections::BTreeSet<_> = [a, b, a].map(OrderedRect).into_iter().collect();
+        assert_eq!(set.len(), 2);
+        assert_ne!(
+            OrderedRect(Rect::from_tuples((0.0, 0.0), (1.0, 1.0))),
+            OrderedRect(Rect::from_tuples((-0.0, 0.0), (1.0, 1.0)))
+        );
+
+        let mut cells = [
+            IRect::from_tuples((1, 1), (2, 2)),
+            IRect::from_tuples((-1, 1), (0, 2)),
+        ];
+        cells.sort_by_key(|&rect| OrderedRect(rect));
+        assert_eq!(cells[0].top_left.x, -1);
+        assert_eq!(Rect::default(), Rect::from_tuples((0.0, 0.0), (0.0, 0.0)));
+    }
+}
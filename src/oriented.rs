@@ -0,0 +1,197 @@
+use core::ops::Range;
+
+use glam::Vec2;
+
+use crate::Rect;
+
+/// A rectangle rotated about its center, also known as an oriented bounding
+/// box. Intersection tests use the separating axis theorem.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct OrientedRect {
+    pub center: Vec2,
+    pub half_extents: Vec2,
+    /// The counterclockwise rotation in radians, with the y axis pointing
+    /// down as for [`Rect`].
+    pub rotation: f32,
+}
+
+impl OrientedRect {
+    /// Constructs a new `OrientedRect`. Both components of `half_extents`
+    /// must be non-negative.
+    #[inline]
+    pub const fn new(center: Vec2, half_extents: Vec2, rotation: f32) -> Self {
+        Self {
+            center,
+            half_extents,
+            rotation,
+        }
+    }
+
+    /// Constructs an unrotated `OrientedRect` covering `rect`.
+    #[inline]
+    pub fn from_rect(rect: &Rect) -> Self {
+        Self::new(rect.center(), rect.size() * 0.5, 0.0)
+    }
+
+    /// Returns the unit vectors along the rectangle's local x and y axes.
+    #[inline]
+    pub fn axes(&self) -> [Vec2; 2] {
+        let x_axis = Vec2::from_angle(self.rotation);
+        [x_axis, x_axis.perp()]
+    }
+
+    /// Returns the four vertices, starting with the one that is the top left
+    /// vertex when unrotated and continuing in the same order as
+    /// [`Rect::corners`].
+    pub fn corners(&self) -> [Vec2; 4] {
+        let [x_axis, y_axis] = self.axes();
+        let x = x_axis * self.half_extents.x;
+        let y = y_axis * self.half_extents.y;
+        [
+            self.center - x - y,
+            self.center + x - y,
+            self.center + x + y,
+            self.center - x + y,
+        ]
+    }
+
+    /// Returns `true` if `point` is inside the rectangle or on its border.
+    pub fn contains(&self, point: Vec2) -> bool {
+        let offset = point - self.center;
+        let [x_axis, y_axis] = self.axes();
+        offset.dot(x_axis).abs() <= self.half_extents.x
+            && offset.dot(y_axis).abs() <= self.half_extents.y
+    }
+
+    /// Returns the pixels covered by the rectangle as spans of whole rows,
+    /// each as its `y` coordinate and the range of `x` coordinates it covers.
+    /// A pixel at `(x, y)` is covered if its center `(x + 0.5, y + 0.5)` is
+    /// inside the rectangle, so that neighbouring shapes do not fill the same
+    /// pixel twice. Rows are visited from top to bottom and empty ones are
+    /// skipped.
+    pub fn rasterize_spans(&self) -> impl Iterator<Item = (i32, Range<i32>)> {
+        let corners = self.corners();
+        let bounds = self.bounding_rect();
+        let rows = (bounds.top_left.y - 0.5).ceil() as i32
+            ..(bounds.bottom_right.y - 0.5).floor() as i32 + 1;
+        rows.filter_map(move |y| {
+            let center_y = y as f32 + 0.5;
+            let (min, max) = (0..4).fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), i| {
+                let (a, b) = (corners[i], corners[(i + 1) % 4]);
+                if center_y < a.y.min(b.y) || center_y > a.y.max(b.y) {
+                    return (min, max);
+                }
+                let (from, to) = if a.y == b.y {
+                    (a.x.min(b.x), a.x.max(b.x))
+                } else {
+                    let x = a.x + (b.x - a.x) * (center_y - a.y) / (b.y - a.y);
+                    (x, x)
+                };
+                (min.min(from), max.max(to))
+            });
+            let columns = (min - 0.5).ceil() as i32..(max - 0.5).floor() as i32 + 1;
+            (!columns.is_empty()).then_some((y, columns))
+        })
+    }
+
+    /// Returns the smallest axis-aligned rect containing this one.
+    pub fn bounding_rect(&self) -> Rect {
+        let [x_axis, y_axis] = self.axes();
+        let extents = x_axis.abs() * self.half_extents.x + y_axis.abs() * self.half_extents.y;
+        Rect::new(self.center - extents, self.center + extents)
+    }
+
+    /// Returns `true` if the two rectangles have a common area. Rectangles
+    /// that only touch do not intersect, as with [`Rect::intersects`].
+    pub fn intersects(&self, other: &OrientedRect) -> bool {
+        let a = self.corners();
+        let b = other.corners();
+        self.axes()
+            .into_iter()
+            .chain(other.axes())
+            .all(|axis| overlaps_on_axis(&a, &b, axis))
+    }
+
+    /// Returns `true` if this rectangle and `rect` have a common area.
+    pub fn intersects_rect(&self, rect: &Rect) -> bool {
+        self.intersects(&OrientedRect::from_rect(rect))
+    }
+}
+
+/// Returns `true` if the projections of the two polygons onto `axis`
+/// overlap with positive length.
+fn overlaps_on_axis(a: &[Vec2; 4], b: &[Vec2; 4], axis: Vec2) -> bool {
+    let project = |points: &[Vec2; 4]| {
+        points
+            .iter()
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), p| {
+                let d = p.dot(axis);
+                (min.min(d), max.max(d))
+            })
+    };
+    let (a_min, a_max) = project(a);
+    let (b_min, b_max) = project(b);
+    a_min < b_max && b_min < a_max
+}
+
+impl From<Rect> for OrientedRect {
+    #[inline]
+    fn from(rect: Rect) -> Self {
+        OrientedRect::from_rect(&rect)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_4;
+
+    use super::*;
+
+    #[test]
+    pub fn test_oriented_rect() {
+        let diamond = OrientedRect::new(Vec2::ZERO, Vec2::ONE, FRAC_PI_4);
+        let sqrt2 = 2f32.sqrt();
+        assert!(diamond.contains(Vec2::new(1.4, 0.0)));
+        assert!(!diamond.contains(Vec2::new(0.9, 0.9)));
+        let bounds = diamond.bounding_rect();
+        assert!(bounds.bottom_right.abs_diff_eq(Vec2::splat(sqrt2), 1e-6));
+
+        // The corner of this rect is outside the diamond, but inside its
+        // bounding rect.
+        let corner = Rect::from_tuples((0.8, 0.8), (2.0, 2.0));
+        assert!(corner.intersects(&bounds));
+        assert!(!diamond.intersects_rect(&corner));
+        assert!(diamond.intersects_rect(&Rect::from_tuples((0.6, 0.6), (2.0, 2.0))));
+
+        let other = OrientedRect::new(Vec2::new(2.0, 0.0), Vec2::ONE, 0.0);
+        assert!(diamond.intersects(&other));
+        let far = OrientedRect::new(Vec2::new(2.5, 0.0), Vec2::ONE, 0.0);
+        assert!(!diamond.intersects(&far));
+    }
+
+    #[test]
+    pub fn test_rasterize_spans() {
+        let rotated = OrientedRect::new(Vec2::new(5.3, 4.1), Vec2::new(4.2, 2.7), 0.4);
+        let spans: Vec<_> = rotated.rasterize_spans().collect();
+        assert!(spans.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        for y in -5..15 {
+            for x in -5..15 {
+                let center = Vec2::new(x as f32 + 0.5, y as f32 + 0.5);
+                let covered = spans
+                    .iter()
+                    .any(|(row, columns)| *row == y && columns.contains(&x));
+                assert_eq!(covered, rotated.contains(center), "pixel {x}, {y}");
+            }
+        }
+
+        let aligned = OrientedRect::from_rect(&Rect::from_tuples((1.0, 2.0), (4.0, 4.0)));
+        assert_eq!(
+            aligned.rasterize_spans().collect::<Vec<_>>(),
+            [(2, 1..4), (3, 1..4)]
+        );
+        let thin = OrientedRect::from_rect(&Rect::from_tuples((1.1, 2.0), (1.4, 4.0)));
+        assert_eq!(thin.rasterize_spans().count(), 0);
+    }
+}
@@ -0,0 +1,73 @@
+use glam::Vec2;
+
+use crate::Rect;
+
+/// Pushes overlapping rects apart, for decluttering labels and cleaning up
+/// automatic layouts.
+///
+/// Each of up to `iterations` rounds finds every overlapping pair and moves
+/// both rects apart by half of their overlap, along the axis where the
+/// overlap is smallest so that the movement is minimal. No rect ends up
+/// further than `max_displacement` from where it started, along either axis.
+///
+/// Returns `true` if no rects overlap afterwards.
+pub fn remove_overlaps(rects: &mut [Rect], iterations: u32, max_displacement: f32) -> bool {
+    let origins: Vec<Vec2> = rects.iter().map(|rect| rect.top_left).collect();
+    let mut pushes = vec![Vec2::ZERO; rects.len()];
+    for _ in 0..iterations {
+        let mut overlapping = false;
+        pushes.fill(Vec2::ZERO);
+        for i in 0..rects.len() {
+            for j in i + 1..rects.len() {
+                let Some(overlap) = rects[i].intersect(&rects[j]) else {
+                    continue;
+                };
+                overlapping = true;
+                let delta = rects[j].center() - rects[i].center();
+                // Ties, such as identical rects, are broken deterministically
+                // by pushing the later rect right or down.
+                let push = if overlap.width() < overlap.height() {
+                    Vec2::new(overlap.width().copysign(delta.x), 0.0)
+                } else {
+                    Vec2::new(0.0, overlap.height().copysign(delta.y))
+                };
+                pushes[i] -= push * 0.5;
+                pushes[j] += push * 0.5;
+            }
+        }
+        if !overlapping {
+            return true;
+        }
+        for ((rect, push), origin) in rects.iter_mut().zip(&pushes).zip(&origins) {
+            let limit = Vec2::splat(max_displacement);
+            let top_left = (rect.top_left + *push).clamp(*origin - limit, *origin + limit);
+            *rect = Rect::from_position_size(top_left, rect.size());
+        }
+    }
+    rects
+        .iter()
+        .enumerate()
+        .all(|(i, a)| rects[i + 1..].iter().all(|b| !a.intersects(b)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_remove_overlaps() {
+        let mut rects = [
+            Rect::from_tuples((0.0, 0.0), (10.0, 10.0)),
+            Rect::from_tuples((8.0, 1.0), (18.0, 11.0)),
+            Rect::from_tuples((40.0, 0.0), (50.0, 10.0)),
+        ];
+        assert!(remove_overlaps(&mut rects, 10, 5.0));
+        assert_eq!(rects[0], Rect::from_tuples((-1.0, 0.0), (9.0, 10.0)));
+        assert_eq!(rects[1], Rect::from_tuples((9.0, 1.0), (19.0, 11.0)));
+        assert_eq!(rects[2], Rect::from_tuples((40.0, 0.0), (50.0, 10.0)));
+
+        let mut stacked = [Rect::from_tuples((0.0, 0.0), (10.0, 10.0)); 2];
+        assert!(!remove_overlaps(&mut stacked, 10, 2.0));
+        assert!(remove_overlaps(&mut stacked, 10, 5.0));
+    }
+}
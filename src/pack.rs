@@ -0,0 +1,312 @@
+use glam::UVec2;
+
+use crate::URect;
+
+/// Packs rects into an atlas, such as glyphs or sprites into a texture.
+///
+/// This is a guillotine packer: it keeps a list of disjoint free rects,
+/// places each allocation in the free rect it fits most tightly, and splits
+/// the leftover space in two. Deallocated rects are returned to the free
+/// list and merged with their free neighbours where possible.
+#[derive(Debug, Clone)]
+pub struct RectPacker {
+    bounds: URect,
+    free: Vec<URect>,
+    allocated: Vec<URect>,
+}
+
+impl RectPacker {
+    /// Constructs a new packer for an empty atlas covering `bounds`.
+    pub fn new(bounds: URect) -> Self {
+        let free = if bounds.is_positive_area() {
+            vec![bounds]
+        } else {
+            Vec::new()
+        };
+        Self {
+            bounds,
+            free,
+            allocated: Vec::new(),
+        }
+    }
+
+    /// Returns the area of the atlas.
+    #[inline]
+    pub fn bounds(&self) -> URect {
+        self.bounds
+    }
+
+    /// Returns the free space as disjoint rects.
+    #[inline]
+    pub fn free_rects(&self) -> &[URect] {
+        &self.free
+    }
+
+    /// Returns the allocated rects, in no particular order.
+    #[inline]
+    pub fn allocated_rects(&self) -> &[URect] {
+        &self.allocated
+    }
+
+    /// Returns the total free area.
+    pub fn free_area(&self) -> u64 {
+        self.free.iter().map(URect::area).sum()
+    }
+
+    /// Finds room for a rect of `size` and marks it as used, or returns
+    /// `None` if it does not fit anywhere.
+    pub fn allocate(&mut self, size: UVec2) -> Option<URect> {
+        if size.x == 0 || size.y == 0 {
+            return None;
+        }
+        let (index, _) = self
+            .free
+            .iter()
+            .enumerate()
+            .filter(|(_, free)| free.width() >= size.x && free.height() >= size.y)
+            .min_by_key(|(_, free)| {
+                let leftover = free.size() - size;
+                (leftover.x.min(leftover.y), leftover.x.max(leftover.y))
+            })?;
+        let free = self.free.swap_remove(index);
+        let placed = URect::from_position_size(free.top_left, size);
+
+        // Split along the shorter leftover side, keeping the larger of the
+        // two remaining rects as big as possible.
+        let right_x = placed.bottom_right.x;
+        let bottom_y = placed.bottom_right.y;
+        let (right, bottom) = if free.width() - size.x < free.height() - size.y {
+            (
+                URect::new(
+                    UVec2::new(right_x, free.top_left.y),
+                    UVec2::new(free.bottom_right.x, bottom_y),
+                ),
+                URect::new(UVec2::new(free.top_left.x, bottom_y), free.bottom_right),
+            )
+        } else {
+            (
+                URect::new(UVec2::new(right_x, free.top_left.y), free.bottom_right),
+                URect::new(
+                    UVec2::new(free.top_left.x, bottom_y),
+                    UVec2::new(right_x, free.bottom_right.y),
+                ),
+            )
+        };
+        self.free
+            .extend([right, bottom].into_iter().filter(URect::is_positive_area));
+        self.allocated.push(placed);
+        Some(placed)
+    }
+
+    /// Allocates a rect of `size`, doubling the width or height of the atlas
+    /// as needed while it stays within `max_size`. Returns `None` without
+    /// growing the atlas if the rect does not fit even at `max_size`. The
+    /// atlas never shrinks, so an axis of `max_size` below the current size
+    /// keeps the current size instead.
+    pub fn allocate_growing(&mut self, size: UVec2, max_size: UVec2) -> Option<URect> {
+        let max_size = max_size.max(self.bounds.size());
+        let mut packer = self.clone();
+        loop {
+            if let Some(rect) = packer.allocate(size) {
+                *self = packer;
+                return Some(rect);
+            }
+            let current = packer.bounds.size();
+            let grow_width =
+                current.x <= current.y && current.x < max_size.x || current.y >= max_size.y;
+            let new_size = if grow_width {
+                UVec2::new((current.x.max(1) * 2).min(max_size.x), current.y)
+            } else {
+                UVec2::new(current.x, (current.y.max(1) * 2).min(max_size.y))
+            };
+            if new_size == current {
+                return None;
+            }
+            packer.grow(new_size);
+        }
+    }
+
+    /// Grows the atlas to `size`, keeping its top left corner and all
+    /// allocations in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is smaller than the current size along either axis.
+    pub fn grow(&mut self, size: UVec2) {
+        let old = self.bounds;
+        assert!(size.cmpge(old.size()).all(), "the atlas can only grow");
+        self.bounds = URect::from_position_size(old.top_left, size);
+        let right = URect::new(
+            UVec2::new(old.bottom_right.x, old.top_left.y),
+            self.bounds.bottom_right,
+        );
+        let bottom = URect::new(
+            UVec2::new(old.top_left.x, old.bottom_right.y),
+            UVec2::new(old.bottom_right.x, self.bounds.bottom_right.y),
+        );
+        for rect in [right, bottom] {
+            if rect.is_positive_area() {
+                self.release(rect);
+            }
+        }
+    }
+
+    /// Returns an allocated rect to the free space. `rect` must have been
+    /// returned by [`RectPacker::allocate`] and not deallocated since.
+    pub fn deallocate(&mut self, rect: &URect) {
+        debug_assert!(
+            !self.free.iter().any(|free| free.intersects(rect)),
+            "the rect is already free"
+        );
+        if let Some(index) = self.allocated.iter().position(|used| used == rect) {
+            self.allocated.swap_remove(index);
+        }
+        self.release(*rect);
+    }
+
+    /// Moves up to `budget` allocations towards the top left of the atlas to
+    /// merge fragmented free space, returning each move as the old and new
+    /// rect in the order they were made. The caller must copy the contents
+    /// in that order; a new rect may overlap the old one it replaces.
+    ///
+    /// Allocations furthest from the top left are moved first, and only if
+    /// they can be placed in an earlier row, or further left in the same
+    /// row, so repeated calls settle once the atlas is compact.
+    pub fn defragment(&mut self, budget: usize) -> Vec<(URect, URect)> {
+        let mut candidates = self.allocated.clone();
+        candidates.sort_by_key(|rect| core::cmp::Reverse((rect.top_left.y, rect.top_left.x)));
+        let mut moves = Vec::new();
+        for old in candidates {
+            if moves.len() == budget {
+                break;
+            }
+            let mut packer = self.clone();
+            packer.deallocate(&old);
+            let Some(new) = packer.allocate(old.size()) else {
+                continue;
+            };
+            if (new.top_left.y, new.top_left.x) < (old.top_left.y, old.top_left.x) {
+                *self = packer;
+                moves.push((old, new));
+            }
+        }
+        moves
+    }
+
+    /// Forgets all allocations.
+    pub fn clear(&mut self) {
+        *self = Self::new(self.bounds);
+    }
+
+    /// Adds `rect` to the free list, merging it with free neighbours that
+    /// share a whole edge with it.
+    fn release(&mut self, mut rect: URect) {
+        let mut index = 0;
+        while index < self.free.len() {
+            if let Some(joined) = join(&rect, &self.free[index]) {
+                rect = joined;
+                self.free.swap_remove(index);
+                index = 0;
+            } else {
+                index += 1;
+            }
+        }
+        self.free.push(rect);
+    }
+}
+
+/// Returns the union of two rects if it is exactly covered by them.
+fn join(a: &URect, b: &URect) -> Option<URect> {
+    let same_columns = a.top_left.x == b.top_left.x && a.bottom_right.x == b.bottom_right.x;
+    let same_rows = a.top_left.y == b.top_left.y && a.bottom_right.y == b.bottom_right.y;
+    let stacked = a.bottom_right.y == b.top_left.y || b.bottom_right.y == a.top_left.y;
+    let side_by_side = a.bottom_right.x == b.top_left.x || b.bottom_right.x == a.top_left.x;
+    if (same_columns && stacked) || (same_rows && side_by_side) {
+        Some(a.union(b))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_rect_packer() {
+        let mut packer = RectPacker::new(URect::from_tuples((0, 0), (64, 64)));
+        let sizes = [(32, 32), (32, 16), (16, 16), (32, 32), (16, 16)];
+        let placed: Vec<_> = sizes
+            .iter()
+            .map(|&(w, h)| packer.allocate(UVec2::new(w, h)).unwrap())
+            .collect();
+        for (i, a) in placed.iter().enumerate() {
+            assert_eq!(packer.bounds().union(a), packer.bounds());
+            for b in &placed[i + 1..] {
+                assert!(!a.intersects(b));
+            }
+        }
+        assert_eq!(
+            packer.free_rects(),
+            [URect::from_tuples((32, 32), (64, 64))]
+        );
+        assert_eq!(packer.allocate(UVec2::new(33, 1)), None);
+
+        packer.deallocate(&placed[0]);
+        assert_eq!(packer.free_area(), 2 * 32 * 32);
+        assert!(packer.allocate(UVec2::new(32, 32)).is_some());
+
+        let big = packer.allocate_growing(UVec2::new(48, 48), UVec2::new(256, 256));
+        assert!(big.is_some());
+        assert_eq!(packer.bounds().bottom_right, UVec2::new(128, 64));
+        assert_eq!(
+            packer.allocate_growing(UVec2::new(300, 1), UVec2::new(256, 256)),
+            None
+        );
+        assert_eq!(packer.bounds().bottom_right, UVec2::new(128, 64));
+
+        packer.clear();
+        assert_eq!(packer.free_rects(), [packer.bounds()]);
+    }
+
+    #[test]
+    pub fn test_defragment() {
+        let mut packer = RectPacker::new(URect::from_tuples((0, 0), (16, 16)));
+        let placed: Vec<_> = (0..4)
+            .map(|_| packer.allocate(UVec2::new(8, 8)).unwrap())
+            .collect();
+        assert_eq!(packer.allocated_rects().len(), 4);
+        for rect in &placed[..3] {
+            packer.deallocate(rect);
+        }
+        let last = placed[3];
+        assert_ne!(last.top_left, UVec2::ZERO);
+
+        assert!(packer.defragment(0).is_empty());
+        let moves = packer.defragment(4);
+        assert_eq!(moves.len(), 1);
+        assert_eq!(moves[0].0, last);
+        assert_eq!(moves[0].1, URect::from_tuples((0, 0), (8, 8)));
+        assert_eq!(packer.allocated_rects(), [moves[0].1]);
+        assert_eq!(packer.free_area(), 16 * 16 - 8 * 8);
+        assert!(packer.defragment(4).is_empty());
+        assert!(packer.allocate(UVec2::new(8, 16)).is_some());
+    }
+
+    #[test]
+    pub fn test_allocate_growing_small_max_size() {
+        let mut packer = RectPacker::new(URect::from_tuples((0, 0), (64, 64)));
+        packer.allocate(UVec2::new(64, 64)).unwrap();
+        let placed = packer.allocate_growing(UVec2::new(16, 16), UVec2::new(32, 256));
+        assert_eq!(placed, Some(URect::from_tuples((0, 64), (16, 80))));
+        assert_eq!(packer.bounds().size(), UVec2::new(64, 128));
+
+        let mut full = RectPacker::new(URect::from_tuples((0, 0), (64, 64)));
+        full.allocate(UVec2::new(64, 64)).unwrap();
+        assert_eq!(
+            full.allocate_growing(UVec2::new(16, 16), UVec2::new(32, 32)),
+            None
+        );
+        assert_eq!(full.bounds().size(), UVec2::new(64, 64));
+    }
+}
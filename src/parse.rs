@@ -0,0 +1,132 @@
+use std::fmt;
+use std::str::FromStr;
+
+use glam::{DVec2, IVec2, UVec2, Vec2};
+
+use crate::{DRect, IRect, ParseRectError, Rect, RectError, URect};
+
+/// Splits `"x, y"` into its two numbers.
+fn parse_pair<S: FromStr>(text: &str) -> Result<(S, S), ParseRectError> {
+    let (x, y) = text.split_once(',').ok_or(ParseRectError::Syntax)?;
+    let parse = |text: &str| text.trim().parse().map_err(|_| ParseRectError::Number);
+    Ok((parse(x)?, parse(y)?))
+}
+
+macro_rules! impl_text {
+    ($R:ident, $V:ident, $S:ty, $checked_add:expr) => {
+        /// Formats the rect as `Name(x, y, wxh)` with its top left vertex and
+        /// size, or as `Name(x1, y1..x2, y2)` with its vertices if it is not
+        /// normalized. Both forms can be parsed back.
+        impl fmt::Display for $R {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let (top_left, bottom_right) = (self.top_left, self.bottom_right);
+                if self.is_normalized() {
+                    write!(
+                        f,
+                        "{}({}, {}, {}x{})",
+                        stringify!($R),
+                        top_left.x,
+                        top_left.y,
+                        self.width(),
+                        self.height()
+                    )
+                } else {
+                    write!(
+                        f,
+                        "{}({}, {}..{}, {})",
+                        stringify!($R),
+                        top_left.x,
+                        top_left.y,
+                        bottom_right.x,
+                        bottom_right.y
+                    )
+                }
+            }
+        }
+
+        /// Parses a rect from its top left vertex and size as `x, y, w, h` or
+        /// `x, y, wxh`, or from its vertices as `x1, y1..x2, y2`. The numbers
+        #[doc = concat!(" may be wrapped in `", stringify!($R), "(...)`, as written by the `Display`")]
+        /// implementation, and whitespace around them is ignored. The result
+        /// must be a normalized rect.
+        impl FromStr for $R {
+            type Err = ParseRectError;
+
+            fn from_str(text: &str) -> Result<Self, Self::Err> {
+                let text = text.trim();
+                let text = match text.strip_prefix(stringify!($R)) {
+                    Some(rest) => rest
+                        .trim_start()
+                        .strip_prefix('(')
+                        .and_then(|rest| rest.strip_suffix(')'))
+                        .ok_or(ParseRectError::Syntax)?,
+                    None => text,
+                };
+
+                if let Some((a, b)) = text.split_once("..") {
+                    let (x1, y1) = parse_pair::<$S>(a)?;
+                    let (x2, y2) = parse_pair::<$S>(b)?;
+                    return Ok(Self::try_new($V::new(x1, y1), $V::new(x2, y2))?);
+                }
+
+                let parts: Vec<&str> = text.split(',').collect();
+                let (position, size) = match parts[..] {
+                    [x, y, size] => {
+                        let (w, h) = size.split_once('x').ok_or(ParseRectError::Syntax)?;
+                        (format!("{x},{y}"), format!("{w},{h}"))
+                    }
+                    [x, y, w, h] => (format!("{x},{y}"), format!("{w},{h}")),
+                    _ => return Err(ParseRectError::Syntax),
+                };
+                let (x, y) = parse_pair::<$S>(&position)?;
+                let (w, h) = parse_pair::<$S>(&size)?;
+                let add: fn($S, $S) -> Option<$S> = $checked_add;
+                let bottom_right = add(x, w)
+                    .zip(add(y, h))
+                    .ok_or(ParseRectError::Invalid(RectError::Overflow))?;
+                Ok(Self::try_new(
+                    $V::new(x, y),
+                    $V::new(bottom_right.0, bottom_right.1),
+                )?)
+            }
+        }
+    };
+}
+
+impl_text!(Rect, Vec2, f32, |a, b| Some(a + b));
+impl_text!(DRect, DVec2, f64, |a, b| Some(a + b));
+impl_text!(IRect, IVec2, i32, |a, b| a.checked_add(b));
+impl_text!(URect, UVec2, u32, |a, b| a.checked_add(b));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_display_from_str() {
+        let rect = Rect::from_tuples((10.0, 20.0), (40.0, 60.5));
+        assert_eq!(rect.to_string(), "Rect(10, 20, 30x40.5)");
+        assert_eq!(rect.to_string().parse(), Ok(rect));
+        assert_eq!("10,20,30,40.5".parse(), Ok(rect));
+        assert_eq!(" 10, 20 .. 40, 60.5 ".parse(), Ok(rect));
+        assert!("Rect(10, 20, 30x40.5)".parse::<DRect>().is_err());
+        assert_eq!("1,2,3".parse::<Rect>(), Err(ParseRectError::Syntax));
+        assert_eq!("1,2,a,4".parse::<Rect>(), Err(ParseRectError::Number));
+        assert_eq!(
+            "1,2,-3,4".parse::<Rect>(),
+            Err(ParseRectError::Invalid(RectError::Inverted))
+        );
+
+        let irect = IRect::from_tuples((-5, -5), (5, 5));
+        assert_eq!(irect.to_string(), "IRect(-5, -5, 10x10)");
+        assert_eq!(irect.to_string().parse(), Ok(irect));
+        assert_eq!(
+            format!("0, 0, {}x1", u32::MAX).parse::<URect>(),
+            Ok(URect::from_tuples((0, 0), (u32::MAX, 1)))
+        );
+        assert_eq!(
+            format!("1, 0, {}x1", u32::MAX).parse::<URect>(),
+            Err(ParseRectError::Invalid(RectError::Overflow))
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use std::ops::{Index, IndexMut};
+
+use glam::Vec2;
+
+use crate::Rect;
+
+/// A range of rects allocated in a [`RectPool`]. It stays valid until the
+/// pool is reset.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct PoolSlice {
+    start: u32,
+    end: u32,
+}
+
+impl PoolSlice {
+    /// Returns the number of rects in the slice.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        (self.end - self.start) as usize
+    }
+
+    /// Returns `true` if the slice contains no rects.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+}
+
+/// A bump allocator for temporary rects, meant to be reset once per frame.
+///
+/// Allocations return a [`PoolSlice`] that indexes into the pool. Resetting
+/// keeps the storage, so after the first few frames computing subdivisions
+/// and clips no longer touches the global allocator.
+#[derive(Debug, Clone, Default)]
+pub struct RectPool {
+    rects: Vec<Rect>,
+}
+
+impl RectPool {
+    /// Constructs a new, empty pool.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Constructs a new, empty pool with room for `capacity` rects.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            rects: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Frees all allocations at once, keeping the storage for reuse.
+    /// Slices allocated before the reset must not be used afterwards.
+    pub fn reset(&mut self) {
+        self.rects.clear();
+    }
+
+    /// Returns the number of rects allocated since the last reset.
+    pub fn len(&self) -> usize {
+        self.rects.len()
+    }
+
+    /// Returns `true` if nothing was allocated since the last reset.
+    pub fn is_empty(&self) -> bool {
+        self.rects.is_empty()
+    }
+
+    /// Returns the number of rects the pool can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.rects.capacity()
+    }
+
+    /// Allocates the given rects as one slice.
+    pub fn alloc(&mut self, rects: impl IntoIterator<Item = Rect>) -> PoolSlice {
+        let start = self.rects.len() as u32;
+        self.rects.extend(rects);
+        PoolSlice {
+            start,
+            end: self.rects.len() as u32,
+        }
+    }
+
+    /// Allocates the parts of `rect` not covered by `other`, see
+    /// [`Rect::subtract`].
+    pub fn subtract(&mut self, rect: &Rect, other: &Rect) -> PoolSlice {
+        self.alloc(rect.subtract(other))
+    }
+
+    /// Allocates the cells of a grid over `rect`, see [`Rect::split_grid`].
+    pub fn split_grid(&mut self, rect: &Rect, columns: u32, rows: u32, gutter: Vec2) -> PoolSlice {
+        self.alloc(rect.split_grid(columns, rows, gutter))
+    }
+
+    /// Allocates the intersections of `rects` with `clip`, skipping the ones
+    /// outside of it.
+    pub fn clip(&mut self, rects: &[Rect], clip: &Rect) -> PoolSlice {
+        self.alloc(rects.iter().filter_map(|rect| rect.intersect(clip)))
+    }
+
+    /// Returns the rects in `slice`.
+    pub fn get(&self, slice: PoolSlice) -> &[Rect] {
+        &self.rects[slice.start as usize..slice.end as usize]
+    }
+
+    /// Returns the rects in `slice` mutably.
+    pub fn get_mut(&mut self, slice: PoolSlice) -> &mut [Rect] {
+        &mut self.rects[slice.start as usize..slice.end as usize]
+    }
+}
+
+impl Index<PoolSlice> for RectPool {
+    type Output = [Rect];
+
+    fn index(&self, slice: PoolSlice) -> &[Rect] {
+        self.get(slice)
+    }
+}
+
+impl IndexMut<PoolSlice> for RectPool {
+    fn index_mut(&mut self, slice: PoolSlice) -> &mut [Rect] {
+        self.get_mut(slice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_rect_pool() {
+        let mut pool = RectPool::new();
+        let screen = Rect::from_tuples((0.0, 0.0), (100.0, 100.0));
+        let window = Rect::from_tuples((25.0, 25.0), (75.0, 75.0));
+
+        let background = pool.subtract(&screen, &window);
+        let cells = pool.split_grid(&window, 2, 2, Vec2::ZERO);
+        assert_eq!(background.len(), 4);
+        assert_eq!(
+            pool[cells][3],
+            Rect::from_tuples((50.0, 50.0), (75.0, 75.0))
+        );
+
+        let labels = [
+            Rect::from_tuples((-5.0, 0.0), (5.0, 10.0)),
+            Rect::from_tuples((200.0, 0.0), (210.0, 10.0)),
+        ];
+        let clipped = pool.clip(&labels, &screen);
+        assert_eq!(pool[clipped], [Rect::from_tuples((0.0, 0.0), (5.0, 10.0))]);
+        assert_eq!(pool.len(), 9);
+
+        let capacity = pool.capacity();
+        pool.reset();
+        assert!(pool.is_empty());
+        assert_eq!(pool.capacity(), capacity);
+    }
+}
@@ -0,0 +1,148 @@
+//! 16-bit integer rects for compact storage, e.g. in GPU buffers. Requires
+//! glam 0.25 or newer.
+
+use glam::{I16Vec2, I16Vec4, IVec2, U16Vec2, U16Vec4, UVec2, Vec2};
+
+use crate::{IRect, Rect, RectError, URect};
+
+/// A signed 16-bit integer rectangle. See [`IRect`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct I16Rect {
+    pub top_left: I16Vec2,
+    pub bottom_right: I16Vec2,
+}
+
+impl_int_rect!(I16Rect, I16Vec2, I16Vec4, i16, i64, Rect, Vec2, f32, as_vec2, as_i16vec2);
+
+impl I16Rect {
+    /// Converts to a `Rect`.
+    #[inline]
+    pub fn as_rect(&self) -> Rect {
+        Rect::new(self.top_left.as_vec2(), self.bottom_right.as_vec2())
+    }
+
+    /// Converts to a `U16Rect`, or returns `None` if any coordinate is
+    /// negative.
+    #[inline]
+    pub fn to_urect(&self) -> Option<U16Rect> {
+        if self.top_left.min_element() < 0 || self.bottom_right.min_element() < 0 {
+            return None;
+        }
+        Some(U16Rect::new(
+            self.top_left.as_u16vec2(),
+            self.bottom_right.as_u16vec2(),
+        ))
+    }
+}
+
+/// An unsigned 16-bit integer rectangle. See [`URect`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct U16Rect {
+    pub top_left: U16Vec2,
+    pub bottom_right: U16Vec2,
+}
+
+impl_int_rect!(U16Rect, U16Vec2, U16Vec4, u16, u64, Rect, Vec2, f32, as_vec2, as_u16vec2);
+
+impl U16Rect {
+    /// Converts to a `Rect`.
+    #[inline]
+    pub fn as_rect(&self) -> Rect {
+        Rect::new(self.top_left.as_vec2(), self.bottom_right.as_vec2())
+    }
+}
+
+impl From<I16Rect> for IRect {
+    #[inline]
+    fn from(rect: I16Rect) -> Self {
+        IRect::new(rect.top_left.as_ivec2(), rect.bottom_right.as_ivec2())
+    }
+}
+
+impl From<U16Rect> for URect {
+    #[inline]
+    fn from(rect: U16Rect) -> Self {
+        URect::new(rect.top_left.as_uvec2(), rect.bottom_right.as_uvec2())
+    }
+}
+
+/// Fails with [`RectError::Overflow`] if a coordinate does not fit in an
+/// `i16`.
+impl TryFrom<IRect> for I16Rect {
+    type Error = RectError;
+
+    fn try_from(rect: IRect) -> Result<Self, RectError> {
+        let narrow = |v: IVec2| -> Result<I16Vec2, RectError> {
+            let x = i16::try_from(v.x).map_err(|_| RectError::Overflow)?;
+            let y = i16::try_from(v.y).map_err(|_| RectError::Overflow)?;
+            Ok(I16Vec2::new(x, y))
+        };
+        I16Rect::try_new(narrow(rect.top_left)?, narrow(rect.bottom_right)?)
+    }
+}
+
+/// Fails with [`RectError::Overflow`] if a coordinate does not fit in a
+/// `u16`.
+impl TryFrom<URect> for U16Rect {
+    type Error = RectError;
+
+    fn try_from(rect: URect) -> Result<Self, RectError> {
+        let narrow = |v: UVec2| -> Result<U16Vec2, RectError> {
+            let x = u16::try_from(v.x).map_err(|_| RectError::Overflow)?;
+            let y = u16::try_from(v.y).map_err(|_| RectError::Overflow)?;
+            Ok(U16Vec2::new(x, y))
+        };
+        U16Rect::try_new(narrow(rect.top_left)?, narrow(rect.bottom_right)?)
+    }
+}
+
+// SAFETY: see the implementations for the 32-bit rects.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for I16Rect {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for I16Rect {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for U16Rect {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for U16Rect {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_narrow_rects() {
+        let r = U16Rect::from_tuples((0, 0), (u16::MAX, u16::MAX));
+        assert_eq!(r.area(), u16::MAX as u64 * u16::MAX as u64);
+        assert_eq!(URect::from(r), URect::from_tuples((0, 0), (65535, 65535)));
+        assert_eq!(
+            U16Rect::try_from(URect::from_tuples((0, 0), (65536, 1))),
+            Err(RectError::Overflow)
+        );
+
+        let i = IRect::from_tuples((-3, -3), (3, 3));
+        assert_eq!(IRect::from(I16Rect::try_from(i).unwrap()), i);
+    }
+
+    #[test]
+    pub fn test_total_order() {
+        let mut rects = [
+            I16Rect::from_tuples((0, 1), (1, 2)),
+            I16Rect::from_tuples((-2, -1), (0, 0)),
+        ];
+        rects.sort_by(I16Rect::total_cmp);
+        assert_eq!(rects[0].top_left.y, -1);
+        let set: std::collections::BTreeSet<_> = [
+            U16Rect::from_tuples((0, 0), (1, 1)),
+            U16Rect::from_tuples((0, 0), (1, 1)),
+        ]
+        .map(crate::OrderedRect)
+        .into_iter()
+        .collect();
+        assert_eq!(set.len(), 1);
+    }
+}
@@ -0,0 +1,189 @@
+//! 64-bit integer rects for world coordinates and tile maps that overflow
+//! `i32`. Requires glam 0.24 or newer.
+
+use glam::{DVec2, I64Vec2, I64Vec4, IVec2, U64Vec2, U64Vec4, UVec2};
+
+use crate::{DRect, IRect, Rect, RectError, URect};
+
+/// A signed 64-bit integer rectangle. See [`IRect`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct I64Rect {
+    pub top_left: I64Vec2,
+    pub bottom_right: I64Vec2,
+}
+
+impl_int_rect!(I64Rect, I64Vec2, I64Vec4, i64, i128, DRect, DVec2, f64, as_dvec2, as_i64vec2);
+
+impl I64Rect {
+    /// Converts to a `DRect`. This is exact for coordinates up to 2^53 in
+    /// magnitude, which covers any world that fits in memory, and is the
+    /// conversion to use for anything but rendering.
+    #[inline]
+    pub fn as_drect(&self) -> DRect {
+        DRect::new(self.top_left.as_dvec2(), self.bottom_right.as_dvec2())
+    }
+
+    /// Converts to a `Rect`, rounding each coordinate to the nearest `f32`.
+    /// Only coordinates up to 2^24 in magnitude are exact, so large world
+    /// coordinates lose precision; prefer [`as_drect`](Self::as_drect)
+    /// unless the result is only used for rendering.
+    #[inline]
+    pub fn as_rect(&self) -> Rect {
+        Rect::new(self.top_left.as_vec2(), self.bottom_right.as_vec2())
+    }
+
+    /// Converts to a `U64Rect`, or returns `None` if any coordinate is
+    /// negative.
+    #[inline]
+    pub fn to_urect(&self) -> Option<U64Rect> {
+        if self.top_left.min_element() < 0 || self.bottom_right.min_element() < 0 {
+            return None;
+        }
+        Some(U64Rect::new(
+            self.top_left.as_u64vec2(),
+            self.bottom_right.as_u64vec2(),
+        ))
+    }
+}
+
+/// An unsigned 64-bit integer rectangle. See [`URect`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct U64Rect {
+    pub top_left: U64Vec2,
+    pub bottom_right: U64Vec2,
+}
+
+impl_int_rect!(U64Rect, U64Vec2, U64Vec4, u64, u128, DRect, DVec2, f64, as_dvec2, as_u64vec2);
+
+impl U64Rect {
+    /// Converts to a `DRect`. This is exact for coordinates up to 2^53 in
+    /// magnitude, which covers any world that fits in memory, and is the
+    /// conversion to use for anything but rendering.
+    #[inline]
+    pub fn as_drect(&self) -> DRect {
+        DRect::new(self.top_left.as_dvec2(), self.bottom_right.as_dvec2())
+    }
+
+    /// Converts to a `Rect`, rounding each coordinate to the nearest `f32`.
+    /// Only coordinates up to 2^24 in magnitude are exact, so large world
+    /// coordinates lose precision; prefer [`as_drect`](Self::as_drect)
+    /// unless the result is only used for rendering.
+    #[inline]
+    pub fn as_rect(&self) -> Rect {
+        Rect::new(self.top_left.as_vec2(), self.bottom_right.as_vec2())
+    }
+}
+
+impl From<IRect> for I64Rect {
+    #[inline]
+    fn from(rect: IRect) -> Self {
+        I64Rect::new(rect.top_left.as_i64vec2(), rect.bottom_right.as_i64vec2())
+    }
+}
+
+impl From<URect> for U64Rect {
+    #[inline]
+    fn from(rect: URect) -> Self {
+        U64Rect::new(rect.top_left.as_u64vec2(), rect.bottom_right.as_u64vec2())
+    }
+}
+
+/// Fails with [`RectError::Overflow`] if a coordinate does not fit in an
+/// `i32`.
+impl TryFrom<I64Rect> for IRect {
+    type Error = RectError;
+
+    fn try_from(rect: I64Rect) -> Result<Self, RectError> {
+        let narrow = |v: I64Vec2| -> Result<IVec2, RectError> {
+            let x = i32::try_from(v.x).map_err(|_| RectError::Overflow)?;
+            let y = i32::try_from(v.y).map_err(|_| RectError::Overflow)?;
+            Ok(IVec2::new(x, y))
+        };
+        IRect::try_new(narrow(rect.top_left)?, narrow(rect.bottom_right)?)
+    }
+}
+
+/// Fails with [`RectError::Overflow`] if a coordinate does not fit in a
+/// `u32`.
+impl TryFrom<U64Rect> for URect {
+    type Error = RectError;
+
+    fn try_from(rect: U64Rect) -> Result<Self, RectError> {
+        let narrow = |v: U64Vec2| -> Result<UVec2, RectError> {
+            let x = u32::try_from(v.x).map_err(|_| RectError::Overflow)?;
+            let y = u32::try_from(v.y).map_err(|_| RectError::Overflow)?;
+            Ok(UVec2::new(x, y))
+        };
+        URect::try_new(narrow(rect.top_left)?, narrow(rect.bottom_right)?)
+    }
+}
+
+// SAFETY: see the implementations for the 32-bit rects.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for I64Rect {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for I64Rect {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for U64Rect {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for U64Rect {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_wide_rects() {
+        let r = I64Rect::from_tuples((-5_000_000_000, 0), (5_000_000_000, 10));
+        assert_eq!(r.width(), 10_000_000_000);
+        assert_eq!(r.area(), 100_000_000_000);
+        assert_eq!(IRect::try_from(r), Err(RectError::Overflow));
+        assert_eq!(r.aspect_ratio(), 1_000_000_000.0f64);
+        let odd = (1 << 40) + 1;
+        assert_eq!(
+            I64Rect::from_tuples((0, 0), (odd, 1))
+                .as_drect()
+                .bottom_right
+                .x,
+            odd as f64
+        );
+        assert_ne!(
+            U64Rect::from_tuples((0, 0), (odd as u64, 1))
+                .as_rect()
+                .bottom_right
+                .x as f64,
+            odd as f64
+        );
+        let diagonal: f64 = I64Rect::from_tuples((0, 0), (3 << 40, 4 << 40)).diagonal_length();
+        assert_eq!(diagonal, (5u64 << 40) as f64);
+
+        let small = IRect::from_tuples((-1, -2), (3, 4));
+        assert_eq!(IRect::try_from(I64Rect::from(small)), Ok(small));
+        assert_eq!(
+            U64Rect::from_tuples((0, 0), (u64::MAX, 1)).to_vec4(),
+            U64Vec4::new(0, 0, u64::MAX, 1)
+        );
+    }
+
+    #[test]
+    pub fn test_total_order() {
+        let mut rects = [
+            I64Rect::from_tuples((0, 1), (1, 2)),
+            I64Rect::from_tuples((-2, -1), (0, 0)),
+        ];
+        rects.sort_by(I64Rect::total_cmp);
+        assert_eq!(rects[0].top_left.y, -1);
+        let set: std::collections::BTreeSet<_> = [
+            U64Rect::from_tuples((0, 0), (1, 1)),
+            U64Rect::from_tuples((0, 0), (1, 1)),
+        ]
+        .map(crate::OrderedRect)
+        .into_iter()
+        .collect();
+        assert_eq!(set.len(), 1);
+    }
+}
@@ -0,0 +1,355 @@
+use glam::Vec2;
+
+use crate::Rect;
+
+/// An area made up of disjoint rects, supporting boolean operations with
+/// rects and other regions. This is the classic damage region of windowing
+/// systems and dirty-rect renderers.
+///
+/// The rects always have positive area and never overlap, but the same area
+/// may be split into rects in different ways depending on the order of
+/// operations. Use [`Region::coalesce`] to merge neighbouring rects.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Region {
+    rects: Vec<Rect>,
+}
+
+impl Region {
+    /// Constructs a new, empty region.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if the region covers no area.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.rects.is_empty()
+    }
+
+    /// Returns the disjoint rects making up the region.
+    #[inline]
+    pub fn rects(&self) -> &[Rect] {
+        &self.rects
+    }
+
+    /// Returns an iterator over the disjoint rects making up the region.
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, Rect> {
+        self.rects.iter()
+    }
+
+    /// Empties the region.
+    pub fn clear(&mut self) {
+        self.rects.clear();
+    }
+
+    /// Returns the total area covered by the region.
+    pub fn area(&self) -> f32 {
+        self.rects.iter().map(Rect::area).sum()
+    }
+
+    /// Returns the smallest rect containing the whole region, or `None` if
+    /// it is empty.
+    pub fn bounds(&self) -> Option<Rect> {
+        self.rects.iter().copied().reduce(|a, b| a.union(&b))
+    }
+
+    /// Returns `true` if `point` is inside the region, using the same
+    /// convention as [`Rect::contains`].
+    pub fn contains(&self, point: Vec2) -> bool {
+        self.rects.iter().any(|rect| rect.contains(point))
+    }
+
+    /// Returns `true` if the region overlaps `rect`.
+    pub fn intersects_rect(&self, rect: &Rect) -> bool {
+        self.rects.iter().any(|r| r.intersects(rect))
+    }
+
+    /// Adds `rect` to the region.
+    pub fn union_rect(&mut self, rect: &Rect) {
+        if !rect.is_positive_area() {
+            return;
+        }
+        let mut pieces = vec![*rect];
+        for existing in &self.rects {
+            if !pieces.iter().any(|piece| piece.intersects(existing)) {
+                continue;
+            }
+            pieces = pieces
+                .iter()
+                .flat_map(|piece| piece.subtract(existing))
+                .collect();
+            if pieces.is_empty() {
+                return;
+            }
+        }
+        self.rects.extend(pieces);
+    }
+
+    /// Removes `rect` from the region.
+    pub fn subtract_rect(&mut self, rect: &Rect) {
+        if !self.intersects_rect(rect) {
+            return;
+        }
+        self.rects = self
+            .rects
+            .iter()
+            .flat_map(|existing| existing.subtract(rect))
+            .collect();
+    }
+
+    /// Restricts the region to the part inside `rect`.
+    pub fn intersect_rect(&mut self, rect: &Rect) {
+        self.rects
+            .retain_mut(|existing| match existing.intersect(rect) {
+                Some(overlap) => {
+                    *existing = overlap;
+                    true
+                }
+                None => false,
+            });
+    }
+
+    /// Adds the area of `other` to the region.
+    pub fn union(&mut self, other: &Region) {
+        for rect in &other.rects {
+            self.union_rect(rect);
+        }
+    }
+
+    /// Removes the area of `other` from the region.
+    pub fn subtract(&mut self, other: &Region) {
+        for rect in &other.rects {
+            self.subtract_rect(rect);
+        }
+    }
+
+    /// Restricts the region to the area it shares with `other`.
+    pub fn intersect(&mut self, other: &Region) {
+        self.rects = self
+            .rects
+            .iter()
+            .flat_map(|a| other.rects.iter().filter_map(move |b| a.intersect(b)))
+            .collect();
+    }
+
+    /// Returns the area gained and lost going from this region to `other`,
+    /// e.g. the parts of a screen to repaint and to clear between frames.
+    pub fn diff(&self, other: &Region) -> RegionDiff {
+        let mut added = other.clone();
+        added.subtract(self);
+        let mut removed = self.clone();
+        removed.subtract(other);
+        RegionDiff { added, removed }
+    }
+
+    /// Grows the region by `radius` in every direction, so that it covers
+    /// every point within `radius` of it along each axis. This is the
+    /// Minkowski sum with a square, useful to pad damage for filter bleed.
+    /// A non-positive `radius` leaves the region unchanged.
+    pub fn dilate(&mut self, radius: f32) {
+        if radius <= 0.0 {
+            return;
+        }
+        let rects = std::mem::take(&mut self.rects);
+        self.extend(rects.iter().map(|rect| rect.inflate(radius)));
+        self.coalesce();
+    }
+
+    /// Shrinks the region by `radius`, keeping only the points whose
+    /// surrounding square of half size `radius` lies entirely inside it. A
+    /// non-positive `radius` leaves the region unchanged.
+    pub fn erode(&mut self, radius: f32) {
+        let Some(bounds) = self.bounds() else {
+            return;
+        };
+        if radius <= 0.0 {
+            return;
+        }
+        let mut outside = Region::from(bounds.inflate(radius));
+        outside.subtract(self);
+        outside.dilate(radius);
+        *self = Region::from(bounds);
+        self.subtract(&outside);
+        self.coalesce();
+    }
+
+    /// Merges pairs of rects that share a whole edge into one, reducing the
+    /// number of rects without changing the covered area.
+    pub fn coalesce(&mut self) {
+        let mut merged = true;
+        while merged {
+            merged = false;
+            'outer: for i in 0..self.rects.len() {
+                for j in i + 1..self.rects.len() {
+                    if let Some(joined) = join(&self.rects[i], &self.rects[j]) {
+                        self.rects[i] = joined;
+                        self.rects.swap_remove(j);
+                        merged = true;
+                        break 'outer;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The difference between two regions, as returned by [`Region::diff`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RegionDiff {
+    /// The area covered by the new region but not the old one.
+    pub added: Region,
+    /// The area covered by the old region but not the new one.
+    pub removed: Region,
+}
+
+/// Grows the area covered by `rects`, which may overlap, by `radius` as in
+/// [`Region::dilate`], returning it as disjoint rects. This pads collision
+/// shapes or damage made of several rects without double counting overlaps.
+pub fn dilate_rects(rects: &[Rect], radius: f32) -> Vec<Rect> {
+    let mut region: Region = rects.iter().copied().collect();
+    region.dilate(radius);
+    region.rects
+}
+
+/// Shrinks the area covered by `rects`, which may overlap, by `radius` as in
+/// [`Region::erode`], returning it as disjoint rects. Unlike deflating each
+/// rect on its own, this keeps the parts where the rects cover each other's
+/// borders.
+pub fn erode_rects(rects: &[Rect], radius: f32) -> Vec<Rect> {
+    let mut region: Region = rects.iter().copied().collect();
+    region.erode(radius);
+    region.rects
+}
+
+/// Returns the union of two rects if it is exactly covered by them.
+fn join(a: &Rect, b: &Rect) -> Option<Rect> {
+    let same_columns = a.top_left.x == b.top_left.x && a.bottom_right.x == b.bottom_right.x;
+    let same_rows = a.top_left.y == b.top_left.y && a.bottom_right.y == b.bottom_right.y;
+    let stacked = a.bottom_right.y == b.top_left.y || b.bottom_right.y == a.top_left.y;
+    let side_by_side = a.bottom_right.x == b.top_left.x || b.bottom_right.x == a.top_left.x;
+    if (same_columns && stacked) || (same_rows && side_by_side) {
+        Some(a.union(b))
+    } else {
+        None
+    }
+}
+
+impl From<Rect> for Region {
+    fn from(rect: Rect) -> Self {
+        let mut region = Region::new();
+        region.union_rect(&rect);
+        region
+    }
+}
+
+impl FromIterator<Rect> for Region {
+    fn from_iter<I: IntoIterator<Item = Rect>>(iter: I) -> Self {
+        let mut region = Region::new();
+        region.extend(iter);
+        region
+    }
+}
+
+impl Extend<Rect> for Region {
+    fn extend<I: IntoIterator<Item = Rect>>(&mut self, iter: I) {
+        for rect in iter {
+            self.union_rect(&rect);
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Region {
+    type Item = &'a Rect;
+    type IntoIter = std::slice::Iter<'a, Rect>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.rects.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_region() {
+        let a = Rect::from_tuples((0.0, 0.0), (10.0, 10.0));
+        let b = Rect::from_tuples((5.0, 5.0), (15.0, 15.0));
+        let mut region: Region = [a, b].into_iter().collect();
+        assert_eq!(region.area(), 175.0);
+        assert!(region.contains(Vec2::new(12.0, 12.0)));
+        assert!(!region.contains(Vec2::new(12.0, 2.0)));
+        assert_eq!(region.bounds(), Some(a.union(&b)));
+
+        region.subtract_rect(&Rect::from_tuples((0.0, 0.0), (15.0, 5.0)));
+        assert_eq!(region.area(), 125.0);
+
+        let mut other = Region::from(Rect::from_tuples((0.0, 8.0), (20.0, 20.0)));
+        other.intersect(&region);
+        assert_eq!(other.area(), 80.0);
+
+        region.subtract(&other);
+        assert_eq!(region.area(), 125.0 - 80.0);
+
+        let mut strips: Region = (0..4)
+            .map(|i| Rect::from_tuples((i as f32, 0.0), (i as f32 + 1.0, 2.0)))
+            .collect();
+        strips.coalesce();
+        assert_eq!(strips.rects(), [Rect::from_tuples((0.0, 0.0), (4.0, 2.0))]);
+    }
+
+    #[test]
+    pub fn test_dilate_erode() {
+        let mut region: Region = [
+            Rect::from_tuples((0.0, 0.0), (10.0, 10.0)),
+            Rect::from_tuples((10.0, 0.0), (20.0, 4.0)),
+        ]
+        .into_iter()
+        .collect();
+        let original = region.clone();
+
+        region.dilate(1.0);
+        assert_eq!(
+            region.bounds(),
+            Some(Rect::from_tuples((-1.0, -1.0), (21.0, 11.0)))
+        );
+        assert_eq!(region.area(), 12.0 * 12.0 + 12.0 * 6.0 - 2.0 * 6.0);
+
+        region.erode(1.0);
+        assert_eq!(region.area(), original.area());
+        region.subtract(&original);
+        assert!(region.is_empty());
+
+        let halves = [
+            Rect::from_tuples((0.0, 0.0), (6.0, 4.0)),
+            Rect::from_tuples((4.0, 0.0), (10.0, 4.0)),
+        ];
+        assert_eq!(
+            erode_rects(&halves, 1.0),
+            [Rect::from_tuples((1.0, 1.0), (9.0, 3.0))]
+        );
+        let padded = dilate_rects(&halves, 1.0);
+        assert_eq!(padded, [Rect::from_tuples((-1.0, -1.0), (11.0, 5.0))]);
+
+        let mut thin = original.clone();
+        thin.erode(2.5);
+        assert_eq!(thin.rects(), [Rect::from_tuples((2.5, 2.5), (7.5, 7.5))]);
+    }
+
+    #[test]
+    pub fn test_diff() {
+        let old = Region::from(Rect::from_tuples((0.0, 0.0), (10.0, 10.0)));
+        let new = Region::from(Rect::from_tuples((5.0, 0.0), (15.0, 10.0)));
+        let diff = old.diff(&new);
+        assert_eq!(diff.added.area(), 50.0);
+        assert_eq!(diff.removed.area(), 50.0);
+        assert!(diff.added.contains(Vec2::new(12.0, 5.0)));
+        assert!(diff.removed.contains(Vec2::new(2.0, 5.0)));
+        assert!(!diff.added.contains(Vec2::new(7.0, 5.0)));
+        assert!(!diff.removed.contains(Vec2::new(7.0, 5.0)));
+
+        let same = old.diff(&old);
+        assert!(same.added.is_empty() && same.removed.is_empty());
+    }
+}
@@ -0,0 +1,15 @@
+/// How two rectangles are positioned relative to each other, as returned by
+/// `relation` on the rect types.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum RectRelation {
+    /// The rectangles have no common area, though they may touch.
+    Disjoint,
+    /// The rectangles have a common area, but neither contains the other.
+    Overlaps,
+    /// The first rectangle fully contains the second.
+    Contains,
+    /// The first rectangle is fully contained in the second.
+    Within,
+    /// The rectangles are identical.
+    Equal,
+}
@@ -0,0 +1,26 @@
+/// How float coordinates are rounded when converting to an integer rect.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum RoundMode {
+    /// Rounds both vertices down.
+    Floor,
+    /// Rounds both vertices up.
+    Ceil,
+    /// Rounds both vertices to the nearest integer, with halves rounded away
+    /// from zero.
+    Round,
+    /// Rounds the top left vertex down and the bottom right vertex up, giving
+    /// the smallest integer rect containing the float rect.
+    Expand,
+}
+
+/// Which cells of a grid count as covered by a float rect.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum CoverMode {
+    /// Every cell the rect overlaps at all. This is the conservative choice
+    /// for rasterization and damage tracking.
+    Touched,
+    /// Cells of which the rect covers more than half along each axis.
+    Majority,
+    /// Only the cells lying entirely inside the rect.
+    Full,
+}
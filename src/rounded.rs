@@ -0,0 +1,181 @@
+use glam::Vec2;
+
+use crate::{Corner, Edge, Rect};
+
+/// A rectangle with rounded corners, for hit-testing and rendering UI
+/// elements. The radius of each corner is given in the same order as
+/// [`Rect::corners`]: top left, top right, bottom right, bottom left.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct RoundedRect {
+    pub rect: Rect,
+    pub radii: [f32; 4],
+}
+
+impl RoundedRect {
+    /// Constructs a new `RoundedRect`. The radii must be non-negative.
+    #[inline]
+    pub const fn new(rect: Rect, radii: [f32; 4]) -> Self {
+        Self { rect, radii }
+    }
+
+    /// Constructs a new `RoundedRect` with the same radius on every corner.
+    #[inline]
+    pub const fn uniform(rect: Rect, radius: f32) -> Self {
+        Self::new(rect, [radius; 4])
+    }
+
+    /// Returns the smallest rect containing the rounded rect, which is the
+    /// rect whose corners were rounded.
+    #[inline]
+    pub fn bounding_rect(&self) -> Rect {
+        self.rect
+    }
+
+    /// Returns the corner of the quadrant containing `point`.
+    fn nearest_corner(&self, point: Vec2) -> Corner {
+        let center = self.rect.center();
+        match (point.x < center.x, point.y < center.y) {
+            (true, true) => Corner::TopLeft,
+            (false, true) => Corner::TopRight,
+            (false, false) => Corner::BottomRight,
+            (true, false) => Corner::BottomLeft,
+        }
+    }
+
+    /// Returns the radius of the corner nearest to `point`, limited to half
+    /// the shorter side so that the corners never overlap.
+    fn radius_towards(&self, point: Vec2) -> f32 {
+        self.radii[self.nearest_corner(point).index()].min(self.rect.shortest_side() * 0.5)
+    }
+
+    /// Returns the signed distance from `point` to the border: positive
+    /// outside, negative inside, and zero on the border.
+    pub fn signed_distance(&self, point: Vec2) -> f32 {
+        let radius = self.radius_towards(point);
+        let offset = (point - self.rect.center()).abs() - self.rect.size() * 0.5 + radius;
+        offset.max(Vec2::ZERO).length() + offset.x.max(offset.y).min(0.0) - radius
+    }
+
+    /// Returns `true` if `point` is inside the rounded rect or on its
+    /// border.
+    #[inline]
+    pub fn contains(&self, point: Vec2) -> bool {
+        self.signed_distance(point) <= 0.0
+    }
+
+    /// Returns `true` if `point` is inside the rounded rect or within
+    /// `border_width` outside of it, as when the shape is drawn with an outer
+    /// stroke of that width.
+    #[inline]
+    pub fn contains_with_border(&self, point: Vec2, border_width: f32) -> bool {
+        self.signed_distance(point) <= border_width
+    }
+
+    /// Returns the part of the border within `tolerance` of `point`, or `None`
+    /// if the point is further away from it. Points near a rounded corner, or
+    /// near both edges of a sharp one, hit the corner; others hit the nearest
+    /// edge. This picks the resize handle under the cursor of a window.
+    pub fn hit_edge(&self, point: Vec2, tolerance: f32) -> Option<BorderHit> {
+        if self.signed_distance(point).abs() > tolerance {
+            return None;
+        }
+        let corner = self.nearest_corner(point);
+        let zone = self.radius_towards(point).max(tolerance);
+        let offset = (point - self.rect.corner(corner)).abs();
+        if offset.x <= zone && offset.y <= zone {
+            return Some(BorderHit::Corner(corner));
+        }
+        let Rect {
+            top_left,
+            bottom_right,
+        } = self.rect;
+        let distances = [
+            (point.x - top_left.x).abs(),
+            (point.y - top_left.y).abs(),
+            (point.x - bottom_right.x).abs(),
+            (point.y - bottom_right.y).abs(),
+        ];
+        Edge::ALL
+            .into_iter()
+            .zip(distances)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(edge, _)| BorderHit::Edge(edge))
+    }
+}
+
+/// The part of the border of a [`RoundedRect`] hit by
+/// [`RoundedRect::hit_edge`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum BorderHit {
+    Edge(Edge),
+    Corner(Corner),
+}
+
+impl From<Rect> for RoundedRect {
+    /// Converts a `Rect` to a `RoundedRect` with sharp corners.
+    #[inline]
+    fn from(rect: Rect) -> Self {
+        Self::uniform(rect, 0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_rounded_rect() {
+        let rect = Rect::from_tuples((0.0, 0.0), (20.0, 10.0));
+        let rounded = RoundedRect::new(rect, [4.0, 0.0, 2.0, 100.0]);
+
+        // Just inside the corner of the bounding rect, but outside the arc.
+        assert!(!rounded.contains(Vec2::new(0.5, 0.5)));
+        assert!(rounded.contains(Vec2::new(19.5, 0.5)));
+        assert_eq!(rounded.signed_distance(Vec2::new(4.0, -3.0)), 3.0);
+        assert_eq!(rounded.signed_distance(Vec2::new(10.0, 5.0)), -5.0);
+        assert_eq!(rounded.signed_distance(Vec2::new(30.0, 5.0)), 10.0);
+        // On the bottom right arc, which is centered on (18, 8).
+        let on_arc = Vec2::new(18.0, 8.0) + Vec2::splat(2.0 / 2f32.sqrt());
+        assert!(rounded.signed_distance(on_arc).abs() < 1e-5);
+
+        // The bottom left radius is limited to half the height.
+        assert!(rounded.contains(Vec2::new(5.0, 9.9)));
+        assert!(!rounded.contains(Vec2::new(0.5, 9.5)));
+
+        assert_eq!(
+            RoundedRect::from(rect).signed_distance(Vec2::new(0.0, 0.0)),
+            0.0
+        );
+        assert_eq!(rounded.bounding_rect(), rect);
+    }
+
+    #[test]
+    pub fn test_border_hits() {
+        let rect = Rect::from_tuples((0.0, 0.0), (20.0, 10.0));
+        let rounded = RoundedRect::new(rect, [4.0, 0.0, 0.0, 0.0]);
+
+        assert!(!rounded.contains(Vec2::new(21.0, 5.0)));
+        assert!(rounded.contains_with_border(Vec2::new(21.0, 5.0), 2.0));
+        assert!(!rounded.contains_with_border(Vec2::new(23.0, 5.0), 2.0));
+        assert!(!rounded.contains_with_border(Vec2::new(0.5, 0.5), 0.5));
+
+        let hit = |x, y| rounded.hit_edge(Vec2::new(x, y), 1.0);
+        assert_eq!(hit(10.0, 5.0), None);
+        assert_eq!(hit(10.0, -0.5), Some(BorderHit::Edge(Edge::Top)));
+        assert_eq!(hit(20.5, 5.0), Some(BorderHit::Edge(Edge::Right)));
+        assert_eq!(hit(10.0, 9.5), Some(BorderHit::Edge(Edge::Bottom)));
+        assert_eq!(hit(0.0, 6.0), Some(BorderHit::Edge(Edge::Left)));
+        // On the top left arc, which is centered on (4, 4).
+        let on_arc = Vec2::splat(4.0 - 4.0 / 2f32.sqrt());
+        assert_eq!(
+            hit(on_arc.x, on_arc.y),
+            Some(BorderHit::Corner(Corner::TopLeft))
+        );
+        // Sharp corners are hit within the tolerance of both edges.
+        assert_eq!(hit(20.5, -0.5), Some(BorderHit::Corner(Corner::TopRight)));
+        assert_eq!(hit(19.5, 9.5), Some(BorderHit::Corner(Corner::BottomRight)));
+        assert_eq!(hit(18.5, 9.5), Some(BorderHit::Edge(Edge::Bottom)));
+    }
+}
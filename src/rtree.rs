@@ -0,0 +1,203 @@
+use core::ops::Range;
+
+use glam::Vec2;
+
+use crate::Rect;
+
+/// The maximum number of children of a node.
+const NODE_CAPACITY: usize = 16;
+
+#[derive(Debug, Clone)]
+struct Node {
+    bounds: Rect,
+    /// The range of `items` for leaves, or of `nodes` otherwise.
+    children: Range<usize>,
+    is_leaf: bool,
+}
+
+/// A static R-tree over rects with values, bulk loaded with the
+/// Sort-Tile-Recursive algorithm.
+///
+/// Building the tree once takes `O(n log n)` time, after which queries only
+/// visit the nodes whose bounds match. Unlike [`SpatialGrid`](crate::SpatialGrid),
+/// it adapts to unevenly distributed rects of any size, but it cannot be
+/// changed after building.
+#[derive(Debug, Clone)]
+pub struct RTree<T> {
+    items: Vec<(Rect, T)>,
+    nodes: Vec<Node>,
+}
+
+impl<T> RTree<T> {
+    /// Builds a tree containing `items`.
+    pub fn new(items: impl IntoIterator<Item = (Rect, T)>) -> Self {
+        let mut items: Vec<(Rect, T)> = items.into_iter().collect();
+        let mut nodes = Vec::new();
+        if items.is_empty() {
+            return Self { items, nodes };
+        }
+
+        sort_tiles(&mut items, |(rect, _)| *rect);
+        let mut level: Vec<Node> = pack(&items, |(rect, _)| *rect, true, 0);
+        loop {
+            sort_tiles(&mut level, |node| node.bounds);
+            let start = nodes.len();
+            nodes.append(&mut level);
+            if nodes.len() - start == 1 {
+                break;
+            }
+            level = pack(&nodes[start..], |node| node.bounds, false, start);
+        }
+        Self { items, nodes }
+    }
+
+    /// Returns the number of items in the tree.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if the tree contains no items.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the smallest rect containing all items, or `None` if there are
+    /// none.
+    #[inline]
+    pub fn bounds(&self) -> Option<Rect> {
+        self.nodes.last().map(|root| root.bounds)
+    }
+
+    /// Returns the bounds of every node, leaves first and the root last.
+    pub(crate) fn node_bounds(&self) -> impl Iterator<Item = Rect> + '_ {
+        self.nodes.iter().map(|node| node.bounds)
+    }
+
+    /// Returns the items overlapping `rect` with positive area, as by
+    /// [`Rect::intersects`].
+    pub fn query_intersecting<'a>(
+        &'a self,
+        rect: &'a Rect,
+    ) -> impl Iterator<Item = (&'a Rect, &'a T)> + 'a {
+        self.query(move |bounds| bounds.intersects(rect))
+    }
+
+    /// Returns the items containing `point`, as by [`Rect::contains`].
+    pub fn query_containing(&self, point: Vec2) -> impl Iterator<Item = (&Rect, &T)> + '_ {
+        self.query(move |bounds| bounds.contains(point))
+    }
+
+    /// Walks the nodes whose bounds match `matches`, yielding the matching
+    /// items.
+    fn query<'a>(
+        &'a self,
+        matches: impl Fn(&Rect) -> bool + 'a,
+    ) -> impl Iterator<Item = (&'a Rect, &'a T)> + 'a {
+        let mut stack: Vec<usize> = match self.nodes.last() {
+            Some(root) if matches(&root.bounds) => vec![self.nodes.len() - 1],
+            _ => Vec::new(),
+        };
+        let mut leaf = 0..0;
+        core::iter::from_fn(move || loop {
+            if let Some(index) = leaf.next() {
+                let (rect, value) = &self.items[index];
+                if matches(rect) {
+                    return Some((rect, value));
+                }
+                continue;
+            }
+            let node = &self.nodes[stack.pop()?];
+            if node.is_leaf {
+                leaf = node.children.clone();
+            } else {
+                stack.extend(
+                    node.children
+                        .clone()
+                        .filter(|&child| matches(&self.nodes[child].bounds)),
+                );
+            }
+        })
+    }
+}
+
+/// Orders `entries` so that consecutive runs of `NODE_CAPACITY` are compact:
+/// sorted by center x into vertical slices, each sorted by center y.
+fn sort_tiles<E>(entries: &mut [E], bounds: impl Fn(&E) -> Rect) {
+    let key = |entry: &E, axis: usize| {
+        let rect = bounds(entry);
+        rect.top_left[axis] + rect.bottom_right[axis]
+    };
+    entries.sort_by(|a, b| key(a, 0).total_cmp(&key(b, 0)));
+    let leaves = entries.len().div_ceil(NODE_CAPACITY);
+    let slices = (leaves as f64).sqrt().ceil() as usize;
+    let slice_len = leaves.div_ceil(slices) * NODE_CAPACITY;
+    for slice in entries.chunks_mut(slice_len) {
+        slice.sort_by(|a, b| key(a, 1).total_cmp(&key(b, 1)));
+    }
+}
+
+/// Groups consecutive runs of `NODE_CAPACITY` entries into parent nodes.
+/// `offset` is the index of the first entry in its storage.
+fn pack<E>(entries: &[E], bounds: impl Fn(&E) -> Rect, is_leaf: bool, offset: usize) -> Vec<Node> {
+    entries
+        .chunks(NODE_CAPACITY)
+        .enumerate()
+        .map(|(i, chunk)| {
+            let start = offset + i * NODE_CAPACITY;
+            Node {
+                bounds: chunk
+                    .iter()
+                    .map(&bounds)
+                    .reduce(|a, b| a.union(&b))
+                    .unwrap(),
+                children: start..start + chunk.len(),
+                is_leaf,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_rtree() {
+        let rects: Vec<Rect> = (0..1000)
+            .map(|i| {
+                let x = ((i * 37) % 101) as f32 * 3.0;
+                let y = ((i * 53) % 97) as f32 * 3.0;
+                Rect::from_position_size(Vec2::new(x, y), Vec2::new(1.0 + (i % 5) as f32, 2.0))
+            })
+            .collect();
+        let tree = RTree::new(rects.iter().copied().zip(0..));
+        assert_eq!(tree.len(), 1000);
+        assert_eq!(
+            tree.bounds(),
+            rects.iter().copied().reduce(|a, b| a.union(&b))
+        );
+
+        let query = Rect::from_tuples((50.0, 40.0), (90.0, 70.0));
+        let mut found: Vec<usize> = tree.query_intersecting(&query).map(|(_, &i)| i).collect();
+        found.sort_unstable();
+        let expected: Vec<usize> = (0..rects.len())
+            .filter(|&i| rects[i].intersects(&query))
+            .collect();
+        assert!(!expected.is_empty());
+        assert_eq!(found, expected);
+
+        let point = rects[123].top_left;
+        let mut found: Vec<usize> = tree.query_containing(point).map(|(_, &i)| i).collect();
+        found.sort_unstable();
+        let expected: Vec<usize> = (0..rects.len())
+            .filter(|&i| rects[i].contains(point))
+            .collect();
+        assert_eq!(found, expected);
+
+        let empty = RTree::<()>::new([]);
+        assert!(empty.is_empty() && empty.bounds().is_none());
+        assert_eq!(empty.query_intersecting(&query).count(), 0);
+    }
+}
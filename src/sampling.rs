@@ -0,0 +1,169 @@
+use glam::Vec2;
+use rand::distributions::Distribution;
+use rand::Rng;
+
+use crate::Rect;
+
+/// Picks the index of one of `rects` with probability proportional to its
+/// area. Returns `None` if the rects have no area in total.
+pub fn pick_weighted_by_area<R: Rng + ?Sized>(rects: &[Rect], rng: &mut R) -> Option<usize> {
+    let total: f32 = rects.iter().map(|rect| rect.area().max(0.0)).sum();
+    if total <= 0.0 {
+        return None;
+    }
+    let mut remaining = rng.gen::<f32>() * total;
+    let mut last_positive = None;
+    for (index, rect) in rects.iter().enumerate() {
+        let area = rect.area().max(0.0);
+        if area > 0.0 {
+            if remaining < area {
+                return Some(index);
+            }
+            remaining -= area;
+            last_positive = Some(index);
+        }
+    }
+    // Rounding in the running sum can leave a sliver past the last rect.
+    last_positive
+}
+
+/// Samples a point uniformly over the area covered by `rects`. Overlapping
+/// parts are not sampled more often than the rest. Returns `None` if the
+/// rects have no area in total.
+pub fn sample_point_in_union<R: Rng + ?Sized>(rects: &[Rect], rng: &mut R) -> Option<Vec2> {
+    loop {
+        let rect = &rects[pick_weighted_by_area(rects, rng)?];
+        let point = rect.sample_point(rng);
+        // A point covered by `k` rects is proposed `k` times as often, so
+        // accepting it with probability `1 / k` makes the result uniform.
+        let coverage = rects.iter().filter(|rect| rect.contains(point)).count();
+        if rng.gen_range(0..coverage.max(1)) == 0 {
+            return Some(point);
+        }
+    }
+}
+
+impl Rect {
+    /// Samples a point uniformly inside the rectangle. Like
+    /// [`Rect::contains`], this includes the top and left edges but not the
+    /// bottom and right ones, even where rounding would otherwise land on
+    /// them. An axis with zero extent always yields its single coordinate.
+    pub fn sample_point<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        let mut axis = |min: f32, max: f32| {
+            if min < max {
+                rng.gen_range(min..max)
+            } else {
+                min
+            }
+        };
+        Vec2::new(
+            axis(self.top_left.x, self.bottom_right.x),
+            axis(self.top_left.y, self.bottom_right.y),
+        )
+    }
+
+    /// Samples the position of a rect of `size` uniformly among the
+    /// positions where it lies entirely inside this rectangle. Returns
+    /// `None` if it does not fit.
+    pub fn sample_subrect<R: Rng + ?Sized>(&self, size: Vec2, rng: &mut R) -> Option<Rect> {
+        let free = self.size() - size;
+        if free.cmplt(Vec2::ZERO).any() {
+            return None;
+        }
+        let top_left = self.top_left + free * Vec2::new(rng.gen(), rng.gen());
+        Some(Rect::from_position_size(top_left, size))
+    }
+}
+
+/// Samples points uniformly inside the rect, see [`Rect::sample_point`].
+impl Distribution<Vec2> for Rect {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        self.sample_point(rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    pub fn test_pick_weighted_by_area() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let rects = [
+            Rect::from_tuples((0.0, 0.0), (1.0, 1.0)),
+            Rect::from_tuples((0.0, 0.0), (0.0, 5.0)),
+            Rect::from_tuples((0.0, 0.0), (3.0, 1.0)),
+        ];
+
+        let mut counts = [0; 3];
+        for _ in 0..4000 {
+            counts[pick_weighted_by_area(&rects, &mut rng).unwrap()] += 1;
+        }
+        assert_eq!(counts[1], 0);
+        assert!((800..1200).contains(&counts[0]), "{counts:?}");
+
+        assert_eq!(pick_weighted_by_area(&rects[1..2], &mut rng), None);
+        assert_eq!(pick_weighted_by_area(&[], &mut rng), None);
+    }
+
+    #[test]
+    pub fn test_sample_point_in_union() {
+        let mut rng = StdRng::seed_from_u64(7);
+        // The second rect is entirely covered by the first, so half of the
+        // samples should land in it, not two thirds.
+        let rects = [
+            Rect::from_tuples((0.0, 0.0), (2.0, 1.0)),
+            Rect::from_tuples((0.0, 0.0), (1.0, 1.0)),
+        ];
+
+        let mut inner = 0;
+        for _ in 0..4000 {
+            let point = sample_point_in_union(&rects, &mut rng).unwrap();
+            assert!(rects[0].contains(point));
+            if rects[1].contains(point) {
+                inner += 1;
+            }
+        }
+        assert!((1800..2200).contains(&inner), "{inner}");
+    }
+
+    #[test]
+    pub fn test_sample_rect() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let rect = Rect::from_tuples((-4.0, 2.0), (4.0, 6.0));
+        let mut left = 0;
+        for point in rect.sample_iter(&mut rng).take(4000) {
+            assert!(rect.contains(point));
+            if point.x < 0.0 {
+                left += 1;
+            }
+        }
+        assert!((1800..2200).contains(&left), "{left}");
+
+        let size = Vec2::new(6.0, 4.0);
+        for _ in 0..100 {
+            let subrect = rect.sample_subrect(size, &mut rng).unwrap();
+            assert_eq!(subrect.size(), size);
+            assert_eq!(subrect.union(&rect), rect);
+        }
+        assert_eq!(rect.sample_subrect(Vec2::new(9.0, 1.0), &mut rng), None);
+    }
+
+    #[test]
+    pub fn test_sample_point_excludes_far_edges() {
+        let mut rng = StdRng::seed_from_u64(7);
+        // Coordinates are 1/16 apart here, so scaling a random fraction of
+        // the size often rounds up to the right edge.
+        let rect = Rect::from_tuples((1e6, 1e6), (1e6 + 0.125, 1e6 + 0.125));
+        for _ in 0..1000 {
+            let sample = rect.sample_point(&mut rng);
+            assert!(rect.contains(sample), "{sample}");
+        }
+
+        let line = Rect::from_tuples((1.0, 2.0), (1.0, 5.0));
+        assert_eq!(line.sample_point(&mut rng).x, 1.0);
+    }
+}
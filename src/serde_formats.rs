@@ -0,0 +1,281 @@
+//! Alternative serde representations for use with `#[serde(with = "...")]`,
+//! for reading and writing rects in the formats used by other engines.
+//!
+//! ```ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Sprite {
+//!     #[serde(with = "glam_rect::serde_formats::position_size")]
+//!     source: URect,
+//! }
+//! ```
+//!
+//! Both formats validate the rect on deserialization and reject inverted
+//! corners.
+
+use crate::{DRect, IRect, Rect, RectError, URect};
+#[cfg(any(
+    feature = "glam-0_25",
+    feature = "glam-0_26",
+    feature = "glam-0_27",
+    feature = "glam-0_28",
+    feature = "glam-0_29"
+))]
+use crate::{I16Rect, U16Rect};
+#[cfg(any(
+    feature = "glam-0_24",
+    feature = "glam-0_25",
+    feature = "glam-0_26",
+    feature = "glam-0_27",
+    feature = "glam-0_28",
+    feature = "glam-0_29"
+))]
+use crate::{I64Rect, U64Rect};
+
+/// Rect types supported by the formats in this module.
+pub trait SerdeRect: Sized {
+    type Scalar: Copy;
+
+    /// Returns `[left, top, right, bottom]`.
+    fn to_corners(&self) -> [Self::Scalar; 4];
+
+    /// Constructs the rect from `[left, top, right, bottom]`.
+    fn try_from_corners(corners: [Self::Scalar; 4]) -> Result<Self, RectError>;
+
+    /// Returns `[x, y, width, height]`.
+    fn to_xywh(&self) -> [Self::Scalar; 4];
+
+    /// Constructs the rect from `[x, y, width, height]`.
+    fn try_from_xywh(xywh: [Self::Scalar; 4]) -> Result<Self, RectError>;
+}
+
+macro_rules! impl_serde_rect {
+    ($rect:ident, $vec:ident, $t:ty) => {
+        impl SerdeRect for $rect {
+            type Scalar = $t;
+
+            fn to_corners(&self) -> [$t; 4] {
+                [
+                    self.top_left.x,
+                    self.top_left.y,
+                    self.bottom_right.x,
+                    self.bottom_right.y,
+                ]
+            }
+
+            fn try_from_corners([x0, y0, x1, y1]: [$t; 4]) -> Result<Self, RectError> {
+                $rect::try_new(glam::$vec::new(x0, y0), glam::$vec::new(x1, y1))
+            }
+
+            fn to_xywh(&self) -> [$t; 4] {
+                [
+                    self.top_left.x,
+                    self.top_left.y,
+                    self.width(),
+                    self.height(),
+                ]
+            }
+
+            fn try_from_xywh([x, y, width, height]: [$t; 4]) -> Result<Self, RectError> {
+                $rect::try_new(
+                    glam::$vec::new(x, y),
+                    glam::$vec::new(x, y) + glam::$vec::new(width, height),
+                )
+            }
+        }
+    };
+}
+
+impl_serde_rect!(Rect, Vec2, f32);
+impl_serde_rect!(DRect, DVec2, f64);
+impl_serde_rect!(URect, UVec2, u32);
+impl_serde_rect!(IRect, IVec2, i32);
+#[cfg(any(
+    feature = "glam-0_24",
+    feature = "glam-0_25",
+    feature = "glam-0_26",
+    feature = "glam-0_27",
+    feature = "glam-0_28",
+    feature = "glam-0_29"
+))]
+impl_serde_rect!(I64Rect, I64Vec2, i64);
+#[cfg(any(
+    feature = "glam-0_24",
+    feature = "glam-0_25",
+    feature = "glam-0_26",
+    feature = "glam-0_27",
+    feature = "glam-0_28",
+    feature = "glam-0_29"
+))]
+impl_serde_rect!(U64Rect, U64Vec2, u64);
+#[cfg(any(
+    feature = "glam-0_25",
+    feature = "glam-0_26",
+    feature = "glam-0_27",
+    feature = "glam-0_28",
+    feature = "glam-0_29"
+))]
+impl_serde_rect!(I16Rect, I16Vec2, i16);
+#[cfg(any(
+    feature = "glam-0_25",
+    feature = "glam-0_26",
+    feature = "glam-0_27",
+    feature = "glam-0_28",
+    feature = "glam-0_29"
+))]
+impl_serde_rect!(U16Rect, U16Vec2, u16);
+
+/// Corner form: `{ "min": [x, y], "max": [x, y] }`.
+pub mod corners {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::SerdeRect;
+
+    #[derive(Serialize, Deserialize)]
+    struct Corners<T> {
+        min: [T; 2],
+        max: [T; 2],
+    }
+
+    pub fn serialize<R, S>(rect: &R, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        R: SerdeRect,
+        R::Scalar: Serialize,
+        S: Serializer,
+    {
+        let [x0, y0, x1, y1] = rect.to_corners();
+        Corners {
+            min: [x0, y0],
+            max: [x1, y1],
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, R, D>(deserializer: D) -> Result<R, D::Error>
+    where
+        R: SerdeRect,
+        R::Scalar: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let Corners {
+            min: [x0, y0],
+            max: [x1, y1],
+        } = Corners::deserialize(deserializer)?;
+        R::try_from_corners([x0, y0, x1, y1]).map_err(D::Error::custom)
+    }
+}
+
+/// Position and size form: `{ "x": .., "y": .., "width": .., "height": .. }`.
+pub mod position_size {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::SerdeRect;
+
+    #[derive(Serialize, Deserialize)]
+    struct PositionSize<T> {
+        x: T,
+        y: T,
+        width: T,
+        height: T,
+    }
+
+    pub fn serialize<R, S>(rect: &R, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        R: SerdeRect,
+        R::Scalar: Serialize,
+        S: Serializer,
+    {
+        let [x, y, width, height] = rect.to_xywh();
+        PositionSize {
+            x,
+            y,
+            width,
+            height,
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, R, D>(deserializer: D) -> Result<R, D::Error>
+    where
+        R: SerdeRect,
+        R::Scalar: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let PositionSize {
+            x,
+            y,
+            width,
+            height,
+        } = PositionSize::deserialize(deserializer)?;
+        R::try_from_xywh([x, y, width, height]).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::{IRect, URect};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Sprite {
+        #[serde(with = "super::position_size")]
+        source: URect,
+        #[serde(with = "super::corners")]
+        bounds: IRect,
+    }
+
+    #[test]
+    pub fn test_round_trip() {
+        let sprite = Sprite {
+            source: URect::from_tuples((16, 32), (48, 40)),
+            bounds: IRect::from_tuples((-4, -4), (4, 4)),
+        };
+        let json = serde_json::to_string(&sprite).unwrap();
+        assert_eq!(
+            json,
+            r#"{"source":{"x":16,"y":32,"width":32,"height":8},"bounds":{"min":[-4,-4],"max":[4,4]}}"#
+        );
+        assert_eq!(serde_json::from_str::<Sprite>(&json).unwrap(), sprite);
+
+        let inverted =
+            r#"{"source":{"x":0,"y":0,"width":1,"height":1},"bounds":{"min":[4,4],"max":[0,0]}}"#;
+        assert!(serde_json::from_str::<Sprite>(inverted).is_err());
+    }
+
+    #[test]
+    #[cfg(any(
+        feature = "glam-0_24",
+        feature = "glam-0_25",
+        feature = "glam-0_26",
+        feature = "glam-0_27",
+        feature = "glam-0_28",
+        feature = "glam-0_29"
+    ))]
+    pub fn test_wide_rects() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Tile {
+            #[serde(with = "super::position_size")]
+            area: crate::I64Rect,
+        }
+
+        let tile = Tile {
+            area: crate::I64Rect::from_tuples((-5_000_000_000, 0), (5_000_000_000, 1)),
+        };
+        let json = serde_json::to_string(&tile).unwrap();
+        assert_eq!(
+            json,
+            r#"{"area":{"x":-5000000000,"y":0,"width":10000000000,"height":1}}"#
+        );
+        assert_eq!(serde_json::from_str::<Tile>(&json).unwrap(), tile);
+    }
+
+    #[test]
+    pub fn test_default_format() {
+        let rect = IRect::from_tuples((1, 2), (3, 4));
+        let json = serde_json::to_string(&rect).unwrap();
+        assert_eq!(json, r#"{"top_left":[1,2],"bottom_right":[3,4]}"#);
+        assert_eq!(serde_json::from_str::<IRect>(&json).unwrap(), rect);
+    }
+}
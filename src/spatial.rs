@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+
+use glam::{IVec2, Vec2};
+
+use crate::{CoverMode, IRect, Rect};
+
+/// Identifies an item in a [`SpatialGrid`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub struct SpatialId(usize);
+
+/// A broadphase that hashes rects into the cells of an unbounded grid.
+///
+/// Each item is stored in every cell its rect touches, so queries only look
+/// at the items in the cells the query touches. Compared to a tree this is
+/// cheap to update incrementally, and works best when items are about the
+/// size of a cell.
+#[derive(Debug, Clone)]
+pub struct SpatialGrid<T> {
+    cell_size: Vec2,
+    cells: HashMap<IVec2, Vec<SpatialId>>,
+    items: Vec<Option<(Rect, T)>>,
+    vacant: Vec<SpatialId>,
+}
+
+impl<T> SpatialGrid<T> {
+    /// Constructs a new, empty grid with cells of `cell_size`, starting at
+    /// the origin.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cell_size` is not positive along both axes.
+    pub fn new(cell_size: Vec2) -> Self {
+        assert!(
+            cell_size.cmpgt(Vec2::ZERO).all(),
+            "cells must have a positive size"
+        );
+        Self {
+            cell_size,
+            cells: HashMap::new(),
+            items: Vec::new(),
+            vacant: Vec::new(),
+        }
+    }
+
+    /// Returns the size of a cell.
+    #[inline]
+    pub fn cell_size(&self) -> Vec2 {
+        self.cell_size
+    }
+
+    /// Returns the number of items in the grid.
+    pub fn len(&self) -> usize {
+        self.items.len() - self.vacant.len()
+    }
+
+    /// Returns `true` if the grid contains no items.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all items, keeping the allocated storage for reuse when the
+    /// grid is rebuilt every frame.
+    pub fn clear(&mut self) {
+        self.cells.values_mut().for_each(Vec::clear);
+        self.items.clear();
+        self.vacant.clear();
+    }
+
+    /// Adds `value` covering `rect` and returns its id.
+    pub fn insert(&mut self, rect: Rect, value: T) -> SpatialId {
+        let id = match self.vacant.pop() {
+            Some(id) => {
+                self.items[id.0] = Some((rect, value));
+                id
+            }
+            None => {
+                self.items.push(Some((rect, value)));
+                SpatialId(self.items.len() - 1)
+            }
+        };
+        for cell in self.cells_of(&rect).points() {
+            self.cells.entry(cell).or_default().push(id);
+        }
+        id
+    }
+
+    /// Removes the item with `id`, returning its rect and value.
+    pub fn remove(&mut self, id: SpatialId) -> Option<(Rect, T)> {
+        let (rect, value) = self.items.get_mut(id.0)?.take()?;
+        self.unlink(id, &rect);
+        self.vacant.push(id);
+        Some((rect, value))
+    }
+
+    /// Moves the item with `id` to `rect`, only touching the cells it
+    /// enters or leaves. Returns `false` if there is no such item.
+    pub fn update(&mut self, id: SpatialId, rect: Rect) -> bool {
+        let Some(Some((old, _))) = self.items.get(id.0) else {
+            return false;
+        };
+        let (old_cells, new_cells) = (self.cells_of(old), self.cells_of(&rect));
+        if old_cells != new_cells {
+            for cell in old_cells.points() {
+                if !new_cells.contains(cell) {
+                    if let Some(ids) = self.cells.get_mut(&cell) {
+                        ids.retain(|other| *other != id);
+                    }
+                }
+            }
+            for cell in new_cells.points() {
+                if !old_cells.contains(cell) {
+                    self.cells.entry(cell).or_default().push(id);
+                }
+            }
+        }
+        if let Some((current, _)) = &mut self.items[id.0] {
+            *current = rect;
+        }
+        true
+    }
+
+    /// Returns the rect and value of the item with `id`.
+    pub fn get(&self, id: SpatialId) -> Option<(&Rect, &T)> {
+        let (rect, value) = self.items.get(id.0)?.as_ref()?;
+        Some((rect, value))
+    }
+
+    /// Returns the ids of the items overlapping `rect`, each once, in
+    /// ascending order.
+    pub fn query_ids(&self, rect: &Rect) -> Vec<SpatialId> {
+        let mut ids: Vec<SpatialId> = self
+            .cells_of(rect)
+            .points()
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .copied()
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids.retain(|id| self.get(*id).is_some_and(|(item, _)| item.intersects(rect)));
+        ids
+    }
+
+    /// Returns the values of the items overlapping `rect`, each once.
+    pub fn query(&self, rect: &Rect) -> impl Iterator<Item = &T> {
+        self.query_ids(rect)
+            .into_iter()
+            .filter_map(|id| self.get(id).map(|(_, value)| value))
+    }
+
+    /// Returns the bounds of every cell that holds at least one item.
+    pub(crate) fn occupied_cells(&self) -> impl Iterator<Item = Rect> + '_ {
+        self.cells
+            .iter()
+            .filter(|(_, ids)| !ids.is_empty())
+            .map(|(cell, _)| {
+                Rect::from_position_size(cell.as_vec2() * self.cell_size, self.cell_size)
+            })
+    }
+
+    fn cells_of(&self, rect: &Rect) -> IRect {
+        let cells = rect.covered_cells(self.cell_size, CoverMode::Touched);
+        // Rects lying on a grid line still belong to the cell after it.
+        IRect::new(cells.top_left, cells.bottom_right.max(cells.top_left + 1))
+    }
+
+    fn unlink(&mut self, id: SpatialId, rect: &Rect) {
+        for cell in self.cells_of(rect).points() {
+            if let Some(ids) = self.cells.get_mut(&cell) {
+                ids.retain(|other| *other != id);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_spatial_grid() {
+        let mut grid = SpatialGrid::new(Vec2::splat(10.0));
+        let wide = grid.insert(Rect::from_tuples((0.0, 0.0), (35.0, 5.0)), "wide");
+        let small = grid.insert(Rect::from_tuples((22.0, 22.0), (24.0, 24.0)), "small");
+        grid.insert(Rect::from_tuples((-15.0, -15.0), (-5.0, -5.0)), "negative");
+        assert_eq!(grid.len(), 3);
+
+        let query = Rect::from_tuples((15.0, 0.0), (25.0, 25.0));
+        // The wide item spans several queried cells but is reported once.
+        assert_eq!(grid.query_ids(&query), [wide, small]);
+        let found: Vec<_> = grid
+            .query(&Rect::from_tuples((-10.0, -10.0), (1.0, 1.0)))
+            .collect();
+        assert_eq!(found, [&"wide", &"negative"]);
+        // Sharing a cell is not enough to be reported.
+        assert_eq!(
+            grid.query(&Rect::from_tuples((20.0, 20.0), (21.0, 21.0)))
+                .count(),
+            0
+        );
+
+        assert!(grid.update(small, Rect::from_tuples((-8.0, 2.0), (-6.0, 4.0))));
+        assert!(grid.query(&query).eq([&"wide"]));
+        assert_eq!(
+            grid.query_ids(&Rect::from_tuples((-9.0, 0.0), (-7.0, 3.0))),
+            [small]
+        );
+
+        assert_eq!(grid.remove(wide).map(|(_, value)| value), Some("wide"));
+        assert!(grid.remove(wide).is_none());
+        assert_eq!(grid.query(&query).count(), 0);
+        let reused = grid.insert(query, "reused");
+        assert_eq!(reused, wide);
+
+        grid.clear();
+        assert!(grid.is_empty());
+    }
+}
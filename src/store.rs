@@ -0,0 +1,263 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use glam::Vec2;
+
+use crate::{Anchor, Edge, Insets, Rect};
+
+/// How a rect in a [`RectStore`] is derived from its parent.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RectRule {
+    /// The parent itself.
+    Same,
+    /// A strip of the given thickness along one edge of the parent, clamped
+    /// to the parent's size.
+    Cut(Edge, f32),
+    /// The parent shrunk by the insets, collapsing to zero size where they
+    /// do not fit.
+    Deflate(Insets),
+    /// A rect of the given size placed at the anchor of the parent.
+    Align(Anchor, Vec2),
+}
+
+impl RectRule {
+    /// Applies the rule to the parent rect.
+    pub fn apply(&self, parent: &Rect) -> Rect {
+        let Rect {
+            top_left,
+            bottom_right,
+        } = *parent;
+        match *self {
+            RectRule::Same => *parent,
+            RectRule::Cut(edge, amount) => {
+                let amount = amount.max(0.0);
+                match edge {
+                    Edge::Left => Rect::new(
+                        top_left,
+                        Vec2::new((top_left.x + amount).min(bottom_right.x), bottom_right.y),
+                    ),
+                    Edge::Top => Rect::new(
+                        top_left,
+                        Vec2::new(bottom_right.x, (top_left.y + amount).min(bottom_right.y)),
+                    ),
+                    Edge::Right => Rect::new(
+                        Vec2::new((bottom_right.x - amount).max(top_left.x), top_left.y),
+                        bottom_right,
+                    ),
+                    Edge::Bottom => Rect::new(
+                        Vec2::new(top_left.x, (bottom_right.y - amount).max(top_left.y)),
+                        bottom_right,
+                    ),
+                }
+            }
+            RectRule::Deflate(insets) => parent.deflate_clamped(insets),
+            RectRule::Align(anchor, size) => Rect::from_position_size(
+                top_left + (parent.size() - size) * anchor.fraction(),
+                size,
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Entry<K> {
+    rect: Rect,
+    source: Option<(K, RectRule)>,
+    children: Vec<K>,
+}
+
+/// A minimal reactive layout graph. Rects are stored under keys, either set
+/// directly or derived from a parent rect by a [`RectRule`], and changing a
+/// rect recomputes everything derived from it.
+#[derive(Debug, Clone)]
+pub struct RectStore<K> {
+    entries: HashMap<K, Entry<K>>,
+}
+
+impl<K> Default for RectStore<K> {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Eq + Hash + Clone> RectStore<K> {
+    /// Constructs a new, empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the rect stored under `key`.
+    pub fn get(&self, key: &K) -> Option<&Rect> {
+        self.entries.get(key).map(|entry| &entry.rect)
+    }
+
+    /// Returns the parent and rule that `key` is derived from, or `None` if
+    /// it is unknown or set directly.
+    pub fn source(&self, key: &K) -> Option<(&K, &RectRule)> {
+        let (parent, rule) = self.entries.get(key)?.source.as_ref()?;
+        Some((parent, rule))
+    }
+
+    /// Sets the rect of `key`, detaching it from its parent if it was
+    /// derived. Returns every rect that changed as a result, starting with
+    /// `key` and followed by its descendants, parents before children.
+    pub fn set(&mut self, key: K, rect: Rect) -> Vec<(K, Rect)> {
+        self.attach(key, rect, None)
+    }
+
+    /// Derives the rect of `key` from `parent` using `rule`, replacing how
+    /// `key` was defined before. Returns every rect that changed, as
+    /// [`RectStore::set`] does.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parent` is unknown, or if it is `key` or derived from it,
+    /// which would create a cycle.
+    pub fn derive(&mut self, key: K, parent: K, rule: RectRule) -> Vec<(K, Rect)> {
+        let mut ancestor = Some(&parent);
+        while let Some(current) = ancestor {
+            assert!(*current != key, "deriving the rect would create a cycle");
+            let entry = self.entries.get(current).expect("unknown parent rect");
+            ancestor = entry.source.as_ref().map(|(parent, _)| parent);
+        }
+        let parent_entry = self.entries.get_mut(&parent).unwrap();
+        let rect = rule.apply(&parent_entry.rect);
+        if !parent_entry.children.contains(&key) {
+            parent_entry.children.push(key.clone());
+        }
+        self.attach(key, rect, Some((parent, rule)))
+    }
+
+    /// Removes `key` and everything derived from it, returning the removed
+    /// keys.
+    pub fn remove(&mut self, key: &K) -> Vec<K> {
+        let Some(entry) = self.entries.remove(key) else {
+            return Vec::new();
+        };
+        self.detach(key, &entry.source);
+        let mut removed = vec![key.clone()];
+        let mut pending = entry.children;
+        while let Some(child) = pending.pop() {
+            if let Some(entry) = self.entries.remove(&child) {
+                pending.extend(entry.children);
+                removed.push(child);
+            }
+        }
+        removed
+    }
+
+    /// Returns an iterator over all keys and their rects, in arbitrary
+    /// order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &Rect)> {
+        self.entries.iter().map(|(key, entry)| (key, &entry.rect))
+    }
+
+    fn attach(&mut self, key: K, rect: Rect, source: Option<(K, RectRule)>) -> Vec<(K, Rect)> {
+        let children = match self.entries.remove(&key) {
+            Some(old) => {
+                if old.source.as_ref().map(|(parent, _)| parent)
+                    != source.as_ref().map(|(parent, _)| parent)
+                {
+                    self.detach(&key, &old.source);
+                }
+                if old.rect == rect {
+                    self.entries.insert(key, Entry { source, ..old });
+                    return Vec::new();
+                }
+                old.children
+            }
+            None => Vec::new(),
+        };
+        self.entries.insert(
+            key.clone(),
+            Entry {
+                rect,
+                source,
+                children,
+            },
+        );
+
+        let mut changed = vec![(key.clone(), rect)];
+        let mut index = 0;
+        while index < changed.len() {
+            let parent = self.entries[&changed[index].0].rect;
+            let children = self.entries[&changed[index].0].children.clone();
+            for child in children {
+                let entry = self.entries.get_mut(&child).unwrap();
+                let (_, rule) = entry.source.as_ref().unwrap();
+                let rect = rule.apply(&parent);
+                if entry.rect != rect {
+                    entry.rect = rect;
+                    changed.push((child, rect));
+                }
+            }
+            index += 1;
+        }
+        changed
+    }
+
+    /// Removes `key` from the children of its former parent.
+    fn detach(&mut self, key: &K, source: &Option<(K, RectRule)>) {
+        if let Some((parent, _)) = source {
+            if let Some(parent) = self.entries.get_mut(parent) {
+                parent.children.retain(|child| child != key);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_rect_store() {
+        let mut store = RectStore::new();
+        store.set("window", Rect::from_tuples((0.0, 0.0), (800.0, 600.0)));
+        store.derive("sidebar", "window", RectRule::Cut(Edge::Left, 200.0));
+        store.derive("toolbar", "sidebar", RectRule::Cut(Edge::Top, 40.0));
+        store.derive(
+            "badge",
+            "window",
+            RectRule::Align(Anchor::BottomRight, Vec2::new(20.0, 20.0)),
+        );
+        assert_eq!(
+            store.get(&"toolbar"),
+            Some(&Rect::from_tuples((0.0, 0.0), (200.0, 40.0)))
+        );
+
+        let mut changed = store.set("window", Rect::from_tuples((0.0, 0.0), (800.0, 400.0)));
+        assert_eq!(changed[0].0, "window");
+        changed.sort_by_key(|(key, _)| *key);
+        let keys: Vec<_> = changed.iter().map(|(key, _)| *key).collect();
+        // The toolbar keeps its size, so it is not reported.
+        assert_eq!(keys, ["badge", "sidebar", "window"]);
+        assert_eq!(
+            store.get(&"badge"),
+            Some(&Rect::from_tuples((780.0, 380.0), (800.0, 400.0)))
+        );
+
+        let changed = store.derive("sidebar", "window", RectRule::Cut(Edge::Left, 100.0));
+        assert_eq!(changed.len(), 2);
+        assert_eq!(
+            store.get(&"toolbar"),
+            Some(&Rect::from_tuples((0.0, 0.0), (100.0, 40.0)))
+        );
+
+        let mut removed = store.remove(&"sidebar");
+        removed.sort();
+        assert_eq!(removed, ["sidebar", "toolbar"]);
+        assert_eq!(store.iter().count(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn test_rect_store_cycle() {
+        let mut store = RectStore::new();
+        store.set(0, Rect::from_tuples((0.0, 0.0), (1.0, 1.0)));
+        store.derive(1, 0, RectRule::Same);
+        store.derive(0, 1, RectRule::Same);
+    }
+}
@@ -0,0 +1,95 @@
+use glam::Vec2;
+
+use crate::Rect;
+
+/// Where a moving rect first hits another, as found by [`Rect::sweep`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SweepHit {
+    /// The fraction of the velocity travelled before the hit, in `[0, 1]`.
+    pub time: f32,
+    /// The unit normal of the face that was hit, pointing out of the other
+    /// rect towards the moving one. This is zero if the rects already
+    /// overlapped at the start.
+    pub normal: Vec2,
+}
+
+impl Rect {
+    /// Moves the rectangle by `velocity` and returns where it first overlaps
+    /// `other`, or `None` if it never does. Unlike testing only the final
+    /// position, this catches fast objects that would otherwise pass
+    /// through thin walls. As with [`Rect::intersects`], rects that only
+    /// touch, or slide along each other, do not hit.
+    ///
+    /// To respond to the hit, move by `velocity * hit.time` and remove the
+    /// part of the remaining velocity along `hit.normal`.
+    pub fn sweep(&self, velocity: Vec2, other: &Rect) -> Option<SweepHit> {
+        let half_size = self.size() * 0.5;
+        let min = other.top_left - half_size;
+        let max = other.bottom_right + half_size;
+        let center = self.center();
+
+        let mut entry = Vec2::splat(f32::NEG_INFINITY);
+        let mut exit = Vec2::splat(f32::INFINITY);
+        for axis in 0..2 {
+            if velocity[axis] == 0.0 {
+                if center[axis] <= min[axis] || center[axis] >= max[axis] {
+                    return None;
+                }
+            } else {
+                let t1 = (min[axis] - center[axis]) / velocity[axis];
+                let t2 = (max[axis] - center[axis]) / velocity[axis];
+                entry[axis] = t1.min(t2);
+                exit[axis] = t1.max(t2);
+            }
+        }
+
+        let time = entry.max_element();
+        if time >= exit.min_element() || exit.min_element() <= 0.0 || time > 1.0 {
+            return None;
+        }
+        if time < 0.0 {
+            return Some(SweepHit {
+                time: 0.0,
+                normal: Vec2::ZERO,
+            });
+        }
+        let normal = if entry.x > entry.y {
+            Vec2::new(-velocity.x.signum(), 0.0)
+        } else {
+            Vec2::new(0.0, -velocity.y.signum())
+        };
+        Some(SweepHit { time, normal })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_sweep() {
+        let wall = Rect::from_tuples((10.0, -50.0), (11.0, 50.0));
+        let player = Rect::from_tuples((0.0, 0.0), (2.0, 2.0));
+
+        // Moving fast enough to skip over the wall in a single step.
+        let hit = player.sweep(Vec2::new(40.0, 4.0), &wall).unwrap();
+        assert_eq!(hit.time, 0.2);
+        assert_eq!(hit.normal, Vec2::new(-1.0, 0.0));
+        assert!(player.sweep(Vec2::new(4.0, 0.0), &wall).is_none());
+        assert!(player.sweep(Vec2::new(-40.0, 0.0), &wall).is_none());
+
+        let floor = Rect::from_tuples((-50.0, 10.0), (50.0, 20.0));
+        let hit = player.sweep(Vec2::new(3.0, 16.0), &floor).unwrap();
+        assert_eq!(hit.time, 0.5);
+        assert_eq!(hit.normal, Vec2::new(0.0, -1.0));
+
+        // Sliding along the floor's top face is not a hit.
+        let resting = Rect::from_tuples((0.0, 8.0), (2.0, 10.0));
+        assert!(resting.sweep(Vec2::new(10.0, 0.0), &floor).is_none());
+
+        let overlapping = Rect::from_tuples((0.0, 9.0), (2.0, 11.0));
+        let hit = overlapping.sweep(Vec2::new(1.0, 0.0), &floor).unwrap();
+        assert_eq!(hit.time, 0.0);
+        assert_eq!(hit.normal, Vec2::ZERO);
+    }
+}
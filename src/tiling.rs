@@ -0,0 +1,319 @@
+use glam::Vec2;
+
+use crate::Rect;
+
+/// The direction in which a [`TilingTree`] container arranges its children,
+/// or in which [`Rect::split_fraction`] places its two parts.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum SplitAxis {
+    /// Children are placed side by side, from left to right.
+    Horizontal,
+    /// Children are stacked from top to bottom.
+    Vertical,
+}
+
+/// The smallest share of its container a child can be resized to, unless the
+/// container has so many children that even shares are smaller.
+const MIN_RATIO: f32 = 0.05;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node<W> {
+    Window(W),
+    Container {
+        axis: SplitAxis,
+        /// Each child with its share of the container. The shares sum to one.
+        children: Vec<(f32, Node<W>)>,
+    },
+}
+
+impl<W: PartialEq> Node<W> {
+    fn contains(&self, window: &W) -> bool {
+        match self {
+            Node::Window(w) => w == window,
+            Node::Container { children, .. } => {
+                children.iter().any(|(_, child)| child.contains(window))
+            }
+        }
+    }
+
+    /// Inserts `window` next to `target`. Returns the window back if `target`
+    /// is not in this subtree.
+    fn insert(&mut self, target: &W, window: W, axis: SplitAxis) -> Result<(), W> {
+        match self {
+            Node::Window(w) if w == target => {
+                let old = std::mem::replace(
+                    self,
+                    Node::Container {
+                        axis,
+                        children: Vec::new(),
+                    },
+                );
+                if let Node::Container { children, .. } = self {
+                    children.push((0.5, old));
+                    children.push((0.5, Node::Window(window)));
+                }
+                Ok(())
+            }
+            Node::Window(_) => Err(window),
+            Node::Container {
+                axis: own_axis,
+                children,
+            } => {
+                let position = children
+                    .iter()
+                    .position(|(_, child)| child.contains(target));
+                let Some(index) = position else {
+                    return Err(window);
+                };
+                if *own_axis == axis && matches!(children[index].1, Node::Window(_)) {
+                    // Like i3, splitting along the container's own axis adds a
+                    // sibling with an equal share instead of nesting.
+                    let share = 1.0 / (children.len() + 1) as f32;
+                    for (ratio, _) in children.iter_mut() {
+                        *ratio *= 1.0 - share;
+                    }
+                    children.insert(index + 1, (share, Node::Window(window)));
+                    Ok(())
+                } else {
+                    children[index].1.insert(target, window, axis)
+                }
+            }
+        }
+    }
+
+    /// Removes `window` from this container's subtree, collapsing containers
+    /// that are left with a single child.
+    fn remove(&mut self, window: &W) -> bool {
+        let Node::Container { children, .. } = self else {
+            return false;
+        };
+        let Some(index) = children
+            .iter()
+            .position(|(_, child)| child.contains(window))
+        else {
+            return false;
+        };
+        if matches!(&children[index].1, Node::Window(w) if w == window) {
+            let (share, _) = children.remove(index);
+            let rest = 1.0 - share;
+            for (ratio, _) in children.iter_mut() {
+                *ratio /= rest;
+            }
+        } else {
+            children[index].1.remove(window);
+        }
+        if children.len() == 1 {
+            let (_, only) = children.pop().unwrap();
+            *self = only;
+        }
+        true
+    }
+
+    /// Grows the share of the child containing `window` in the nearest
+    /// enclosing container split along `axis`.
+    fn resize(&mut self, window: &W, axis: SplitAxis, delta: f32) -> bool {
+        let Node::Container {
+            axis: own_axis,
+            children,
+        } = self
+        else {
+            return false;
+        };
+        let Some(index) = children
+            .iter()
+            .position(|(_, child)| child.contains(window))
+        else {
+            return false;
+        };
+        if children[index].1.resize(window, axis, delta) {
+            return true;
+        }
+        if *own_axis != axis {
+            return false;
+        }
+        let count = children.len() as f32;
+        // With many children, even shares can be smaller than `MIN_RATIO`.
+        let min_ratio = MIN_RATIO.min(1.0 / count);
+        let old = children[index].0;
+        let new = (old + delta).clamp(min_ratio, 1.0 - min_ratio * (count - 1.0));
+        let others = 1.0 - old;
+        for (i, (ratio, _)) in children.iter_mut().enumerate() {
+            *ratio = if i == index {
+                new
+            } else {
+                *ratio / others * (1.0 - new)
+            };
+        }
+        true
+    }
+
+    fn layout(&self, area: Rect, out: &mut Vec<(W, Rect)>)
+    where
+        W: Clone,
+    {
+        match self {
+            Node::Window(window) => out.push((window.clone(), area)),
+            Node::Container { axis, children } => {
+                let mut start = area.top_left;
+                for (i, (ratio, child)) in children.iter().enumerate() {
+                    let last = i + 1 == children.len();
+                    let end = match axis {
+                        SplitAxis::Horizontal if last => area.bottom_right,
+                        SplitAxis::Horizontal => {
+                            Vec2::new(start.x + area.width() * ratio, area.bottom_right.y)
+                        }
+                        SplitAxis::Vertical if last => area.bottom_right,
+                        SplitAxis::Vertical => {
+                            Vec2::new(area.bottom_right.x, start.y + area.height() * ratio)
+                        }
+                    };
+                    child.layout(Rect::new(start, end), out);
+                    start = match axis {
+                        SplitAxis::Horizontal => Vec2::new(end.x, area.top_left.y),
+                        SplitAxis::Vertical => Vec2::new(area.top_left.x, end.y),
+                    };
+                }
+            }
+        }
+    }
+}
+
+/// An i3-style tiling layout: a tree of containers that split their area
+/// horizontally or vertically between their children, with windows as the
+/// leaves.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TilingTree<W> {
+    root: Option<Node<W>>,
+}
+
+impl<W> Default for TilingTree<W> {
+    fn default() -> Self {
+        Self { root: None }
+    }
+}
+
+impl<W: PartialEq + Clone> TilingTree<W> {
+    /// Constructs a new, empty tree.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if the tree has no windows.
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Returns `true` if `window` is in the tree.
+    pub fn contains(&self, window: &W) -> bool {
+        self.root.as_ref().is_some_and(|root| root.contains(window))
+    }
+
+    /// Inserts `window` after `target`, splitting `target`'s space along
+    /// `axis`. If `target` is `None` or not in the tree, the whole tree is
+    /// split instead. The first window inserted takes up the whole area.
+    pub fn insert(&mut self, window: W, target: Option<&W>, axis: SplitAxis) {
+        let Some(root) = &mut self.root else {
+            self.root = Some(Node::Window(window));
+            return;
+        };
+        let window = match target {
+            Some(target) => match root.insert(target, window, axis) {
+                Ok(()) => return,
+                Err(window) => window,
+            },
+            None => window,
+        };
+        let old = self.root.take().unwrap();
+        self.root = Some(Node::Container {
+            axis,
+            children: vec![(0.5, old), (0.5, Node::Window(window))],
+        });
+    }
+
+    /// Removes `window`, giving its space to its siblings. Returns `false`
+    /// if it was not in the tree.
+    pub fn remove(&mut self, window: &W) -> bool {
+        match &mut self.root {
+            Some(Node::Window(w)) if w == window => {
+                self.root = None;
+                true
+            }
+            Some(root) => root.remove(window),
+            None => false,
+        }
+    }
+
+    /// Grows the window by `delta`, as a fraction of the nearest enclosing
+    /// container split along `axis`, taking the space proportionally from its
+    /// siblings. A negative `delta` shrinks it. Returns `false` if the window
+    /// is not in such a container.
+    pub fn resize(&mut self, window: &W, axis: SplitAxis, delta: f32) -> bool {
+        self.root
+            .as_mut()
+            .is_some_and(|root| root.resize(window, axis, delta))
+    }
+
+    /// Returns the rect of every window when the tree fills `area`.
+    pub fn layout(&self, area: Rect) -> Vec<(W, Rect)> {
+        let mut out = Vec::new();
+        if let Some(root) = &self.root {
+            root.layout(area, &mut out);
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_tiling_tree() {
+        let area = Rect::from_tuples((0.0, 0.0), (100.0, 100.0));
+        let mut tree = TilingTree::new();
+        tree.insert('a', None, SplitAxis::Horizontal);
+        assert_eq!(tree.layout(area), vec![('a', area)]);
+
+        tree.insert('b', Some(&'a'), SplitAxis::Horizontal);
+        tree.insert('c', Some(&'b'), SplitAxis::Vertical);
+        assert_eq!(
+            tree.layout(area),
+            vec![
+                ('a', Rect::from_tuples((0.0, 0.0), (50.0, 100.0))),
+                ('b', Rect::from_tuples((50.0, 0.0), (100.0, 50.0))),
+                ('c', Rect::from_tuples((50.0, 50.0), (100.0, 100.0))),
+            ]
+        );
+
+        assert!(tree.resize(&'c', SplitAxis::Horizontal, 0.25));
+        assert_eq!(
+            tree.layout(area)[0],
+            ('a', Rect::from_tuples((0.0, 0.0), (25.0, 100.0)))
+        );
+
+        assert!(tree.remove(&'b'));
+        assert_eq!(
+            tree.layout(area)[1],
+            ('c', Rect::from_tuples((25.0, 0.0), (100.0, 100.0)))
+        );
+        assert!(!tree.remove(&'b'));
+    }
+
+    #[test]
+    pub fn test_resize_many_siblings() {
+        let area = Rect::from_tuples((0.0, 0.0), (250.0, 10.0));
+        let mut tree = TilingTree::new();
+        tree.insert(0, None, SplitAxis::Horizontal);
+        for window in 1..25 {
+            tree.insert(window, Some(&(window - 1)), SplitAxis::Horizontal);
+        }
+        assert_eq!(tree.layout(area).len(), 25);
+
+        assert!(tree.resize(&3, SplitAxis::Horizontal, 0.1));
+        let layout = tree.layout(area);
+        let total: f32 = layout.iter().map(|(_, rect)| rect.width()).sum();
+        assert!((total - 250.0).abs() < 1e-3);
+        assert!(layout.iter().all(|(_, rect)| rect.width() > 0.0));
+        assert!(tree.resize(&3, SplitAxis::Horizontal, -1.0));
+    }
+}
@@ -0,0 +1,173 @@
+use glam::Vec2;
+
+use crate::Rect;
+
+/// How a [`RectTrack`] blends between neighbouring keyframes.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum TrackInterpolation {
+    /// Holds each keyframe until the next one is reached.
+    Step,
+    /// Linearly interpolates the vertices.
+    Linear,
+    /// Interpolates the vertices along a Catmull-Rom spline through all
+    /// keyframes, giving smooth motion through the intermediate ones.
+    Cubic,
+}
+
+/// An animation of a rect, described by `(time, rect)` keyframes.
+///
+/// Sampling before the first keyframe or after the last one returns the
+/// first or last rect respectively.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RectTrack {
+    keyframes: Vec<(f32, Rect)>,
+    interpolation: TrackInterpolation,
+}
+
+impl RectTrack {
+    /// Constructs a new track without keyframes.
+    pub fn new(interpolation: TrackInterpolation) -> Self {
+        Self {
+            keyframes: Vec::new(),
+            interpolation,
+        }
+    }
+
+    /// Adds a keyframe, replacing any keyframe at exactly the same time.
+    pub fn insert(&mut self, time: f32, rect: Rect) {
+        match self.keyframes.binary_search_by(|(t, _)| t.total_cmp(&time)) {
+            Ok(index) => self.keyframes[index].1 = rect,
+            Err(index) => self.keyframes.insert(index, (time, rect)),
+        }
+    }
+
+    /// Builder version of [`RectTrack::insert`].
+    #[must_use]
+    pub fn with_keyframe(mut self, time: f32, rect: Rect) -> Self {
+        self.insert(time, rect);
+        self
+    }
+
+    /// Removes the keyframe at exactly `time`, returning its rect.
+    pub fn remove(&mut self, time: f32) -> Option<Rect> {
+        let index = self
+            .keyframes
+            .binary_search_by(|(t, _)| t.total_cmp(&time))
+            .ok()?;
+        Some(self.keyframes.remove(index).1)
+    }
+
+    /// Returns the keyframes ordered by time.
+    pub fn keyframes(&self) -> &[(f32, Rect)] {
+        &self.keyframes
+    }
+
+    pub fn interpolation(&self) -> TrackInterpolation {
+        self.interpolation
+    }
+
+    pub fn set_interpolation(&mut self, interpolation: TrackInterpolation) {
+        self.interpolation = interpolation;
+    }
+
+    /// Returns `true` if the track has no keyframes.
+    pub fn is_empty(&self) -> bool {
+        self.keyframes.is_empty()
+    }
+
+    /// Returns the time of the first keyframe.
+    pub fn start_time(&self) -> Option<f32> {
+        self.keyframes.first().map(|(time, _)| *time)
+    }
+
+    /// Returns the time of the last keyframe.
+    pub fn end_time(&self) -> Option<f32> {
+        self.keyframes.last().map(|(time, _)| *time)
+    }
+
+    /// Returns the time between the first and last keyframes, or zero if
+    /// there are fewer than two.
+    pub fn duration(&self) -> f32 {
+        match (self.start_time(), self.end_time()) {
+            (Some(start), Some(end)) => end - start,
+            _ => 0.0,
+        }
+    }
+
+    /// Returns `true` if `time` is at or after the last keyframe.
+    pub fn is_finished(&self, time: f32) -> bool {
+        self.end_time().is_none_or(|end| time >= end)
+    }
+
+    /// Returns the rect at `time`, or `None` if the track is empty.
+    pub fn sample(&self, time: f32) -> Option<Rect> {
+        let last = self.keyframes.len().checked_sub(1)?;
+        let next = self.keyframes.partition_point(|(t, _)| *t <= time);
+        if next == 0 {
+            return Some(self.keyframes[0].1);
+        }
+        if next > last {
+            return Some(self.keyframes[last].1);
+        }
+        let (t0, r0) = self.keyframes[next - 1];
+        let (t1, r1) = self.keyframes[next];
+        let t = (time - t0) / (t1 - t0);
+        Some(match self.interpolation {
+            TrackInterpolation::Step => r0,
+            TrackInterpolation::Linear => r0.lerp(&r1, t),
+            TrackInterpolation::Cubic => {
+                let before = self.keyframes[next.saturating_sub(2)].1;
+                let after = self.keyframes[(next + 1).min(last)].1;
+                let spline = |p0: Vec2, p1: Vec2, p2: Vec2, p3: Vec2| {
+                    0.5 * (2.0 * p1
+                        + (p2 - p0) * t
+                        + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t * t
+                        + (3.0 * (p1 - p2) + p3 - p0) * t * t * t)
+                };
+                let a = spline(before.top_left, r0.top_left, r1.top_left, after.top_left);
+                let b = spline(
+                    before.bottom_right,
+                    r0.bottom_right,
+                    r1.bottom_right,
+                    after.bottom_right,
+                );
+                // Overshoot can invert the rect between keyframes.
+                Rect::new(a.min(b), a.max(b))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_track_sampling() {
+        let a = Rect::from_tuples((0.0, 0.0), (10.0, 10.0));
+        let b = Rect::from_tuples((10.0, 0.0), (30.0, 10.0));
+        let c = Rect::from_tuples((20.0, 20.0), (40.0, 30.0));
+        let mut track = RectTrack::new(TrackInterpolation::Linear)
+            .with_keyframe(2.0, c)
+            .with_keyframe(0.0, a)
+            .with_keyframe(1.0, b);
+
+        assert_eq!(track.duration(), 2.0);
+        assert_eq!(track.sample(-1.0), Some(a));
+        assert_eq!(track.sample(5.0), Some(c));
+        assert_eq!(
+            track.sample(0.5),
+            Some(Rect::from_tuples((5.0, 0.0), (20.0, 10.0)))
+        );
+
+        track.set_interpolation(TrackInterpolation::Step);
+        assert_eq!(track.sample(0.99), Some(a));
+        assert_eq!(track.sample(1.0), Some(b));
+
+        track.set_interpolation(TrackInterpolation::Cubic);
+        assert_eq!(track.sample(1.0), Some(b));
+        assert_eq!(track.sample(2.0), Some(c));
+
+        assert_eq!(RectTrack::new(TrackInterpolation::Linear).sample(0.0), None);
+    }
+}
@@ -0,0 +1,172 @@
+use glam::Vec2;
+
+use crate::{Anchor, Rect};
+
+/// A compact record of how a rect was edited, built from translations,
+/// resizes and quarter turns. Editors can push these onto an undo stack
+/// instead of full snapshots, and undo an edit by applying its
+/// [`RectTransaction::inverse`].
+///
+/// Internally the edit maps a point `p` to `translation + scale * R(p)`,
+/// where `R` rotates by `quarter_turns` clockwise quarter turns about the
+/// origin (with y pointing down).
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct RectTransaction {
+    quarter_turns: u8,
+    scale: Vec2,
+    translation: Vec2,
+}
+
+impl Default for RectTransaction {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl RectTransaction {
+    /// The transaction that leaves rects unchanged.
+    pub const IDENTITY: RectTransaction = RectTransaction {
+        quarter_turns: 0,
+        scale: Vec2::ONE,
+        translation: Vec2::ZERO,
+    };
+
+    /// A translation by `offset`.
+    #[inline]
+    pub fn translate(offset: Vec2) -> Self {
+        Self {
+            translation: offset,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// A per-axis scale by `factor` about the `pivot` point.
+    #[inline]
+    pub fn scale_about(pivot: Vec2, factor: Vec2) -> Self {
+        Self {
+            quarter_turns: 0,
+            scale: factor,
+            translation: pivot - pivot * factor,
+        }
+    }
+
+    /// The edit that resizes `rect` to `new_size` while keeping its `anchor`
+    /// point fixed.
+    #[inline]
+    pub fn resize(rect: &Rect, new_size: Vec2, anchor: Anchor) -> Self {
+        let pivot = rect.top_left + rect.size() * anchor.fraction();
+        Self::scale_about(pivot, new_size / rect.size())
+    }
+
+    /// A quarter turn about `pivot`, clockwise on screen if `clockwise` is
+    /// true.
+    #[inline]
+    pub fn rotate_90(pivot: Vec2, clockwise: bool) -> Self {
+        let quarter_turns = if clockwise { 1 } else { 3 };
+        Self {
+            quarter_turns,
+            scale: Vec2::ONE,
+            translation: pivot - rotate(pivot, quarter_turns),
+        }
+    }
+
+    /// Returns `true` if applying this transaction changes nothing.
+    #[inline]
+    pub fn is_identity(&self) -> bool {
+        *self == Self::IDENTITY
+    }
+
+    /// Applies the transaction to a single point.
+    #[inline]
+    pub fn apply_point(&self, point: Vec2) -> Vec2 {
+        self.translation + self.scale * rotate(point, self.quarter_turns)
+    }
+
+    /// Applies the transaction to `rect`. The result is normalized, so the
+    /// vertices swap roles after rotations or mirroring scales.
+    #[inline]
+    pub fn apply(&self, rect: &Rect) -> Rect {
+        let a = self.apply_point(rect.top_left);
+        let b = self.apply_point(rect.bottom_right);
+        Rect::new(a.min(b), a.max(b))
+    }
+
+    /// Returns the transaction that applies `self` and then `next`.
+    #[inline]
+    #[must_use]
+    pub fn then(&self, next: &Self) -> Self {
+        Self {
+            quarter_turns: (self.quarter_turns + next.quarter_turns) % 4,
+            scale: next.scale * swap_if_odd(self.scale, next.quarter_turns),
+            translation: next.apply_point(self.translation),
+        }
+    }
+
+    /// Returns the transaction that undoes `self`. Scales of zero cannot be
+    /// undone and produce non-finite values.
+    #[inline]
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        let quarter_turns = (4 - self.quarter_turns) % 4;
+        let inverse_scale = Vec2::ONE / self.scale;
+        Self {
+            quarter_turns,
+            scale: swap_if_odd(inverse_scale, self.quarter_turns),
+            translation: -rotate(inverse_scale * self.translation, quarter_turns),
+        }
+    }
+}
+
+fn rotate(point: Vec2, quarter_turns: u8) -> Vec2 {
+    match quarter_turns % 4 {
+        0 => point,
+        1 => Vec2::new(-point.y, point.x),
+        2 => -point,
+        _ => Vec2::new(point.y, -point.x),
+    }
+}
+
+fn swap_if_odd(v: Vec2, quarter_turns: u8) -> Vec2 {
+    if quarter_turns % 2 == 1 {
+        Vec2::new(v.y, v.x)
+    } else {
+        v
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_rotate_and_resize() {
+        let r = Rect::from_tuples((0.0, 0.0), (4.0, 2.0));
+
+        let rotate = RectTransaction::rotate_90(Vec2::ZERO, true);
+        assert_eq!(rotate.apply(&r), Rect::from_tuples((-2.0, 0.0), (0.0, 4.0)));
+
+        let resize = RectTransaction::resize(&r, Vec2::new(8.0, 1.0), Anchor::BottomRight);
+        assert_eq!(resize.apply(&r), Rect::from_tuples((-4.0, 1.0), (4.0, 2.0)));
+    }
+
+    #[test]
+    pub fn test_compose_and_invert() {
+        let r = Rect::from_tuples((1.0, 2.0), (5.0, 4.0));
+        let edits = [
+            RectTransaction::translate(Vec2::new(3.0, -1.0)),
+            RectTransaction::rotate_90(Vec2::new(2.0, 2.0), true),
+            RectTransaction::scale_about(Vec2::new(1.0, 1.0), Vec2::new(2.0, 0.5)),
+            RectTransaction::rotate_90(Vec2::new(-1.0, 0.0), false),
+        ];
+
+        let mut stepwise = r;
+        let mut combined = RectTransaction::IDENTITY;
+        for edit in &edits {
+            stepwise = edit.apply(&stepwise);
+            combined = combined.then(edit);
+        }
+        assert_eq!(combined.apply(&r), stepwise);
+        assert_eq!(combined.inverse().apply(&stepwise), r);
+        assert!(combined.then(&combined.inverse()).is_identity());
+    }
+}
@@ -0,0 +1,95 @@
+use glam::{Affine2, DAffine2, DMat2, Mat2};
+
+use crate::{DRect, Rect};
+
+impl Rect {
+    /// Returns the axis-aligned bounds of the rectangle transformed by
+    /// `affine`. Use [`Affine2::from_mat2`] to transform by a `Mat2`.
+    #[inline]
+    #[must_use]
+    pub fn transformed_bounds(&self, affine: Affine2) -> Rect {
+        let abs = Mat2::from_cols(affine.matrix2.x_axis.abs(), affine.matrix2.y_axis.abs());
+        let center = affine.transform_point2(self.center());
+        let half_extents = abs * (self.size() * 0.5);
+        Rect::new(center - half_extents, center + half_extents)
+    }
+}
+
+impl DRect {
+    /// Returns the axis-aligned bounds of the rectangle transformed by
+    /// `affine`. Use [`DAffine2::from_mat2`] to transform by a `DMat2`.
+    #[inline]
+    #[must_use]
+    pub fn transformed_bounds(&self, affine: DAffine2) -> DRect {
+        let abs = DMat2::from_cols(affine.matrix2.x_axis.abs(), affine.matrix2.y_axis.abs());
+        let center = affine.transform_point2(self.center());
+        let half_extents = abs * (self.size() * 0.5);
+        DRect::new(center - half_extents, center + half_extents)
+    }
+}
+
+/// Transforms every rect in `rects` by `affine` and writes the axis-aligned
+/// bounds of the results to `out`, e.g. to cull instanced sprites after a
+/// camera transform. This is [`Rect::transformed_bounds`] for many rects.
+///
+/// Instead of transforming all four corners, each rect's center is
+/// transformed and its half extents are multiplied by the absolute value of
+/// the linear part, which gives the same bounds with less work.
+///
+/// # Panics
+///
+/// Panics if `rects` and `out` have different lengths.
+pub fn transform_aabbs(affine: &Affine2, rects: &[Rect], out: &mut [Rect]) {
+    assert_eq!(
+        rects.len(),
+        out.len(),
+        "the input and output slices must have the same length"
+    );
+    for (rect, out) in rects.iter().zip(out) {
+        *out = rect.transformed_bounds(*affine);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::Vec2;
+
+    use super::*;
+
+    #[test]
+    pub fn test_transform_aabbs() {
+        let rects = [
+            Rect::from_tuples((0.0, 0.0), (2.0, 1.0)),
+            Rect::from_tuples((-1.0, -1.0), (1.0, 1.0)),
+        ];
+        let mut out = [Rect::ZERO; 2];
+
+        let affine = Affine2::from_scale_angle_translation(
+            Vec2::new(2.0, 1.0),
+            std::f32::consts::FRAC_PI_2,
+            Vec2::new(10.0, 0.0),
+        );
+        transform_aabbs(&affine, &rects, &mut out);
+        for (rect, out) in rects.iter().zip(&out) {
+            let corners = rect.corners().map(|c| affine.transform_point2(c));
+            let expected = Rect::from_points(corners).unwrap();
+            assert!(out.top_left.abs_diff_eq(expected.top_left, 1e-5));
+            assert!(out.bottom_right.abs_diff_eq(expected.bottom_right, 1e-5));
+        }
+    }
+
+    #[test]
+    pub fn test_transformed_bounds() {
+        let rect = Rect::from_tuples((1.0, 1.0), (3.0, 2.0));
+        let rotate = Affine2::from_mat2(Mat2::from_angle(std::f32::consts::FRAC_PI_2));
+        let bounds = rect.transformed_bounds(rotate);
+        assert!(bounds.top_left.abs_diff_eq(Vec2::new(-2.0, 1.0), 1e-6));
+        assert!(bounds.bottom_right.abs_diff_eq(Vec2::new(-1.0, 3.0), 1e-6));
+
+        let shift = DAffine2::from_translation(glam::DVec2::new(1.0, 2.0));
+        assert_eq!(
+            DRect::from(rect).transformed_bounds(shift),
+            DRect::from(rect.with_offset(Vec2::new(1.0, 2.0)))
+        );
+    }
+}
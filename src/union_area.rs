@@ -0,0 +1,82 @@
+//! The area covered by a set of rects, computed with a sweep line over a
+//! segment tree of the compressed y coordinates (Klee's algorithm).
+
+use core::ops::{Add, Mul, Sub};
+
+/// A segment tree counting how many rects cover each interval between
+/// consecutive y coordinates, and the total length covered under each node.
+struct CoverTree<W> {
+    ys: Vec<W>,
+    count: Vec<u32>,
+    covered: Vec<W>,
+}
+
+impl<W> CoverTree<W>
+where
+    W: Copy + Default + Sub<Output = W> + Add<Output = W>,
+{
+    fn update(&mut self, node: usize, lo: usize, hi: usize, a: usize, b: usize, add: bool) {
+        if b <= lo || hi <= a {
+            return;
+        }
+        if a <= lo && hi <= b {
+            if add {
+                self.count[node] += 1;
+            } else {
+                self.count[node] -= 1;
+            }
+        } else {
+            let mid = (lo + hi) / 2;
+            self.update(node * 2, lo, mid, a, b, add);
+            self.update(node * 2 + 1, mid, hi, a, b, add);
+        }
+        self.covered[node] = if self.count[node] > 0 {
+            self.ys[hi] - self.ys[lo]
+        } else if hi - lo == 1 {
+            W::default()
+        } else {
+            self.covered[node * 2] + self.covered[node * 2 + 1]
+        };
+    }
+}
+
+/// Returns the area covered by rects given as `[left, top, right, bottom]`,
+/// already widened to the type the area is accumulated in. Rects without
+/// positive area, including those with NaN coordinates, are ignored.
+pub(crate) fn union_area<W>(rects: impl Iterator<Item = [W; 4]>) -> W
+where
+    W: Copy + Default + PartialOrd + Add<Output = W> + Sub<Output = W> + Mul<Output = W>,
+{
+    let rects: Vec<[W; 4]> = rects.filter(|r| r[0] < r[2] && r[1] < r[3]).collect();
+    if rects.is_empty() {
+        return W::default();
+    }
+    let compare = |a: &W, b: &W| a.partial_cmp(b).unwrap();
+    let mut ys: Vec<W> = rects.iter().flat_map(|r| [r[1], r[3]]).collect();
+    ys.sort_by(compare);
+    ys.dedup_by(|a, b| a == b);
+
+    let mut events: Vec<(W, bool, usize, usize)> = Vec::with_capacity(rects.len() * 2);
+    for r in &rects {
+        let a = ys.partition_point(|&y| y < r[1]);
+        let b = ys.partition_point(|&y| y < r[3]);
+        events.push((r[0], true, a, b));
+        events.push((r[2], false, a, b));
+    }
+    events.sort_by(|a, b| compare(&a.0, &b.0));
+
+    let segments = ys.len() - 1;
+    let mut tree = CoverTree {
+        ys,
+        count: vec![0; segments * 4],
+        covered: vec![W::default(); segments * 4],
+    };
+    let mut area = W::default();
+    let mut previous_x = events[0].0;
+    for (x, add, a, b) in events {
+        area = area + tree.covered[1] * (x - previous_x);
+        previous_x = x;
+        tree.update(1, 0, segments, a, b, add);
+    }
+    area
+}
@@ -0,0 +1,125 @@
+use glam::UVec2;
+
+use crate::{SplitAxis, URect};
+
+/// How [`split_screen`] arranges the viewports.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SplitLayout {
+    /// The direction of the first split. With two players,
+    /// [`SplitAxis::Vertical`] stacks the views and [`SplitAxis::Horizontal`]
+    /// puts them side by side. With three players, the first player gets
+    /// the whole first half and the second half is split the other way.
+    pub axis: SplitAxis,
+    /// The space left between neighbouring views, in pixels.
+    pub gutter: u32,
+    /// If set, each view is shrunk to the largest centered rect with this
+    /// width to height ratio, letterboxing or pillarboxing it.
+    pub aspect_ratio: Option<f32>,
+}
+
+impl Default for SplitLayout {
+    fn default() -> Self {
+        Self {
+            axis: SplitAxis::Vertical,
+            gutter: 0,
+            aspect_ratio: None,
+        }
+    }
+}
+
+/// Divides `viewport` into one view per player in the usual split-screen
+/// arrangements: the whole viewport for one player, two halves for two,
+/// one half and two quarters for three, and quarters for four. More players
+/// are laid out in the smallest grid with enough cells, row by row.
+pub fn split_screen(viewport: &URect, players: usize, layout: SplitLayout) -> Vec<URect> {
+    let gutter = layout.gutter;
+    let split = |rect: &URect, axis: SplitAxis, parts: u32| -> Vec<URect> {
+        match axis {
+            SplitAxis::Horizontal => rect.split_grid(parts, 1, UVec2::new(gutter, 0)).collect(),
+            SplitAxis::Vertical => rect.split_grid(1, parts, UVec2::new(0, gutter)).collect(),
+        }
+    };
+    let other = match layout.axis {
+        SplitAxis::Horizontal => SplitAxis::Vertical,
+        SplitAxis::Vertical => SplitAxis::Horizontal,
+    };
+    let views = match players {
+        0 => Vec::new(),
+        1 => vec![*viewport],
+        2 => split(viewport, layout.axis, 2),
+        3 => {
+            let halves = split(viewport, layout.axis, 2);
+            let mut views = vec![halves[0]];
+            views.extend(split(&halves[1], other, 2));
+            views
+        }
+        _ => {
+            let columns = (players as f64).sqrt().ceil() as u32;
+            let rows = (players as u32).div_ceil(columns);
+            viewport
+                .split_grid(columns, rows, UVec2::splat(gutter))
+                .take(players)
+                .collect()
+        }
+    };
+    match layout.aspect_ratio {
+        Some(ratio) => views.iter().map(|view| letterbox(view, ratio)).collect(),
+        None => views,
+    }
+}
+
+/// Returns the largest rect with the given aspect ratio centered in `view`.
+fn letterbox(view: &URect, ratio: f32) -> URect {
+    let size = view.size();
+    let fitted = if size.x as f32 > size.y as f32 * ratio {
+        UVec2::new((size.y as f32 * ratio).round() as u32, size.y)
+    } else {
+        UVec2::new(size.x, (size.x as f32 / ratio).round() as u32)
+    };
+    URect::from_position_size(view.top_left + (size - fitted) / 2, fitted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_split_screen() {
+        let screen = URect::from_tuples((0, 0), (1920, 1080));
+        assert_eq!(
+            split_screen(&screen, 2, SplitLayout::default()),
+            [
+                URect::from_tuples((0, 0), (1920, 540)),
+                URect::from_tuples((0, 540), (1920, 1080)),
+            ]
+        );
+
+        let layout = SplitLayout {
+            axis: SplitAxis::Horizontal,
+            gutter: 10,
+            aspect_ratio: None,
+        };
+        assert_eq!(
+            split_screen(&screen, 3, layout),
+            [
+                URect::from_tuples((0, 0), (955, 1080)),
+                URect::from_tuples((965, 0), (1920, 535)),
+                URect::from_tuples((965, 545), (1920, 1080)),
+            ]
+        );
+        assert_eq!(
+            split_screen(&screen, 4, layout)[3].top_left,
+            UVec2::new(965, 545)
+        );
+        assert_eq!(split_screen(&screen, 5, layout).len(), 5);
+
+        let layout = SplitLayout {
+            aspect_ratio: Some(16.0 / 9.0),
+            ..SplitLayout::default()
+        };
+        assert_eq!(
+            split_screen(&screen, 2, layout)[0],
+            URect::from_tuples((480, 0), (1440, 540))
+        );
+    }
+}
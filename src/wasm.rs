@@ -0,0 +1,307 @@
+//! JavaScript bindings generated with wasm-bindgen. glam vectors cannot cross
+//! the wasm boundary, so each rect type is wrapped and exposes its vertices as
+//! plain numbers.
+
+use glam::{IVec2, UVec2, Vec2};
+use wasm_bindgen::prelude::*;
+
+use crate::{IRect, Rect, RectPacker, Region, URect};
+
+macro_rules! js_rect {
+    ($js:ident, $js_name:literal, $rect:ident, $vec:ident, $t:ty) => {
+        #[doc = concat!("JavaScript wrapper around [`", stringify!($rect), "`].")]
+        #[wasm_bindgen(js_name = $js_name)]
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct $js($rect);
+
+        #[wasm_bindgen(js_class = $js_name)]
+        impl $js {
+            /// Constructs a new rect from the coordinates of its top left and
+            /// bottom right vertices.
+            #[wasm_bindgen(constructor)]
+            pub fn new(left: $t, top: $t, right: $t, bottom: $t) -> Self {
+                Self($rect::new($vec::new(left, top), $vec::new(right, bottom)))
+            }
+
+            #[wasm_bindgen(getter)]
+            pub fn left(&self) -> $t {
+                self.0.top_left.x
+            }
+
+            #[wasm_bindgen(getter)]
+            pub fn top(&self) -> $t {
+                self.0.top_left.y
+            }
+
+            #[wasm_bindgen(getter)]
+            pub fn right(&self) -> $t {
+                self.0.bottom_right.x
+            }
+
+            #[wasm_bindgen(getter)]
+            pub fn bottom(&self) -> $t {
+                self.0.bottom_right.y
+            }
+
+            #[wasm_bindgen(getter)]
+            pub fn width(&self) -> $t {
+                self.0.width()
+            }
+
+            #[wasm_bindgen(getter)]
+            pub fn height(&self) -> $t {
+                self.0.height()
+            }
+
+            /// See [`Rect::contains`].
+            pub fn contains(&self, x: $t, y: $t) -> bool {
+                self.0.contains($vec::new(x, y))
+            }
+
+            /// See [`Rect::intersect`]. Returns `undefined` if the rects have no
+            /// common area.
+            pub fn intersect(&self, other: &$js) -> Option<$js> {
+                self.0.intersect(&other.0).map(Self)
+            }
+
+            /// See [`Rect::with_offset`].
+            #[wasm_bindgen(js_name = withOffset)]
+            pub fn with_offset(&self, x: $t, y: $t) -> $js {
+                Self(self.0.with_offset($vec::new(x, y)))
+            }
+        }
+
+        impl From<$rect> for $js {
+            fn from(rect: $rect) -> Self {
+                Self(rect)
+            }
+        }
+
+        impl From<$js> for $rect {
+            fn from(rect: $js) -> Self {
+                rect.0
+            }
+        }
+    };
+}
+
+js_rect!(JsRect, "Rect", Rect, Vec2, f32);
+js_rect!(JsURect, "URect", URect, UVec2, u32);
+js_rect!(JsIRect, "IRect", IRect, IVec2, i32);
+
+/// JavaScript wrapper around [`RectPacker`].
+#[wasm_bindgen(js_name = RectPacker)]
+#[derive(Debug, Clone)]
+pub struct JsRectPacker(RectPacker);
+
+#[wasm_bindgen(js_class = RectPacker)]
+impl JsRectPacker {
+    /// Constructs an empty packer for an atlas of the given size.
+    #[wasm_bindgen(constructor)]
+    pub fn new(width: u32, height: u32) -> Self {
+        Self(RectPacker::new(URect::from_tuples((0, 0), (width, height))))
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn bounds(&self) -> JsURect {
+        self.0.bounds().into()
+    }
+
+    /// See [`RectPacker::free_area`].
+    #[wasm_bindgen(getter, js_name = freeArea)]
+    pub fn free_area(&self) -> f64 {
+        self.0.free_area() as f64
+    }
+
+    /// See [`RectPacker::allocate`]. Returns `undefined` if the size does not
+    /// fit.
+    pub fn allocate(&mut self, width: u32, height: u32) -> Option<JsURect> {
+        self.0.allocate(UVec2::new(width, height)).map(JsURect)
+    }
+
+    /// Grows the atlas as in [`RectPacker::grow`]. Returns `false` and leaves
+    /// the atlas unchanged if the new size is smaller along either axis, as
+    /// a panic would abort the whole module.
+    pub fn grow(&mut self, width: u32, height: u32) -> bool {
+        let size = UVec2::new(width, height);
+        if size.cmplt(self.0.bounds().size()).any() {
+            return false;
+        }
+        self.0.grow(size);
+        true
+    }
+
+    /// See [`RectPacker::deallocate`].
+    pub fn deallocate(&mut self, rect: &JsURect) {
+        self.0.deallocate(&rect.0);
+    }
+
+    /// See [`RectPacker::clear`].
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+/// JavaScript wrapper around [`Region`].
+#[wasm_bindgen(js_name = Region)]
+#[derive(Debug, Clone, Default)]
+pub struct JsRegion(Region);
+
+#[wasm_bindgen(js_class = Region)]
+impl JsRegion {
+    /// Constructs an empty region.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self(Region::new())
+    }
+
+    #[wasm_bindgen(getter, js_name = isEmpty)]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn area(&self) -> f32 {
+        self.0.area()
+    }
+
+    /// See [`Region::bounds`]. Returns `undefined` if the region is empty.
+    #[wasm_bindgen(getter)]
+    pub fn bounds(&self) -> Option<JsRect> {
+        self.0.bounds().map(JsRect)
+    }
+
+    /// Returns copies of the disjoint rects making up the region.
+    pub fn rects(&self) -> Vec<JsRect> {
+        self.0.iter().copied().map(JsRect).collect()
+    }
+
+    /// See [`Region::contains`].
+    pub fn contains(&self, x: f32, y: f32) -> bool {
+        self.0.contains(Vec2::new(x, y))
+    }
+
+    /// See [`Region::union_rect`].
+    #[wasm_bindgen(js_name = unionRect)]
+    pub fn union_rect(&mut self, rect: &JsRect) {
+        self.0.union_rect(&rect.0);
+    }
+
+    /// See [`Region::subtract_rect`].
+    #[wasm_bindgen(js_name = subtractRect)]
+    pub fn subtract_rect(&mut self, rect: &JsRect) {
+        self.0.subtract_rect(&rect.0);
+    }
+
+    /// See [`Region::intersect_rect`].
+    #[wasm_bindgen(js_name = intersectRect)]
+    pub fn intersect_rect(&mut self, rect: &JsRect) {
+        self.0.intersect_rect(&rect.0);
+    }
+
+    /// See [`Region::union`].
+    pub fn union(&mut self, other: &JsRegion) {
+        self.0.union(&other.0);
+    }
+
+    /// See [`Region::subtract`].
+    pub fn subtract(&mut self, other: &JsRegion) {
+        self.0.subtract(&other.0);
+    }
+
+    /// See [`Region::intersect`].
+    pub fn intersect(&mut self, other: &JsRegion) {
+        self.0.intersect(&other.0);
+    }
+
+    /// See [`Region::clear`].
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_js_rects() {
+        let rect = JsRect::new(1.0, 2.0, 4.0, 6.0);
+        assert_eq!(
+            (rect.left(), rect.top(), rect.right(), rect.bottom()),
+            (1.0, 2.0, 4.0, 6.0)
+        );
+        assert_eq!((rect.width(), rect.height()), (3.0, 4.0));
+        assert!(rect.contains(1.0, 2.0));
+        assert!(!rect.contains(4.0, 2.0));
+        assert_eq!(
+            Rect::from(rect.with_offset(1.0, -2.0)),
+            Rect::from_tuples((2.0, 0.0), (5.0, 4.0))
+        );
+
+        let a = JsIRect::new(-2, -2, 2, 2);
+        let b = JsIRect::from(IRect::from_tuples((0, 0), (4, 4)));
+        assert_eq!(
+            a.intersect(&b).map(IRect::from),
+            Some(IRect::from_tuples((0, 0), (2, 2)))
+        );
+        assert_eq!(a.intersect(&JsIRect::new(5, 5, 6, 6)), None);
+        assert_eq!(URect::from(JsURect::new(0, 0, 3, 3)).area(), 9);
+    }
+
+    #[test]
+    pub fn test_js_rect_packer() {
+        let mut packer = JsRectPacker::new(8, 8);
+        assert_eq!(
+            URect::from(packer.bounds()),
+            URect::from_tuples((0, 0), (8, 8))
+        );
+        let placed = packer.allocate(8, 4).unwrap();
+        assert_eq!((placed.width(), placed.height()), (8, 4));
+        assert_eq!(packer.free_area(), 32.0);
+        assert!(packer.allocate(8, 8).is_none());
+
+        assert!(!packer.grow(4, 16));
+        assert_eq!(packer.bounds().height(), 8);
+        assert!(packer.grow(8, 16));
+        assert_eq!(packer.free_area(), 96.0);
+
+        packer.deallocate(&placed);
+        assert_eq!(packer.free_area(), 128.0);
+        packer.allocate(1, 1).unwrap();
+        packer.clear();
+        assert_eq!(packer.free_area(), 128.0);
+    }
+
+    #[test]
+    pub fn test_js_region() {
+        let mut region = JsRegion::new();
+        assert!(region.is_empty());
+        assert_eq!(region.bounds(), None);
+
+        region.union_rect(&JsRect::new(0.0, 0.0, 10.0, 10.0));
+        region.subtract_rect(&JsRect::new(0.0, 0.0, 5.0, 10.0));
+        assert_eq!(region.area(), 50.0);
+        assert!(region.contains(7.0, 5.0));
+        assert!(!region.contains(2.0, 5.0));
+        assert_eq!(
+            region.bounds().map(Rect::from),
+            Some(Rect::from_tuples((5.0, 0.0), (10.0, 10.0)))
+        );
+
+        let mut other = JsRegion::new();
+        other.union_rect(&JsRect::new(8.0, 0.0, 20.0, 10.0));
+        region.intersect(&other);
+        assert_eq!(region.area(), 20.0);
+        region.union(&other);
+        assert_eq!(region.area(), 120.0);
+        region.subtract(&other);
+        assert!(region.is_empty());
+
+        region.union_rect(&JsRect::new(0.0, 0.0, 1.0, 1.0));
+        region.intersect_rect(&JsRect::new(0.5, 0.0, 2.0, 1.0));
+        assert_eq!(region.rects().len(), 1);
+        region.clear();
+        assert!(region.rects().is_empty());
+    }
+}